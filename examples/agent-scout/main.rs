@@ -3,8 +3,11 @@
 // Автономный агент-аналитик (Scout Agent)
 
 use ontocms::core::{PhaseEngine, activity_ledger::{OntoPhase, ProfileId, OntoEvent}};
+use ontocms::core::social_context::{AudienceScope, RelationKind, SocialContext};
 use ontocms::ethics::law3_harm_witness::{self, HarmType};
 use ontocms::transport::simd_optim;
+use ontocms::ethics::pipeline::EthicsPipeline;
+use ontocms::transport::json_ld;
 
 // Агент работает в фазе Decline → Heyday: анализ → синтез
 const SCOUT_PROFILE: &str = "Scorpio-Water-Snake";
@@ -26,7 +29,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     for (i, data) in raw_data.iter().enumerate() {
         let event = phase_engine.emit_event(
             serde_json::from_str(data)?,
-            50 // средняя социальная дистанция
+            // Средняя социальная дистанция: сервисные данные, только для агента
+            SocialContext::new(50)
+                .with_relation(RelationKind::Institutional)
+                .with_audience(AudienceScope::Private),
         );
 
         // Проверка на вред (Закон III)
@@ -34,8 +40,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             match harm {
                 HarmType::EnergyValueInjection => {
                     println!("⚠️  Harm detected: energy metric in data stream {}", i);
-                    let witness = law3_harm_witness::generate_harm_witness(harm, &event, profile.clone());
-                    println!("   Witness ID: {}", witness.witness_profile.0);
+                    let witness = law3_harm_witness::generate_harm_witness(harm, &event, profile.clone())
+                        .map_err(|e| format!("{:?}", e))?;
+                    println!("   Witness ID: {} (by {})", witness.id, witness.witness_profile.0);
+                    for evidence in &witness.evidence {
+                        println!("   Evidence: {} at {} — {}", evidence.rule_id, evidence.json_path, evidence.excerpt);
+                    }
                 }
                 _ => {}
             }
@@ -44,14 +54,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Переход в Heyday после анализа
         if i == raw_data.len() - 1 {
             phase_engine.transition_to(OntoPhase::Heyday)?;
-            let synthesis = phase_engine.emit_event(
+            // Синтез трассируется до последнего проанализированного события
+            phase_engine.set_causal_head(json_ld::event_hash(&event).map_err(|e| format!("{:?}", e))?);
+            let pipeline = EthicsPipeline::new();
+            let synthesis = phase_engine.emit_checked(
                 serde_json::json!({
                     "type": "scout_summary",
                     "findings": "1 potential harm detected",
                     "recommendation": "isolate source"
                 }),
-                90
-            );
+                // Синтез — собственная работа агента для сообщества
+                SocialContext::new(90)
+                    .with_relation(RelationKind::SelfAuthored)
+                    .with_audience(AudienceScope::Community),
+                &pipeline,
+            )?;
             println!("✨ Synthesis in Heyday phase: {:?}", synthesis.payload);
         }
     }
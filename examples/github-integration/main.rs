@@ -3,8 +3,11 @@
 // Превращение GitHub-репозитория в ontoCMS-узел
 
 use ontocms::core::{ActivityLedger, LocalMirror, PhaseEngine, activity_ledger::{OntoPhase, ProfileId}};
+use ontocms::core::social_context::{AudienceScope, RelationKind, SocialContext};
 use ontocms::ontocoder::validator::OntoValidator;
 use ontocms::forms::invariant_registry::InvariantRegistry;
+use ontocms::ethics::pipeline::EthicsPipeline;
+use ontocms::ethics::law1_attribution::CachedProfileSource;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 1. Загрузка 144 профилей
@@ -13,7 +16,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 2. Выбор профиля для этого репозитория
     let profile_id = ProfileId("Libra-Earth-Goat".to_string());
-    if registry.get_profile(&profile_id).is_none() {
+    if registry.get_profile(&profile_id.0).is_none() {
         panic!("Profile not in onto-144 registry");
     }
 
@@ -22,28 +25,37 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut mirror = LocalMirror::new(".");
     let mut phase_engine = PhaseEngine::new(OntoPhase::Slow, profile_id.clone());
 
+    // Причинная цепочка узла начинается с инварианта его профиля (Закон II)
+    let invariant_hash = registry
+        .get_profile(&profile_id.0)
+        .map(|p| p.invariant_hash.clone())
+        .ok_or("Profile not in onto-144 registry")?;
+    phase_engine.set_causal_head(invariant_hash);
+
     // 4. GitHub как источник событий (через webhook или GH Actions)
-    // Пример: событие "новый issue"
-    let issue_event = phase_engine.emit_event(
+    // Пример: событие "новый issue" — Три закона + схема github_issue
+    // Закон I проверяется по загруженному реестру профилей
+    let pipeline = EthicsPipeline::new()
+        .with_profiles(CachedProfileSource::new(registry));
+    let issue_event = phase_engine.emit_checked(
         serde_json::json!({
             "type": "github_issue",
             "repo": "user/ontocms-example",
             "title": "Add Slow-phase validation",
             "action": "opened"
         }),
-        70 // social_proximity: умеренная вовлечённость
-    );
-
-    // 5. Этическая проверка
-    use ontocms::ethics::{law1_attribution, law2_tracing};
-    law1_attribution::enforce_attribution(&issue_event)?;
-    law2_tracing::enforce_tracing(&issue_event)?;
+        // Умеренная вовлечённость: участники проекта, публичный репозиторий
+        SocialContext::new(70)
+            .with_relation(RelationKind::Community)
+            .with_audience(AudienceScope::Public),
+        &pipeline,
+    )?;
 
-    // 6. Сохранение локально (автономия)
-    mirror.persist(&issue_event);
-    ledger.append(issue_event.clone());
+    // 5. Сохранение локально (автономия); журнал принимает событие через тот же конвейер
+    mirror.persist(&issue_event)?;
+    ledger.ingest(issue_event.clone(), &pipeline)?;
 
-    // 7. Публикация в Public Hub (опционально)
+    // 6. Публикация в Public Hub (опционально)
     // let hub = IpfsHub::new(HubConfig::default());
     // let _cid = hub.publish(&issue_event).await?;
 
@@ -3,6 +3,8 @@
 // Интеграция email как онтологического канала
 
 use ontocms::core::{PhaseEngine, activity_ledger::{OntoPhase, ProfileId}};
+use ontocms::core::social_context::{AudienceScope, RelationKind, SocialContext};
+use ontocms::ethics::pipeline::EthicsPipeline;
 use lettre::{Message, Transport};
 use std::env;
 
@@ -11,7 +13,7 @@ const EMAIL_PROFILE: &str = "Pisces-Metal-Fish";
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let profile = ProfileId(EMAIL_PROFILE.to_string());
-    let phase_engine = PhaseEngine::new(OntoPhase::Slow, profile.clone());
+    let mut phase_engine = PhaseEngine::new(OntoPhase::Slow, profile.clone());
 
     // Пример: входящее письмо
     let email_content = r#"Subject: [SLOW] Request for OntoReflection
@@ -27,25 +29,33 @@ Body: How does NoemaSlow handle social invariants?"#;
         OntoPhase::Slow // по умолчанию — рефлексия
     };
 
-    // Создание онтологического события
-    let email_event = phase_engine.emit_event(
+    // Событие трассируется до исходного письма (Закон II)
+    phase_engine.set_causal_head(format!("blake3:{}", blake3::hash(email_content.as_bytes()).to_hex()));
+
+    // Создание онтологического события: Три закона + схема email_message
+    let pipeline = EthicsPipeline::new();
+    let email_event = phase_engine.emit_checked(
         serde_json::json!({
+            "type": "email_message",
             "channel": "email",
             "from": "philosopher@example.com",
             "subject": "Request for OntoReflection",
             "content_snippet": "How does NoemaSlow handle social invariants?",
             "phase_hint": format!("{:?}", phase)
         }),
-        80 // высокая социальная близость (личное письмо)
+        // Высокая социальная близость: личное письмо, только адресатам
+        SocialContext::new(80)
+            .with_relation(RelationKind::Personal)
+            .with_audience(AudienceScope::Circle),
+        &pipeline,
     );
-
-    // Валидация по Трём законам
-    use ontocms::ethics::{law1_attribution, law2_tracing, law3_harm_witness};
-    law1_attribution::enforce_attribution(&email_event)?;
-    law2_tracing::enforce_tracing(&email_event)?;
-    if law3_harm_witness::detect_harm(&email_event).is_some() {
-        eprintln!("⚠️  Harm detected in email — logging only");
-    }
+    let email_event = match email_event {
+        Ok(event) => event,
+        Err(e) => {
+            eprintln!("⚠️  Email rejected by the ethics pipeline — logging only:\n{}", e);
+            return Ok(());
+        }
+    };
 
     println!("📬 Email processed as ontoCMS event:");
     println!("   Profile: {}", email_event.profile_id.0);
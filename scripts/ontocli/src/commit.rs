@@ -1,54 +1,147 @@
 ```rust
 use clap::Args;
 use std::fs;
+use std::path::PathBuf;
+
+use ontocms_core::activity_ledger::{OntoPhase, ProfileId};
+use ontocms_core::local_mirror::LocalMirror;
+use ontocms_ethics::law1_attribution::CachedProfileSource;
+use ontocms_ethics::pipeline::EthicsPipeline;
+use ontocms_forms::invariant_registry::InvariantRegistry;
+use ontocms_ontocoder::commit_event::{commit_event, pending_commit_event};
+use ontocms_ontocoder::config::ProjectConfig;
+use ontocms_ontocoder::git_history;
+use ontocms_ontocoder::header::{self, TagValue, PHASE_TAG, PROFILE_TAG, SPDX_TAG};
+
+/// Лицензия, вставляемая в файлы без SPDX, если `--license` не указан
+const DEFAULT_LICENSE: &str = "GPL-3.0-only";
 
 #[derive(Args)]
 pub struct Args {
-    /// ONTO-PROFILE: e.g., Aries-Wood-Rabbit
+    /// ONTO-PROFILE: e.g., Aries-Wood-Rabbit (по умолчанию — `profile` из секции [node] ontocms.toml)
     #[arg(long)]
-    pub profile: String,
+    pub profile: Option<String>,
 
-    /// ONTO-PHASE: Fast | Slow | Heyday | Decline
+    /// ONTO-PHASE: Fast | Slow | Heyday | Decline (по умолчанию — `phase` из секции [node])
     #[arg(long)]
-    pub phase: String,
+    pub phase: Option<String>,
 
     /// Сообщение коммита
-    #[arg(long)]
+    #[arg(short, long)]
     pub message: String,
 
-    /// Путь к файлам (опционально)
+    /// SPDX-выражение лицензии: заменяет существующее (без флага — вставляется GPL-3.0-only, где SPDX нет)
+    #[arg(long)]
+    pub license: Option<String>,
+
+    /// Заголовки получают только файлы индекса под этим путём
     #[arg(long, default_value = ".")]
-    pub path: String,
+    pub path: PathBuf,
+
+    /// Каталог профилей onto-144 (по умолчанию — `profiles` из секции [node] ontocms.toml)
+    #[arg(long, value_name = "DIR")]
+    pub profiles: Option<PathBuf>,
 }
 
-pub async fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
-    // 1. Проверка профиля
-    if !args.profile.contains('-') || args.profile.split('-').count() != 3 {
-        eprintln!("❌ Invalid ONTO-PROFILE format. Use: Zodiac-Element-Animal");
-        std::process::exit(1);
+pub fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let root = git_history::repository_root(&args.path).map_err(|e| format!("git: {:?}", e))?;
+    let config = ProjectConfig::discover(&root).map_err(|e| format!("ontocms.toml: {:?}", e))?;
+    let node = config.file().node.as_ref();
+
+    // 1. Профиль и фаза: флаги или секция [node]; профиль — только из реестра onto-144
+    let profile = args
+        .profile
+        .clone()
+        .or_else(|| node.map(|n| n.profile.clone()))
+        .ok_or("no ONTO-PROFILE: use --profile or `ontocli init`")?;
+    let phase_name = args
+        .phase
+        .clone()
+        .or_else(|| node.map(|n| n.phase.clone()))
+        .ok_or("no ONTO-PHASE: use --phase or `ontocli init`")?;
+    let phase: OntoPhase = phase_name.parse()?;
+
+    let profiles_dir = args
+        .profiles
+        .clone()
+        .or_else(|| config.profiles_dir())
+        .unwrap_or_else(|| PathBuf::from("../../src/forms/profiles"));
+    let mut registry = InvariantRegistry::new();
+    registry.load_from_disk(&profiles_dir.to_string_lossy())?;
+    // Причинная цепочка узла начинается с инварианта профиля (Закон II)
+    let chain_start = registry
+        .get_profile(&profile)
+        .map(|p| p.invariant_hash.clone())
+        .ok_or_else(|| format!("profile '{}' is not in the onto-144 registry", profile))?;
+
+    // 2. Событие будущего коммита проверяется этическим конвейером до записи в историю:
+    //    от самого коммита зависит только его хеш
+    let message = git_history::with_trailers(&root, &args.message, &profile, &phase_name)
+        .map_err(|e| format!("git: {:?}", e))?;
+    let mirror = LocalMirror::new(&config.root().to_string_lossy());
+    let pending = pending_commit_event(&root, &message, ProfileId(profile.clone()), phase.clone(), &mirror, &chain_start)
+        .map_err(|e| format!("pending commit: {:?}", e))?;
+    let pipeline = EthicsPipeline::new()
+        .with_profiles(CachedProfileSource::new(registry));
+    // Отчёт печатается целиком: ошибка `main` выводится в одну строку
+    if let Err(report) = pipeline.enforce(&pending.event) {
+        eprintln!("❌ Commit rejected, nothing was committed:\n{}", report);
+        return Err("commit event rejected by the ethics pipeline".into());
     }
 
-    // 2. Проверка фазы
-    if !["Fast", "Slow", "Heyday", "Decline"].contains(&args.phase.as_str()) {
-        eprintln!("❌ Invalid ONTO-PHASE. Use: Fast | Slow | Heyday | Decline");
-        std::process::exit(1);
+    // 3. Заголовки файлов индекса: в индексе и в рабочем дереве, с учётом языка
+    let staged = git_history::staged_files(&root).map_err(|e| format!("git: {:?}", e))?;
+    if staged.is_empty() {
+        return Err("nothing staged: `git add` the files to commit".into());
     }
+    let values = [
+        TagValue {
+            tag: SPDX_TAG,
+            value: args.license.as_deref().unwrap_or(DEFAULT_LICENSE),
+            replace: args.license.is_some(),
+        },
+        TagValue {
+            tag: PROFILE_TAG,
+            value: &profile,
+            replace: true,
+        },
+        TagValue {
+            tag: PHASE_TAG,
+            value: &phase_name,
+            replace: true,
+        },
+    ];
+    let scope = fs::canonicalize(&args.path)?;
+    println!("📝 Ontological commit: {} · {}", profile, phase_name);
+    for file in staged.iter().filter(|f| f.starts_with(&scope) && config.includes(f)) {
+        let content = git_history::staged_content(&root, file).map_err(|e| format!("git: {:?}", e))?;
+        let Some(updated) = header::rewrite_header(file, &content, &values) else {
+            continue;
+        };
+        git_history::stage_content(&root, file, &updated).map_err(|e| format!("git: {:?}", e))?;
+        // Рабочее дерево правится отдельно: неиндексированные изменения файла сохраняются
+        if let Ok(working) = fs::read_to_string(file) {
+            if let Some(updated) = header::rewrite_header(file, &working, &values) {
+                fs::write(file, updated)?;
+            }
+        }
+        println!("   ✎ {}", file.strip_prefix(&root).unwrap_or(file).display());
+    }
+
+    // 4. Коммит с трейлерами атрибуции; хуки репозитория выполняются
+    let commit = git_history::commit(&root, &message).map_err(|e| format!("git commit: {:?}", e))?;
+    println!("   ✔ commit {}", commit.id);
 
-    // 3. Генерация метаданных коммита
-    let metadata = format!(
-        "// ONTO-PROFILE: {}\n// ONTO-PHASE: {}\n// SPDX-License-Identifier: GPL-3.0-only\n",
-        args.profile, args.phase
-    );
-
-    // 4. Добавление метаданных в каждый файл (упрощённо)
-    // В реальности — через git hooks или patch
-    println!("📝 Preparing ontological commit:");
-    println!("   Profile: {}", args.profile);
-    println!("   Phase: {}", args.phase);
-    println!("   Message: {}", args.message);
-    println!("\n⚠️  Run `git commit -m \"{}\"` manually after adding files.", args.message);
-    println!("   Ensure all files contain the above metadata.");
+    // 5. Событие коммита в локальном журнале; хук мог изменить сообщение — событие проверяется ещё раз
+    let event = commit_event(&commit, ProfileId(profile), phase, &pending.parent_events, &chain_start);
+    if let Err(report) = pipeline.enforce(&event) {
+        eprintln!("❌ Commit {} created, but a hook changed it and its event was rejected:\n{}", commit.id, report);
+        return Err(format!("event of commit {} rejected by the ethics pipeline", commit.id).into());
+    }
+    mirror.persist(&event)?;
+    println!("   ✔ local_mirror/{}/{}.json", event.profile_id.0, event.id);
 
+    println!("✅ Committed with ONTO-PROFILE/ONTO-PHASE attribution");
     Ok(())
 }
 ```
\ No newline at end of file
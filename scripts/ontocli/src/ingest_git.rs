@@ -0,0 +1,97 @@
+```rust
+use clap::Args;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use ontocms_core::activity_ledger::OntoPhase;
+use ontocms_core::local_mirror::LocalMirror;
+use ontocms_ethics::law1_attribution::CachedProfileSource;
+use ontocms_ethics::pipeline::EthicsPipeline;
+use ontocms_forms::invariant_registry::InvariantRegistry;
+use ontocms_ontocoder::config::ProjectConfig;
+use ontocms_ontocoder::git_history;
+use ontocms_ontocoder::git_ingest::{GitIngest, IngestOutcome};
+
+#[derive(Args)]
+pub struct Args {
+    /// Корень репозитория
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Ревизия, история которой принимается
+    #[arg(long, default_value = "HEAD")]
+    pub rev: String,
+
+    /// ONTO-PROFILE для коммитов без трейлера Onto-Profile (без флага такие коммиты пропускаются)
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// ONTO-PHASE для коммитов без трейлера Onto-Phase (по умолчанию — `phase` из секции [node], иначе Slow)
+    #[arg(long)]
+    pub phase: Option<String>,
+
+    /// Каталог профилей onto-144 (по умолчанию — `profiles` из секции [node] ontocms.toml)
+    #[arg(long, value_name = "DIR")]
+    pub profiles: Option<PathBuf>,
+
+    /// Пройти всю историю, а не только коммиты после последнего импорта
+    #[arg(long)]
+    pub full: bool,
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let root = git_history::repository_root(&args.path).map_err(|e| format!("git: {:?}", e))?;
+    let config = ProjectConfig::discover(&root).map_err(|e| format!("ontocms.toml: {:?}", e))?;
+    let default_phase: OntoPhase = args
+        .phase
+        .clone()
+        .or_else(|| config.file().node.as_ref().map(|n| n.phase.clone()))
+        .unwrap_or_else(|| "Slow".to_string())
+        .parse()?;
+
+    // Профили onto-144: причинная цепочка профиля начинается с его инварианта (Закон II)
+    let profiles_dir = args
+        .profiles
+        .clone()
+        .or_else(|| config.profiles_dir())
+        .unwrap_or_else(|| PathBuf::from("../../src/forms/profiles"));
+    let mut registry = InvariantRegistry::new();
+    registry.load_from_disk(&profiles_dir.to_string_lossy())?;
+    let chain_starts: HashMap<String, String> = registry
+        .all_ids()
+        .into_iter()
+        .filter_map(|id| registry.get_profile(&id).map(|p| (id, p.invariant_hash.clone())))
+        .collect();
+    let pipeline = EthicsPipeline::new()
+        .with_profiles(CachedProfileSource::new(registry));
+
+    // Инкрементально: коммиты после отметки прошлого импорта
+    let mirror = LocalMirror::new(&config.root().to_string_lossy());
+    let report = GitIngest::new(&mirror, &pipeline, chain_starts)
+        .with_profile(args.profile.clone())
+        .with_phase(default_phase)
+        .run(&root, &args.rev, args.full)
+        .map_err(|e| format!("ingest-git: {:?}", e))?;
+    println!("📥 {} new commit(s) of {} from {}", report.commits.len(), args.rev, root.display());
+    for (commit, outcome) in &report.commits {
+        let short = &commit.id[..commit.id.len().min(12)];
+        match outcome {
+            IngestOutcome::Ingested(profile) => println!("   ✔ {} {} · {}", short, profile.0, commit.summary),
+            IngestOutcome::Rejected(reason) => eprintln!("   ✘ {} {}:\n{}", short, commit.summary, reason),
+            IngestOutcome::NotPersisted(error) => {
+                eprintln!("   ✘ {} {}: local_mirror: {}", short, commit.summary, error)
+            }
+            IngestOutcome::Known | IngestOutcome::Unattributed => {}
+        }
+    }
+
+    println!(
+        "✅ {} ingested, {} already in local_mirror, {} without Onto-Profile, {} rejected or not saved",
+        report.count(|o| matches!(o, IngestOutcome::Ingested(_))),
+        report.count(|o| *o == IngestOutcome::Known),
+        report.count(|o| *o == IngestOutcome::Unattributed),
+        report.count(|o| matches!(o, IngestOutcome::Rejected(_) | IngestOutcome::NotPersisted(_)))
+    );
+    Ok(())
+}
+```
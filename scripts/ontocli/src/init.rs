@@ -0,0 +1,190 @@
+```rust
+use clap::{Args, ValueEnum};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use ontocms_core::local_mirror::LocalMirror;
+use ontocms_forms::invariant_registry::InvariantRegistry;
+use ontocms_ontocoder::config::{ConfigFile, FilesSection, NodeSection, CONFIG_FILE_NAME};
+use ontocms_ontocoder::git_history::{self, HookInstall};
+
+/// Шаблон CI узла (scripts/ci/ontocms-node.yml)
+const CI_TEMPLATE: &str = include_str!("../../ci/ontocms-node.yml");
+const CI_WORKFLOW: &str = ".github/workflows/ontoci.yml";
+const PHASES: [&str; 4] = ["Fast", "Slow", "Heyday", "Decline"];
+
+#[derive(Args)]
+pub struct Args {
+    /// Корень репозитория
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Инварианта ядра узла
+    #[arg(long, default_value = "Neutral_Core-000")]
+    pub kernel: String,
+
+    /// ONTO-PROFILE автора (без флага — запрос в терминале)
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// ONTO-PHASE коммитов по умолчанию: Fast | Slow | Heyday | Decline
+    #[arg(long, default_value = "Slow")]
+    pub phase: String,
+
+    /// Каталог профилей onto-144
+    #[arg(long, value_name = "DIR", default_value = "../../src/forms/profiles")]
+    pub profiles: PathBuf,
+
+    /// CI-конвейер
+    #[arg(long, value_enum, default_value_t = Ci::Github)]
+    pub ci: Ci,
+
+    /// Не устанавливать хуки git
+    #[arg(long)]
+    pub no_hooks: bool,
+
+    /// Перезаписать существующие файлы
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Ci {
+    /// .github/workflows/ontoci.yml
+    Github,
+    /// Без CI
+    None,
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let root = fs::canonicalize(&args.path)?;
+
+    // 1. Профиль и фаза — только из реестра onto-144
+    if !PHASES.contains(&args.phase.as_str()) {
+        return Err(format!("invalid ONTO-PHASE '{}'. Use: Fast | Slow | Heyday | Decline", args.phase).into());
+    }
+    let mut registry = InvariantRegistry::new();
+    registry
+        .load_from_disk(&args.profiles.to_string_lossy())
+        .map_err(|e| format!("onto-144 profiles in {}: {}", args.profiles.display(), e))?;
+    let profile = match &args.profile {
+        Some(profile) => profile.clone(),
+        None => prompt_profile(&registry)?,
+    };
+    if registry.get_profile(&profile).is_none() {
+        return Err(format!("profile '{}' is not in the onto-144 registry", profile).into());
+    }
+
+    println!("🌱 Initializing ontoCMS node in {}", root.display());
+    println!("   Kernel: {}", args.kernel);
+    println!("   Profile: {}", profile);
+    println!("   Phase: {}", args.phase);
+
+    // 2. ontocms.toml с секцией [node]
+    let mut files = FilesSection::default();
+    files.exclude.extend([".onto144/**".to_string(), "local_mirror/**".to_string()]);
+    let config = ConfigFile {
+        node: Some(NodeSection {
+            kernel: args.kernel.clone(),
+            profile: profile.clone(),
+            phase: args.phase.clone(),
+            profiles: Some(relative_to(&fs::canonicalize(&args.profiles)?, &root)),
+        }),
+        files,
+        ..Default::default()
+    };
+    let toml = config.to_toml_string().map_err(|e| format!("ontocms.toml: {:?}", e))?;
+    write_file(&root.join(CONFIG_FILE_NAME), &toml, args.force)?;
+
+    // 3. Хуки git: проверка индекса и трейлеры атрибуции
+    if args.no_hooks {
+        println!("   - git hooks skipped (--no-hooks)");
+    } else {
+        match git_history::repository_root(&root) {
+            Ok(repository) => {
+                let installed = git_history::install_hooks(&repository, &profile, &args.phase, args.force)
+                    .map_err(|e| format!("git hooks: {:?}", e))?;
+                for hook in installed {
+                    match hook {
+                        HookInstall::Written(path) => println!("   ✔ {}", path.display()),
+                        HookInstall::Kept(path) => {
+                            println!("   ⚠️  {} exists and is not an ontocli hook; kept (use --force)", path.display())
+                        }
+                    }
+                }
+            }
+            Err(_) => println!("   ⚠️  {} is not a git repository; hooks not installed", root.display()),
+        }
+    }
+
+    // 4. CI
+    if let Ci::Github = args.ci {
+        write_file(&root.join(CI_WORKFLOW), CI_TEMPLATE, args.force)?;
+    }
+
+    // 5. Локальное зеркало событий; в репозиторий не попадает
+    LocalMirror::new(&root.to_string_lossy());
+    println!("   ✔ local_mirror/");
+    ignore_local_mirror(&root)?;
+
+    println!("✅ Node initialized. Commit with `ontocli commit` or plain `git commit`.");
+    Ok(())
+}
+
+/// Запрос профиля в терминале; неизвестный профиль — повторный запрос с похожими вариантами
+fn prompt_profile(registry: &InvariantRegistry) -> Result<String, Box<dyn std::error::Error>> {
+    let mut ids = registry.all_ids();
+    ids.sort();
+    let stdin = io::stdin();
+    loop {
+        print!("ONTO-PROFILE (Zodiac-Element-Animal): ");
+        io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            return Err("no profile given (use --profile)".into());
+        }
+        let answer = line.trim();
+        if registry.get_profile(answer).is_some() {
+            return Ok(answer.to_string());
+        }
+        let prefix = answer.split('-').next().unwrap_or_default().to_lowercase();
+        let similar: Vec<&String> = ids.iter().filter(|id| id.to_lowercase().starts_with(&prefix)).take(12).collect();
+        eprintln!("❌ '{}' is not in the onto-144 registry", answer);
+        if !prefix.is_empty() && !similar.is_empty() {
+            eprintln!("   e.g. {}", similar.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", "));
+        }
+    }
+}
+
+/// Существующий файл без `--force` не перезаписывается
+fn write_file(path: &Path, content: &str, force: bool) -> io::Result<()> {
+    if path.exists() && !force {
+        println!("   - {} exists; kept (use --force)", path.display());
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    println!("   ✔ {}", path.display());
+    Ok(())
+}
+
+fn ignore_local_mirror(root: &Path) -> io::Result<()> {
+    let path = root.join(".gitignore");
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    if existing.lines().any(|l| matches!(l.trim(), "/local_mirror/" | "local_mirror/" | "/local_mirror" | "local_mirror")) {
+        return Ok(());
+    }
+    let separator = if existing.is_empty() || existing.ends_with('\n') { "" } else { "\n" };
+    fs::write(&path, format!("{}{}/local_mirror/\n", existing, separator))?;
+    println!("   ✔ .gitignore: /local_mirror/");
+    Ok(())
+}
+
+/// Путь относительно корня проекта, если каталог внутри него; иначе — абсолютный
+fn relative_to(path: &Path, root: &Path) -> PathBuf {
+    path.strip_prefix(root).map(Path::to_path_buf).unwrap_or_else(|_| path.to_path_buf())
+}
+```
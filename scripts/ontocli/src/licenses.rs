@@ -0,0 +1,78 @@
+```rust
+use clap::{Args, ValueEnum};
+use std::path::PathBuf;
+
+use ontocms_ontocoder::dependency_audit::{audit_lockfile, AuditSources};
+use ontocms_ontocoder::license_registry::LicenseRegistry;
+
+#[derive(Args)]
+pub struct Args {
+    /// Корень рабочего пространства cargo
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Cargo.lock (по умолчанию — PATH/Cargo.lock)
+    #[arg(long, value_name = "FILE")]
+    pub lockfile: Option<PathBuf>,
+
+    /// Каталог `cargo vendor` (по умолчанию — PATH/vendor, если есть)
+    #[arg(long, value_name = "DIR")]
+    pub vendor: Option<PathBuf>,
+
+    /// Распакованный реестр cargo, можно несколько (по умолчанию — $CARGO_HOME/registry/src/*)
+    #[arg(long, value_name = "DIR")]
+    pub registry: Vec<PathBuf>,
+
+    /// Формат отчёта
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Записать SBOM SPDX 2.3 (JSON) в файл
+    #[arg(long, value_name = "FILE")]
+    pub sbom: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// Строка на зависимость и итог
+    Text,
+    /// LicenseAudit в JSON
+    Json,
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let mut sources = AuditSources::discover(&args.path);
+    if let Some(vendor) = &args.vendor {
+        sources.vendor_dir = Some(vendor.clone());
+    }
+    if !args.registry.is_empty() {
+        sources.registry_dirs = args.registry.clone();
+    }
+    let lockfile = args.lockfile.clone().unwrap_or_else(|| args.path.join("Cargo.lock"));
+
+    let registry = LicenseRegistry::new();
+    let audit = audit_lockfile(&lockfile, &sources, &registry).map_err(|e| format!("license audit: {:?}", e))?;
+
+    match args.format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(&audit)?),
+        Format::Text => print!("{}", audit.render_text()),
+    }
+
+    if let Some(path) = &args.sbom {
+        let name = std::fs::canonicalize(&args.path)?
+            .file_name()
+            .map_or("workspace".to_string(), |n| n.to_string_lossy().into_owned());
+        std::fs::write(path, audit.to_spdx(&name).to_json()?)?;
+        eprintln!("📦 SBOM written: {}", path.display());
+    }
+
+    if !audit.is_clean() {
+        eprintln!("❌ License audit failed: dependencies incompatible with SGCL or unresolved offline");
+        std::process::exit(1);
+    }
+    if let Format::Text = args.format {
+        println!("✅ License audit passed: all dependencies compatible with SGCL");
+    }
+    Ok(())
+}
+```
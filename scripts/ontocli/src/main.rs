@@ -5,6 +5,10 @@
 use clap::{Parser, Subcommand};
 
 mod commit;
+mod ingest_git;
+mod init;
+mod licenses;
+mod sbom;
 mod validate;
 mod scan;
 
@@ -17,12 +21,20 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
-    /// Создать онтологически корректный коммит
+    /// Подготовить репозиторий как узел ontoCMS: ontocms.toml, хуки git, CI, локальное зеркало
+    Init(init::Args),
+    /// Коммит с заголовками ONTO-PROFILE/ONTO-PHASE/SPDX, трейлерами атрибуции и событием в локальном журнале
     Commit(commit::Args),
+    /// Принять историю git в локальный журнал: коммиты с трейлерами Onto-Profile/Onto-Phase → события
+    IngestGit(ingest_git::Args),
     /// Проверить файл на соответствие SGRL-α и Three Laws
     Validate(validate::Args),
     /// Сканировать репозиторий на энергетические/биометрические нарушения
     Scan(scan::Args),
+    /// Проверить лицензии зависимостей Cargo.lock на совместимость с SGCL
+    Licenses(licenses::Args),
+    /// SBOM исходного дерева (SPDX / CycloneDX) и его сверка
+    Sbom(sbom::Args),
 }
 
 #[tokio::main]
@@ -30,9 +42,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
     match &cli.command {
-        Commands::Commit(args) => commit::run(args).await,
+        Commands::Init(args) => init::run(args),
+        Commands::Commit(args) => commit::run(args),
+        Commands::IngestGit(args) => ingest_git::run(args),
         Commands::Validate(args) => validate::run(args),
         Commands::Scan(args) => scan::run(args),
+        Commands::Licenses(args) => licenses::run(args),
+        Commands::Sbom(args) => sbom::run(args),
     }
 }
 ```
\ No newline at end of file
@@ -0,0 +1,133 @@
+```rust
+use clap::{Args, Subcommand, ValueEnum};
+use std::path::{Path, PathBuf};
+
+use ontocms_ontocoder::config::ProjectConfig;
+use ontocms_ontocoder::cyclonedx::CycloneDxBom;
+use ontocms_ontocoder::file_inventory::{self, FileRecord};
+use ontocms_ontocoder::license_registry::LicenseRegistry;
+use ontocms_ontocoder::sbom::SpdxDocument;
+
+#[derive(Args)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: SbomCommand,
+}
+
+#[derive(Subcommand)]
+pub enum SbomCommand {
+    /// Сформировать SBOM исходного дерева с аннотациями ONTO-PROFILE / ONTO-PHASE
+    Generate(GenerateArgs),
+    /// Сверить SBOM с текущим деревом
+    Verify(VerifyArgs),
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Корень проекта
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Формат документа
+    #[arg(long, value_enum, default_value_t = SbomFormat::Spdx)]
+    pub format: SbomFormat,
+
+    /// Версия релиза (versionInfo пакета / metadata.component.version)
+    #[arg(long, value_name = "VERSION")]
+    pub release: Option<String>,
+
+    /// Файл для записи (по умолчанию — stdout)
+    #[arg(long, short, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Конфигурация проекта (по умолчанию — ближайший ontocms.toml выше PATH)
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// SBOM в формате SPDX 2.3 JSON или CycloneDX JSON
+    #[arg(value_name = "SBOM")]
+    pub sbom: PathBuf,
+
+    /// Корень проекта
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Конфигурация проекта (по умолчанию — ближайший ontocms.toml выше PATH)
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum SbomFormat {
+    /// SPDX 2.3 JSON
+    Spdx,
+    /// CycloneDX 1.5 JSON
+    Cyclonedx,
+}
+
+pub fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    match &args.command {
+        SbomCommand::Generate(args) => generate(args),
+        SbomCommand::Verify(args) => verify(args),
+    }
+}
+
+fn generate(args: &GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let registry = LicenseRegistry::new();
+    let records = tree_records(&args.path, args.config.as_deref(), &registry)?;
+    let name = project_name(&args.path)?;
+    let release = args.release.as_deref();
+
+    let json = match args.format {
+        SbomFormat::Spdx => SpdxDocument::for_files(&name, release, &records, registry.list_version()).to_json()?,
+        SbomFormat::Cyclonedx => CycloneDxBom::for_files(&name, release, &records).to_json()?,
+    };
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, json)?;
+            eprintln!("📦 SBOM written: {} ({} files)", path.display(), records.len());
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let registry = LicenseRegistry::new();
+    let recorded = file_inventory::records_from_sbom(&std::fs::read_to_string(&args.sbom)?)
+        .map_err(|e| format!("{}: {:?}", args.sbom.display(), e))?;
+    let actual = tree_records(&args.path, args.config.as_deref(), &registry)?;
+
+    let diff = file_inventory::diff(&recorded, &actual);
+    if !diff.is_empty() {
+        eprint!("{}", diff.render_text());
+        eprintln!(
+            "❌ SBOM is out of date: {} added, {} removed, {} changed",
+            diff.added.len(),
+            diff.removed.len(),
+            diff.changed.len()
+        );
+        std::process::exit(1);
+    }
+    println!("✅ SBOM matches the tree: {} files", actual.len());
+    Ok(())
+}
+
+fn tree_records(path: &Path, config: Option<&Path>, registry: &LicenseRegistry) -> Result<Vec<FileRecord>, Box<dyn std::error::Error>> {
+    let config = match config {
+        Some(file) => ProjectConfig::load(file),
+        None => ProjectConfig::discover(path),
+    }
+    .map_err(|e| format!("ontocms.toml: {:?}", e))?;
+    Ok(file_inventory::collect(&config, registry).map_err(|e| format!("inventory: {:?}", e))?)
+}
+
+fn project_name(path: &Path) -> Result<String, std::io::Error> {
+    Ok(std::fs::canonicalize(path)?
+        .file_name()
+        .map_or("project".to_string(), |n| n.to_string_lossy().into_owned()))
+}
+```
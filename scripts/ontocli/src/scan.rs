@@ -2,6 +2,8 @@
 use clap::Args;
 use std::path::PathBuf;
 
+use ontocms_ontocoder::config::ProjectConfig;
+
 #[derive(Args)]
 pub struct Args {
     /// Путь к репозиторию
@@ -17,7 +19,13 @@ pub fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
     let forbidden = ["energy", "value_score", "attractiveness", "monetize", "biometric_raw"];
     let mut violations = 0;
 
-    for entry in walkdir::WalkDir::new(&args.repo_path) {
+    // Пути из exclude в ontocms.toml не сканируются
+    let config = ProjectConfig::discover(&args.repo_path).map_err(|e| format!("ontocms.toml: {:?}", e))?;
+    let walker = walkdir::WalkDir::new(&args.repo_path)
+        .into_iter()
+        .filter_entry(|e| !config.excludes(e.path()));
+
+    for entry in walker {
         let entry = entry?;
         if entry.file_type().is_file() {
             let path = entry.path();
@@ -1,47 +1,148 @@
 ```rust
-use clap::Args;
+use clap::{Args, ValueEnum};
 use std::path::PathBuf;
 
+use ontocms_ontocoder::cla_registry::{ClaRegistry, CLA_REGISTRY_FILE};
+use ontocms_ontocoder::config::ProjectConfig;
+use ontocms_ontocoder::git_history::{self, GitHistory};
 use ontocms_ontocoder::validator::OntoValidator;
 use ontocms_ontocoder::license_registry::LicenseRegistry;
+use ontocms_ontocoder::report::ValidationReport;
 use ontocms_forms::invariant_registry::InvariantRegistry;
 
 #[derive(Args)]
 pub struct Args {
     /// Путь к файлу или директории
-    #[arg(value_name = "PATH")]
+    #[arg(value_name = "PATH", default_value = ".")]
     pub path: PathBuf,
+
+    /// Формат отчёта
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Конфигурация проекта (по умолчанию — ближайший ontocms.toml выше PATH)
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Реестр CLA (по умолчанию — legal/cla-registry.yaml в корне проекта, если есть)
+    #[arg(long, value_name = "FILE")]
+    pub cla_registry: Option<PathBuf>,
+
+    /// Каталог профилей onto-144 (по умолчанию — `profiles` из секции [node] ontocms.toml)
+    #[arg(long, value_name = "DIR")]
+    pub profiles: Option<PathBuf>,
+
+    /// Только файлы из индекса git под PATH, в том виде, в каком они будут закоммичены (pre-commit)
+    #[arg(long)]
+    pub staged: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// path:line:col: severity[rule]: message
+    Text,
+    /// Массив ValidationReport в JSON
+    Json,
+    /// Аннотации GitHub Actions
+    Github,
 }
 
 pub fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    // Индекс git: пути абсолютные, конфигурация ищется от корня репозитория
+    let repository = if args.staged {
+        Some(git_history::repository_root(&args.path).map_err(|e| format!("git: {:?}", e))?)
+    } else {
+        None
+    };
+
+    // Конфигурация проекта: уровни правил, термины, include/exclude
+    let config = match (&args.config, &repository) {
+        (Some(file), _) => ProjectConfig::load(file),
+        (None, Some(root)) => ProjectConfig::discover(root),
+        (None, None) => ProjectConfig::discover(&args.path),
+    }
+    .map_err(|e| format!("ontocms.toml: {:?}", e))?;
+
     // Загрузка профилей
+    let profiles_dir = args
+        .profiles
+        .clone()
+        .or_else(|| config.profiles_dir())
+        .unwrap_or_else(|| PathBuf::from("../../src/forms/profiles"));
     let mut registry = InvariantRegistry::new();
-    registry.load_from_disk("../../src/forms/profiles")?;
-    let profiles = registry.all_ids().into_iter().map(|id| ontocms_core::activity_ledger::ProfileId(id)).collect();
+    registry.load_from_disk(&profiles_dir.to_string_lossy())?;
+    let profiles = registry.all_ids().into_iter().map(ontocms_core::activity_ledger::ProfileId).collect();
 
     // Валидатор
     let license_reg = LicenseRegistry::new();
-    let validator = OntoValidator::new(license_reg, profiles);
+    let mut validator = OntoValidator::new(license_reg, profiles)
+        .with_config(&config)
+        .map_err(|e| format!("ontocms.toml: {:?}", e))?;
+
+    // CLA: реестр + локальная история git
+    let cla_file = args.cla_registry.clone().unwrap_or_else(|| config.root().join(CLA_REGISTRY_FILE));
+    if args.cla_registry.is_some() || cla_file.is_file() {
+        let cla = ClaRegistry::load(&cla_file).map_err(|e| format!("{}: {:?}", cla_file.display(), e))?;
+        for (id, reason) in cla.rejected() {
+            eprintln!("⚠️  CLA record {} ignored: {:?}", id, reason);
+        }
+        let history = GitHistory::load(config.root()).map_err(|e| format!("git history: {:?}", e))?;
+        validator = validator.with_cla(cla, history);
+    }
 
-    if args.path.is_file() {
-        validate_file(&validator, &args.path)?;
+    let mut reports = vec![];
+    if let Some(root) = &repository {
+        let scope = std::fs::canonicalize(&args.path)?;
+        for file in git_history::staged_files(root).map_err(|e| format!("git: {:?}", e))? {
+            if !file.starts_with(&scope) || !config.includes(&file) {
+                continue;
+            }
+            let content = git_history::staged_content(root, &file).map_err(|e| format!("git: {:?}", e))?;
+            reports.push(validator.validate_file(&file, &content));
+        }
+    } else if args.path.is_file() {
+        // Явно указанный файл проверяется всегда
+        reports.push(validate_file(&validator, &args.path)?);
     } else if args.path.is_dir() {
-        for entry in std::fs::read_dir(&args.path)? {
+        let walker = walkdir::WalkDir::new(&args.path)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|e| !config.excludes(e.path()));
+        for entry in walker {
             let entry = entry?;
-            if entry.path().extension() == Some(std::ffi::OsStr::new("rs")) {
-                validate_file(&validator, &entry.path())?;
+            if entry.file_type().is_file() && config.includes(entry.path()) {
+                reports.push(validate_file(&validator, entry.path())?);
             }
         }
     }
 
-    println!("✅ Validation passed: compliant with SGRL-α and Three Laws");
+    match args.format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
+        Format::Github => reports.iter().for_each(|r| print!("{}", r.render_github())),
+        Format::Text => {
+            for report in &reports {
+                if report.findings.is_empty() && report.suppressed.is_empty() {
+                    println!("   ✔ {}", report.path);
+                } else {
+                    eprint!("{}", report.render_text());
+                }
+            }
+        }
+    }
+
+    let failed = reports.iter().filter(|r| !r.is_clean()).count();
+    if failed > 0 {
+        eprintln!("❌ Validation failed: {} of {} file(s) violate SGRL-α or Three Laws", failed, reports.len());
+        std::process::exit(1);
+    }
+    if let Format::Text = args.format {
+        println!("✅ Validation passed: compliant with SGRL-α and Three Laws");
+    }
     Ok(())
 }
 
-fn validate_file(validator: &OntoValidator, path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+fn validate_file(validator: &OntoValidator, path: &std::path::Path) -> Result<ValidationReport, Box<dyn std::error::Error>> {
     let content = std::fs::read_to_string(path)?;
-    validator.validate_file(path, &content)?;
-    println!("   ✔ {}", path.display());
-    Ok(())
+    Ok(validator.validate_file(path, &content))
 }
 ```
\ No newline at end of file
@@ -5,6 +5,12 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::core::key_registry::KeyRegistry;
+use crate::core::social_context::SocialContext;
+use crate::ethics::law3_harm_witness::{HarmType, HarmWitness};
+use crate::ethics::pipeline::{EthicsPipeline, EthicsReport};
+use crate::transport::json_ld::{self, JsonLdError};
+
 /// Онтологический профиль по onto-144
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ProfileId(String); // e.g., "Aries-Wood-Rabbit"
@@ -18,6 +24,21 @@ pub enum OntoPhase {
     Heyday,   // Генеративный синтез
 }
 
+/// Фаза из аннотации `ONTO-PHASE` или трейлера `Onto-Phase`
+impl std::str::FromStr for OntoPhase {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "Fast" => Ok(OntoPhase::Fast),
+            "Slow" => Ok(OntoPhase::Slow),
+            "Heyday" => Ok(OntoPhase::Heyday),
+            "Decline" => Ok(OntoPhase::Decline),
+            other => Err(format!("invalid ONTO-PHASE '{}'. Use: Fast | Slow | Heyday | Decline", other)),
+        }
+    }
+}
+
 /// CRDT-совместимое событие
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct OntoEvent {
@@ -25,11 +46,30 @@ pub struct OntoEvent {
     pub profile_id: ProfileId,         // Обязательная атрибуция (Закон I)
     pub phase: OntoPhase,              // Фаза генерации (Закон II)
     pub payload: serde_json::Value,    // Онтологически нейтральные данные
-    pub social_proximity: u8,          // 0–100: контекст социальной близости
+    #[serde(alias = "social_proximity")]
+    pub social_context: SocialContext, // Социальный контекст (Закон II); прежнее поле — число 0–100
     pub causal_hash: Option<String>,   // Хеш предыдущего события (для трассировки)
     pub timestamp: u64,                // Unix timestamp (мс)
 }
 
+/// Ошибка записи свидетельства о вреде в журнал
+#[derive(Debug)]
+pub enum WitnessRecordError {
+    Serialization(serde_json::Error),
+    Rejected(EthicsReport),
+}
+
+impl std::fmt::Display for WitnessRecordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WitnessRecordError::Serialization(e) => write!(f, "harm witness serialization failed: {}", e),
+            WitnessRecordError::Rejected(report) => write!(f, "{}", report),
+        }
+    }
+}
+
+impl std::error::Error for WitnessRecordError {}
+
 /// CRDT-совместимый журнал событий
 /// Гарантирует согласованность без централизованного координатора
 #[derive(Default)]
@@ -37,6 +77,8 @@ pub struct ActivityLedger {
     events: HashMap<String, OntoEvent>,
     by_profile: HashMap<ProfileId, Vec<String>>,
     by_phase: HashMap<OntoPhase, Vec<String>>,
+    witnesses: HashMap<String, HarmWitness>,
+    witnesses_by_harm: HashMap<HarmType, Vec<String>>,
 }
 
 impl ActivityLedger {
@@ -45,17 +87,60 @@ impl ActivityLedger {
     }
 
     /// Добавление события — идемпотентно, CRDT-safe
-    pub fn append(&mut self, event: OntoEvent) {
+    /// Без проверки: только для событий, уже прошедших конвейер (локальное зеркало);
+    /// всё остальное принимается через `ingest`
+    pub(crate) fn append(&mut self, event: OntoEvent, keys: &KeyRegistry) {
         let id = event.id.clone();
         self.events.insert(id.clone(), event.clone());
-        self.by_profile
-            .entry(event.profile_id.clone())
-            .or_default()
-            .push(id.clone());
-        self.by_phase
-            .entry(event.phase.clone())
-            .or_default()
-            .push(id);
+        push_unique(self.by_profile.entry(event.profile_id.clone()).or_default(), &id);
+        push_unique(self.by_phase.entry(event.phase.clone()).or_default(), &id);
+
+        // Свидетельства Закона III индексируются отдельно — только подписанные ключом,
+        // зарегистрированным за `witness_profile`: ключ, встроенный в запись, доказывает лишь целостность
+        if let Some(witness) = HarmWitness::from_event(&event)
+            .filter(|w| w.verify().is_ok() && keys.signed_by(&w.witness_profile, w.signature.as_ref()))
+        {
+            push_unique(self.witnesses_by_harm.entry(witness.detected_harm.clone()).or_default(), &witness.id);
+            self.witnesses.insert(witness.id.clone(), witness);
+        }
+    }
+
+    /// Запись свидетельства о вреде как события журнала — через этический конвейер
+    pub fn record_witness(&mut self, witness: &HarmWitness, pipeline: &EthicsPipeline) -> Result<(), WitnessRecordError> {
+        let event = witness.to_event().map_err(WitnessRecordError::Serialization)?;
+        self.ingest(event, pipeline).map_err(WitnessRecordError::Rejected)
+    }
+
+    pub fn witness(&self, id: &str) -> Option<&HarmWitness> {
+        self.witnesses.get(id)
+    }
+
+    pub fn witnesses_by_harm(&self, harm: &HarmType) -> Vec<&HarmWitness> {
+        self.witnesses_by_harm
+            .get(harm)
+            .unwrap_or(&vec![])
+            .iter()
+            .filter_map(|id| self.witnesses.get(id))
+            .collect()
+    }
+
+    pub fn witnesses(&self) -> impl Iterator<Item = &HarmWitness> {
+        self.witnesses.values()
+    }
+
+    /// Все свидетельства против конкретного события
+    pub fn witnesses_against(&self, event_id: &str) -> Vec<&HarmWitness> {
+        self.witnesses
+            .values()
+            .filter(|w| w.violating_event_id == event_id)
+            .collect()
+    }
+
+    /// Приём события извне (другой узел, импорт) — только через этический конвейер
+    pub fn ingest(&mut self, event: OntoEvent, pipeline: &EthicsPipeline) -> Result<(), EthicsReport> {
+        pipeline.enforce(&event)?;
+        self.append(event, pipeline.keys());
+        Ok(())
     }
 
     pub fn get(&self, id: &str) -> Option<&OntoEvent> {
@@ -80,9 +165,36 @@ impl ActivityLedger {
             .collect()
     }
 
+    pub fn events(&self) -> impl Iterator<Item = &OntoEvent> {
+        self.events.values()
+    }
+
     /// Экспорт для синхронизации (например, в IPFS)
     pub fn export_as_json(&self) -> serde_json::Result<String> {
         serde_json::to_string(&self.events)
     }
+
+    /// Экспорт в JSON-LD (контекст ontoCMS) — для загрузки в графы знаний
+    pub fn export_as_jsonld(&self) -> serde_json::Value {
+        json_ld::export_events(self.ordered_events())
+    }
+
+    /// Экспорт в RDF N-Quads — детерминирован, пригоден для хеширования журнала
+    pub fn export_as_nquads(&self, graph: Option<&str>) -> Result<String, JsonLdError> {
+        json_ld::events_to_nquads(self.ordered_events(), graph)
+    }
+
+    fn ordered_events(&self) -> Vec<&OntoEvent> {
+        let mut events: Vec<&OntoEvent> = self.events.values().collect();
+        events.sort_by(|a, b| (a.timestamp, &a.id).cmp(&(b.timestamp, &b.id)));
+        events
+    }
+}
+
+/// Повторное добавление того же события не дублирует индексы
+fn push_unique(ids: &mut Vec<String>, id: &str) {
+    if !ids.iter().any(|known| known == id) {
+        ids.push(id.to_string());
+    }
 }
 ```
\ No newline at end of file
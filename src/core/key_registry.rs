@@ -0,0 +1,60 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCMS Core — Trusted Signing Keys of Profiles
+
+use std::collections::{HashMap, HashSet};
+
+use libp2p::PeerId;
+use serde::{Deserialize, Serialize};
+
+use crate::core::activity_ledger::ProfileId;
+use crate::core::signature::Signature;
+
+/// Ключи, которыми профили подписывают записи журнала (свидетельства, согласия, решения)
+/// Подпись проверяется ключом, встроенным в саму запись, — это доказывает только целостность;
+/// от имени профиля запись подписана, только если её ключ зарегистрирован за профилем здесь
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct KeyRegistry {
+    #[serde(flatten)]
+    keys: HashMap<ProfileId, HashSet<String>>, // профиль → PeerId ключей (base58)
+}
+
+impl KeyRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_key(mut self, profile: ProfileId, key: &PeerId) -> Self {
+        self.register(profile, key);
+        self
+    }
+
+    pub fn register(&mut self, profile: ProfileId, key: &PeerId) {
+        self.keys.entry(profile).or_default().insert(key.to_base58());
+    }
+
+    pub fn is_registered(&self, profile: &ProfileId, key: &PeerId) -> bool {
+        self.keys.get(profile).is_some_and(|keys| keys.contains(&key.to_base58()))
+    }
+
+    /// Подпись сделана ключом, зарегистрированным за профилем (сама подпись проверяется отдельно)
+    pub fn signed_by(&self, profile: &ProfileId, signature: Option<&Signature>) -> bool {
+        signature
+            .and_then(|s| s.signer().ok())
+            .is_some_and(|key| self.is_registered(profile, &key))
+    }
+
+    /// Профили, за которыми зарегистрирован ключ
+    pub fn profiles_of(&self, key: &PeerId) -> Vec<&ProfileId> {
+        let key = key.to_base58();
+        let mut profiles: Vec<&ProfileId> = self
+            .keys
+            .iter()
+            .filter(|(_, keys)| keys.contains(&key))
+            .map(|(profile, _)| profile)
+            .collect();
+        profiles.sort_by(|a, b| a.0.cmp(&b.0));
+        profiles
+    }
+}
+```
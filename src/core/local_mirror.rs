@@ -4,10 +4,44 @@
 
 use std::collections::HashMap;
 use std::fs;
+use std::io;
 use std::path::Path;
 
+use serde::{Deserialize, Serialize};
+
 use crate::core::activity_ledger::{OntoEvent, ProfileId};
 
+/// Первая пауза перед повторной публикацией свидетельства
+const OUTBOX_BASE_BACKOFF_MS: u64 = 5_000;
+/// Верхняя граница паузы — свидетельство не откладывается больше чем на час
+const OUTBOX_MAX_BACKOFF_MS: u64 = 3_600_000;
+
+/// Состояние доставки свидетельства о вреде в Public Hub
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum DeliveryState {
+    Pending,
+    Delivered { cid: String, delivered_at: u64 },
+    Rejected { reason: String, rejected_at: u64 }, // не прошло этический конвейер — не публикуется
+}
+
+/// Запись исходящей очереди свидетельств (Закон III)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OutboxEntry {
+    pub event: OntoEvent,          // событие-свидетельство
+    pub state: DeliveryState,
+    pub attempts: u32,
+    pub next_attempt_at: u64,      // Unix timestamp (мс)
+    pub last_error: Option<String>,
+    pub recorded_at: u64,
+}
+
+/// Снимок outbox свидетельств
+#[derive(Debug, Default)]
+pub struct OutboxStatus {
+    pub entries: Vec<OutboxEntry>,
+    pub unreadable: Vec<(String, String)>, // (путь, ошибка): повреждённые или недописанные файлы
+}
+
 /// Локальное зеркало событий — автономное, не требует сети
 pub struct LocalMirror {
     base_path: String,
@@ -25,15 +59,12 @@ impl LocalMirror {
     }
 
     /// Сохранение события на диск — идемпотентно
-    pub fn persist(&self, event: &OntoEvent) {
-        let file_path = format!(
-            "{}/{}/{}.json",
-            self.base_path,
-            event.profile_id.0,
-            event.id
-        );
-        fs::create_dir_all(Path::new(&file_path).parent().unwrap()).ok();
-        let _ = fs::write(&file_path, serde_json::to_vec_pretty(event).unwrap());
+    /// Ошибка записи возвращается: событие не считается сохранённым, пока его нет на диске
+    pub fn persist(&self, event: &OntoEvent) -> io::Result<()> {
+        let dir = format!("{}/{}", self.base_path, event.profile_id.0);
+        fs::create_dir_all(&dir)?;
+        let bytes = serde_json::to_vec_pretty(event).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(format!("{}/{}.json", dir, event.id), bytes)
     }
 
     /// Загрузка всех событий профиля
@@ -59,10 +90,190 @@ impl LocalMirror {
         events
     }
 
+    /// Снимок всех событий зеркала по всем профилям (без outbox)
+    /// В отличие от `load_profile` не помечает профили загруженными
+    pub fn load_all(&self) -> Vec<OntoEvent> {
+        let mut events = vec![];
+        let Ok(profiles) = fs::read_dir(&self.base_path) else { return events };
+        for profile in profiles.flatten() {
+            if profile.file_name().to_string_lossy().starts_with('.') || !profile.path().is_dir() {
+                continue;
+            }
+            for entry in fs::read_dir(profile.path()).into_iter().flatten().flatten() {
+                if let Ok(content) = fs::read_to_string(entry.path()) {
+                    if let Ok(event) = serde_json::from_str::<OntoEvent>(&content) {
+                        events.push(event);
+                    }
+                }
+            }
+        }
+        events
+    }
+
     /// Проверка: есть ли локально событие
     pub fn has_event(&self, profile: &ProfileId, event_id: &str) -> bool {
         let path = format!("{}/{}/{}.json", self.base_path, profile.0, event_id);
         Path::new(&path).exists()
     }
+
+    /// Событие по id в любом профиле зеркала
+    pub fn find_event(&self, event_id: &str) -> Option<OntoEvent> {
+        let profiles = fs::read_dir(&self.base_path).ok()?;
+        profiles.flatten().find_map(|profile| {
+            if profile.file_name().to_string_lossy().starts_with('.') {
+                return None; // .outbox, .checkpoints
+            }
+            let content = fs::read_to_string(profile.path().join(format!("{}.json", event_id))).ok()?;
+            serde_json::from_str(&content).ok()
+        })
+    }
+
+    /// Отметка импортёра (например, последний принятый коммит git); `None` — импорт ещё не выполнялся
+    pub fn checkpoint(&self, name: &str) -> Option<String> {
+        let value = fs::read_to_string(format!("{}/.checkpoints/{}", self.base_path, name)).ok()?;
+        Some(value.trim().to_string()).filter(|v| !v.is_empty())
+    }
+
+    pub fn set_checkpoint(&self, name: &str, value: &str) -> io::Result<()> {
+        let dir = format!("{}/.checkpoints", self.base_path);
+        fs::create_dir_all(&dir)?;
+        fs::write(format!("{}/{}", dir, name), format!("{}\n", value))
+    }
+
+    /// Запись свидетельства в outbox — до любой попытки публикации
+    /// Идемпотентно: повторная запись не сбрасывает счётчик попыток
+    pub fn record_witness(&self, event: &OntoEvent) -> io::Result<OutboxEntry> {
+        self.persist(event)?;
+
+        if let Some(existing) = self.outbox_entry(&event.id)? {
+            return Ok(existing);
+        }
+        let now = now_ms();
+        let entry = OutboxEntry {
+            event: event.clone(),
+            state: DeliveryState::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            recorded_at: now,
+        };
+        self.write_outbox_entry(&entry)?;
+        Ok(entry)
+    }
+
+    /// Недоставленные свидетельства, для которых наступило время повтора
+    pub fn due_witnesses(&self, now: u64) -> io::Result<Vec<OutboxEntry>> {
+        Ok(self
+            .outbox_status()?
+            .entries
+            .into_iter()
+            .filter(|e| e.state == DeliveryState::Pending && e.next_attempt_at <= now)
+            .collect())
+    }
+
+    pub fn mark_witness_delivered(&self, event_id: &str, cid: &str) -> io::Result<OutboxEntry> {
+        let mut entry = self.require_outbox_entry(event_id)?;
+        entry.attempts += 1;
+        entry.last_error = None;
+        entry.state = DeliveryState::Delivered {
+            cid: cid.to_string(),
+            delivered_at: now_ms(),
+        };
+        self.write_outbox_entry(&entry)?;
+        Ok(entry)
+    }
+
+    /// Свидетельство отклонено конвейером перед публикацией: больше не повторяется
+    pub fn mark_witness_rejected(&self, event_id: &str, reason: &str) -> io::Result<OutboxEntry> {
+        let mut entry = self.require_outbox_entry(event_id)?;
+        entry.last_error = Some(reason.to_string());
+        entry.state = DeliveryState::Rejected {
+            reason: reason.to_string(),
+            rejected_at: now_ms(),
+        };
+        self.write_outbox_entry(&entry)?;
+        Ok(entry)
+    }
+
+    /// Неудачная попытка: экспоненциальная пауза до следующего повтора
+    pub fn mark_witness_failed(&self, event_id: &str, error: &str) -> io::Result<OutboxEntry> {
+        let mut entry = self.require_outbox_entry(event_id)?;
+        entry.attempts += 1;
+        entry.last_error = Some(error.to_string());
+        entry.next_attempt_at = now_ms() + outbox_backoff_ms(entry.attempts);
+        self.write_outbox_entry(&entry)?;
+        Ok(entry)
+    }
+
+    /// Все записи outbox (доставленные, ожидающие и отклонённые), по времени записи
+    /// Нечитаемый файл не останавливает остальные записи: он остаётся на диске и перечисляется отдельно
+    pub fn outbox_status(&self) -> io::Result<OutboxStatus> {
+        let mut status = OutboxStatus::default();
+        let dir = self.outbox_dir();
+        if !Path::new(&dir).exists() {
+            return Ok(status);
+        }
+        for entry in fs::read_dir(&dir)?.flatten() {
+            let path = entry.path();
+            if path.extension() != Some(std::ffi::OsStr::new("json")) {
+                continue;
+            }
+            let parsed = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| serde_json::from_str::<OutboxEntry>(&content).map_err(|e| e.to_string()));
+            match parsed {
+                Ok(parsed) => status.entries.push(parsed),
+                Err(error) => status.unreadable.push((path.display().to_string(), error)),
+            }
+        }
+        status.entries.sort_by_key(|e| e.recorded_at);
+        status.unreadable.sort();
+        Ok(status)
+    }
+
+    fn outbox_dir(&self) -> String {
+        format!("{}/.outbox", self.base_path)
+    }
+
+    fn outbox_entry(&self, event_id: &str) -> io::Result<Option<OutboxEntry>> {
+        let path = format!("{}/{}.json", self.outbox_dir(), event_id);
+        match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn require_outbox_entry(&self, event_id: &str) -> io::Result<OutboxEntry> {
+        self.outbox_entry(event_id)?.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, format!("witness {} is not in outbox", event_id))
+        })
+    }
+
+    /// Запись через временный файл + rename: запись не теряется при сбое посередине
+    fn write_outbox_entry(&self, entry: &OutboxEntry) -> io::Result<()> {
+        let dir = self.outbox_dir();
+        fs::create_dir_all(&dir)?;
+        let bytes = serde_json::to_vec_pretty(entry).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let tmp_path = format!("{}/{}.json.tmp", dir, entry.event.id);
+        fs::write(&tmp_path, bytes)?;
+        fs::rename(&tmp_path, format!("{}/{}.json", dir, entry.event.id))
+    }
+}
+
+/// Пауза перед попыткой номер `attempts + 1`: 5 с, 10 с, 20 с, … но не более часа
+pub fn outbox_backoff_ms(attempts: u32) -> u64 {
+    OUTBOX_BASE_BACKOFF_MS
+        .saturating_mul(1u64 << attempts.saturating_sub(1).min(20))
+        .min(OUTBOX_MAX_BACKOFF_MS)
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
 }
 ```
\ No newline at end of file
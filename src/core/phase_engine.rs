@@ -3,11 +3,15 @@
 // OntoCMS Core — Phase Engine (Ontogenetic State Manager)
 
 use crate::core::activity_ledger::{OntoEvent, OntoPhase, ProfileId};
+use crate::core::social_context::SocialContext;
+use crate::ethics::pipeline::{EthicsPipeline, EthicsReport};
+use crate::transport::json_ld::{self, JsonLdError};
 
 /// Движок управления фазами онтогенеза
 pub struct PhaseEngine {
     current_phase: OntoPhase,
     profile: ProfileId,
+    causal_head: Option<String>, // хеш последнего принятого события этого движка
 }
 
 impl PhaseEngine {
@@ -15,6 +19,7 @@ impl PhaseEngine {
         Self {
             current_phase: initial_phase,
             profile,
+            causal_head: None,
         }
     }
 
@@ -22,6 +27,16 @@ impl PhaseEngine {
         &self.current_phase
     }
 
+    /// Начало или продолжение причинной цепочки (например, invariant_hash профиля
+    /// или хеш последнего события из LocalMirror)
+    pub fn set_causal_head(&mut self, hash: String) {
+        self.causal_head = Some(hash);
+    }
+
+    pub fn causal_head(&self) -> Option<&str> {
+        self.causal_head.as_deref()
+    }
+
     /// Переход в новую фазу — с этической проверкой
     pub fn transition_to(&mut self, new_phase: OntoPhase) -> Result<(), PhaseError> {
         // Закон III: в фазе Slow разрешено свидетельствовать о вреде
@@ -39,25 +54,53 @@ impl PhaseEngine {
     }
 
     /// Создание события в текущей фазе
-    pub fn emit_event(&self, payload: serde_json::Value, social_proximity: u8) -> OntoEvent {
+    pub fn emit_event(&self, payload: serde_json::Value, social_context: SocialContext) -> OntoEvent {
         let id = uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext));
         OntoEvent {
             id: id.to_string(),
             profile_id: self.profile.clone(),
             phase: self.current_phase.clone(),
             payload,
-            social_proximity,
-            causal_hash: None, // Заполняется внешней системой
+            social_context,
+            causal_hash: self.causal_head.clone(), // None, пока цепочка не начата
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_millis() as u64,
         }
     }
+
+    /// Создание события через этический конвейер (API агентов)
+    /// Принятое событие становится новой головой причинной цепочки
+    pub fn emit_checked(
+        &mut self,
+        payload: serde_json::Value,
+        social_context: SocialContext,
+        pipeline: &EthicsPipeline,
+    ) -> Result<OntoEvent, PhaseError> {
+        let event = self.emit_event(payload, social_context);
+        pipeline.enforce(&event).map_err(PhaseError::EthicsRejected)?;
+        self.causal_head = Some(json_ld::event_hash(&event).map_err(PhaseError::Hashing)?);
+        Ok(event)
+    }
 }
 
 #[derive(Debug)]
 pub enum PhaseError {
     InvalidTransition,
+    EthicsRejected(EthicsReport),
+    Hashing(JsonLdError),
 }
+
+impl std::fmt::Display for PhaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PhaseError::InvalidTransition => write!(f, "invalid phase transition"),
+            PhaseError::EthicsRejected(report) => write!(f, "{}", report),
+            PhaseError::Hashing(e) => write!(f, "cannot hash event: {:?}", e),
+        }
+    }
+}
+
+impl std::error::Error for PhaseError {}
 ```
\ No newline at end of file
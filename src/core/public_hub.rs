@@ -7,13 +7,22 @@ use libp2p::kad::{Kademlia, KademliaConfig};
 use libp2p::{identity, swarm::Swarm, Multiaddr, PeerId, Stream};
 use serde::{Deserialize, Serialize};
 
-use crate::core::activity_ledger::OntoEvent;
+use crate::core::activity_ledger::{OntoEvent, ProfileId};
+use crate::core::key_registry::KeyRegistry;
+use crate::core::quarantine::{IsolationRecord, QuarantineError, QuarantinePolicy, QuarantineRegistry};
+use crate::ethics::law3_harm_witness::HarmWitness;
+use crate::ethics::pipeline::{EthicsPipeline, EthicsReport};
 
 #[derive(Serialize, Deserialize)]
 pub struct HubConfig {
     pub bootstrap_peers: Vec<Multiaddr>,
     pub topic: String, // e.g., "/ontoCMS/v1/Neutral_Core-000"
     pub ipfs_gateway: Option<String>,
+    pub node_profile: ProfileId, // от имени этого профиля записываются события изоляции
+    #[serde(default)]
+    pub quarantine: QuarantinePolicy,
+    #[serde(default)]
+    pub trusted_keys: KeyRegistry, // ключи профилей, подписывающих свидетельства и решения о снятии изоляции
 }
 
 /// Абстракция для публикации в децентрализованную сеть
@@ -27,6 +36,8 @@ pub trait PublicHub {
 pub struct IpfsHub {
     swarm: Swarm<Kademlia<libp2p::kad::store::MemoryStore>>,
     config: HubConfig,
+    quarantine: QuarantineRegistry,
+    pipeline: EthicsPipeline,
 }
 
 impl IpfsHub {
@@ -36,7 +47,7 @@ impl IpfsHub {
         let mut kad_config = KademliaConfig::default();
         kad_config.set_protocol_names(vec![config.topic.clone().into_bytes().into()]);
 
-        let mut swarm = libp2p::SwarmBuilder::with_existing_identity(local_key)
+        let mut swarm = libp2p::SwarmBuilder::with_existing_identity(local_key.clone())
             .with_async_std()
             .with_behaviour(|_| Kademlia::with_config(local_peer_id, libp2p::kad::store::MemoryStore::new(local_peer_id), kad_config))
             .expect("Failed to build Kademlia")
@@ -46,13 +57,62 @@ impl IpfsHub {
             swarm.behaviour_mut().add_address(&PeerId::random(), addr.clone());
         }
 
-        Self { swarm, config }
+        let quarantine = QuarantineRegistry::new(config.quarantine.clone(), config.node_profile.clone(), local_key)
+            .with_keys(config.trusted_keys.clone());
+        // Узел доверяет собственным решениям об изоляции: ключ узла — ключ `node_profile`
+        let pipeline = EthicsPipeline::new()
+            .with_keys(config.trusted_keys.clone().with_key(config.node_profile.clone(), &local_peer_id));
+        Self {
+            swarm,
+            config,
+            quarantine,
+            pipeline,
+        }
+    }
+
+    /// Замена этического конвейера (например, со схемами payload)
+    /// Ключ узла (`peer_id`) должен быть зарегистрирован в нём за `node_profile`,
+    /// иначе события изоляции сканируются правилами вреда как обычный payload
+    pub fn with_pipeline(mut self, pipeline: EthicsPipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    /// Конвейер хаба — для записи событий изоляции в ActivityLedger
+    pub fn pipeline(&self) -> &EthicsPipeline {
+        &self.pipeline
+    }
+
+    /// Пересылка события, полученного от другого узла
+    /// Сообщения изолированных узлов не пересылаются (Закон III: попытка обхода → изоляция)
+    pub async fn forward(&mut self, from: PeerId, event: &OntoEvent) -> Result<String, HubError> {
+        if self.quarantine.is_quarantined(&from) {
+            return Err(HubError::PeerQuarantined(from));
+        }
+        self.quarantine.observe_event(from, event)?;
+        self.publish(event).await
+    }
+
+    /// Учёт свидетельства о вреде; при достижении порога узел изолируется
+    /// Возвращённое событие изоляции следует записать в ActivityLedger через `pipeline()`
+    pub fn observe_witness(&mut self, witness: &HarmWitness) -> Result<Option<IsolationRecord>, QuarantineError> {
+        self.quarantine.observe_witness(witness)
+    }
+
+    pub fn quarantine(&self) -> &QuarantineRegistry {
+        &self.quarantine
+    }
+
+    pub fn quarantine_mut(&mut self) -> &mut QuarantineRegistry {
+        &mut self.quarantine
     }
 }
 
 #[async_trait]
 impl PublicHub for IpfsHub {
     async fn publish(&self, event: &OntoEvent) -> Result<String, HubError> {
+        // В сеть уходят только события, прошедшие Три закона
+        self.pipeline.enforce(event).map_err(HubError::EthicsRejected)?;
         let json = serde_json::to_vec(event).map_err(|_| HubError::Serialization)?;
         // В реальной реализации: публикация в IPFS + CID возвращается
         let cid = "bafybeig..."; // имитация
@@ -74,5 +134,29 @@ pub enum HubError {
     Serialization,
     Network,
     NotImplemented,
+    PeerQuarantined(PeerId),
+    EthicsRejected(EthicsReport),
+    Quarantine(QuarantineError),
+}
+
+impl From<QuarantineError> for HubError {
+    fn from(e: QuarantineError) -> Self {
+        HubError::Quarantine(e)
+    }
 }
+
+impl std::fmt::Display for HubError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HubError::Serialization => write!(f, "event serialization failed"),
+            HubError::Network => write!(f, "network error"),
+            HubError::NotImplemented => write!(f, "not implemented"),
+            HubError::PeerQuarantined(peer) => write!(f, "peer {} is quarantined", peer),
+            HubError::EthicsRejected(report) => write!(f, "{}", report),
+            HubError::Quarantine(e) => write!(f, "quarantine: {:?}", e),
+        }
+    }
+}
+
+impl std::error::Error for HubError {}
 ```
\ No newline at end of file
@@ -0,0 +1,407 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCMS Core — Node Quarantine (Isolation After Repeated Law Violations)
+
+use std::collections::{HashMap, HashSet};
+
+use libp2p::identity::Keypair;
+use libp2p::PeerId;
+use serde::{Deserialize, Serialize};
+
+use crate::core::activity_ledger::{OntoEvent, OntoPhase, ProfileId};
+use crate::core::key_registry::KeyRegistry;
+use crate::core::signature::{self, Signature, SignatureError};
+use crate::core::social_context::{AudienceScope, RelationKind, SocialContext};
+use crate::ethics::law3_harm_witness::{HarmType, HarmWitness};
+use crate::transport::json_ld::{self, JsonLdError};
+
+/// Политика изоляции узла
+/// Без оценок и рейтингов: узел изолируется, только когда число нарушающих событий
+/// со свидетельствами, подписанными зарегистрированными ключами свидетелей, достигает порога
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct QuarantinePolicy {
+    pub violation_threshold: usize, // различных нарушающих событий
+    #[serde(default = "default_min_witnesses")]
+    pub min_witnesses: usize,       // различных профилей-свидетелей в цепочке
+    pub window_ms: Option<u64>,     // окно наблюдения по времени приёма; None — вся история
+    pub harm_types: Vec<HarmType>,  // учитываемые типы вреда; пусто — все
+}
+
+impl Default for QuarantinePolicy {
+    fn default() -> Self {
+        Self {
+            violation_threshold: 3,
+            min_witnesses: default_min_witnesses(),
+            window_ms: Some(24 * 60 * 60 * 1000),
+            harm_types: vec![],
+        }
+    }
+}
+
+/// Один свидетель не изолирует узел в одиночку
+fn default_min_witnesses() -> usize {
+    2
+}
+
+/// Звено цепочки доказательств: свидетельство → нарушающее событие
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EvidenceLink {
+    pub witness_id: String,
+    pub witness_profile: ProfileId,
+    pub violating_event_id: String,
+    pub causal_hash: String, // хеш нарушающего события
+    pub detected_harm: HarmType,
+    pub timestamp: u64,   // время, указанное свидетелем
+    pub observed_at: u64, // время приёма свидетельства этим узлом
+}
+
+/// Решение об изоляции — подписанная запись журнала, которую узел записывает от имени `node_profile`
+/// Подпись ключом, зарегистрированным за `node_profile`, снимает сканирование типов вреда
+/// в цепочке и политике: это имена типов, а не вред (как у доказательств свидетельства)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NodeIsolation {
+    pub id: String,
+    pub peer: String, // PeerId (base58)
+    pub node_profile: ProfileId,
+    pub policy: QuarantinePolicy,
+    pub evidence_chain: Vec<EvidenceLink>,
+    pub causal_hash: String, // хеш события свидетельства, доведшего до порога
+    pub timestamp: u64,
+    pub signature: Option<Signature>, // подпись ключом узла
+}
+
+impl NodeIsolation {
+    pub fn sign(&mut self, keypair: &Keypair) -> Result<(), SignatureError> {
+        self.signature = None;
+        let message = signature::canonical_bytes(self)?;
+        self.signature = Some(Signature::sign(keypair, &message)?);
+        Ok(())
+    }
+
+    pub fn verify(&self) -> Result<(), SignatureError> {
+        let signature = self.signature.as_ref().ok_or(SignatureError::Missing)?;
+        let unsigned = NodeIsolation {
+            signature: None,
+            ..self.clone()
+        };
+        signature.verify(&signature::canonical_bytes(&unsigned)?)
+    }
+
+    pub fn to_event(&self) -> Result<OntoEvent, serde_json::Error> {
+        let mut payload = serde_json::to_value(self)?;
+        if let Some(obj) = payload.as_object_mut() {
+            obj.insert("type".into(), "node_isolation".into());
+            obj.insert("schema_version".into(), 2.into());
+        }
+
+        Ok(OntoEvent {
+            id: self.id.clone(),
+            profile_id: self.node_profile.clone(),
+            phase: OntoPhase::Slow,
+            payload,
+            social_context: SocialContext::new(100)
+                .with_relation(RelationKind::Community)
+                .with_audience(AudienceScope::Public),
+            causal_hash: Some(self.causal_hash.clone()),
+            timestamp: self.timestamp,
+        })
+    }
+
+    /// Восстановление решения из события журнала; поля события вне payload должны совпадать с подписанной записью
+    pub fn from_event(event: &OntoEvent) -> Option<NodeIsolation> {
+        if event.payload.get("type").and_then(|t| t.as_str()) != Some("node_isolation") {
+            return None;
+        }
+        let isolation: NodeIsolation = serde_json::from_value(event.payload.clone()).ok()?;
+        (isolation.id == event.id
+            && event.profile_id == isolation.node_profile
+            && event.causal_hash.as_deref() == Some(isolation.causal_hash.as_str())
+            && event.timestamp == isolation.timestamp)
+            .then_some(isolation)
+    }
+}
+
+/// Запись об изоляции узла
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IsolationRecord {
+    pub peer: String, // PeerId (base58)
+    pub isolated_at: u64,
+    pub evidence_chain: Vec<EvidenceLink>,
+    pub event: OntoEvent, // событие изоляции (`NodeIsolation`) — записывается в журнал
+}
+
+/// Подписанное решение о снятии изоляции
+/// Рецензент подтверждает, что рассмотрел каждое свидетельство цепочки;
+/// `reviewer_profile` должен быть зарегистрирован за ключом подписи
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LiftRequest {
+    pub peer: String,
+    pub isolation_event_id: String,
+    pub reviewer_profile: ProfileId,
+    pub reviewed_witness_ids: Vec<String>,
+    pub reason: String,
+    pub timestamp: u64,
+    pub signature: Option<Signature>,
+}
+
+impl LiftRequest {
+    pub fn sign(&mut self, keypair: &Keypair) -> Result<(), SignatureError> {
+        self.signature = None;
+        let message = signature::canonical_bytes(self)?;
+        self.signature = Some(Signature::sign(keypair, &message)?);
+        Ok(())
+    }
+
+    pub fn verify(&self) -> Result<(), SignatureError> {
+        let signature = self.signature.as_ref().ok_or(SignatureError::Missing)?;
+        let unsigned = LiftRequest {
+            signature: None,
+            ..self.clone()
+        };
+        signature.verify(&signature::canonical_bytes(&unsigned)?)
+    }
+}
+
+#[derive(Debug)]
+pub enum QuarantineError {
+    UnverifiedWitness(SignatureError),
+    UntrustedWitness { witness_profile: ProfileId },
+    CausalHashMismatch { violating_event_id: String },
+    InvalidPeer(String),
+    NotQuarantined,
+    IsolationEventMismatch,
+    UnverifiedLift(SignatureError),
+    UntrustedReviewer { reviewer_profile: ProfileId },
+    ReviewerIsPeer,
+    IncompleteReview { missing: Vec<String> },
+    ReviewerIsWitness,
+    Hashing(JsonLdError),
+    Serialization(serde_json::Error),
+    Signing(SignatureError),
+}
+
+/// Реестр изоляции узлов Public Hub
+pub struct QuarantineRegistry {
+    policy: QuarantinePolicy,
+    node_profile: ProfileId,
+    node_key: Keypair,
+    keys: KeyRegistry,
+    origins: HashMap<String, (PeerId, String)>, // id события → (узел-источник, хеш события)
+    evidence: HashMap<PeerId, Vec<EvidenceLink>>,
+    quarantined: HashMap<PeerId, IsolationRecord>,
+}
+
+impl QuarantineRegistry {
+    /// `node_profile` — профиль, от имени которого узел записывает события изоляции (Закон I);
+    /// `node_key` подписывает их и должен быть зарегистрирован за `node_profile` в конвейере журнала
+    pub fn new(policy: QuarantinePolicy, node_profile: ProfileId, node_key: Keypair) -> Self {
+        Self {
+            policy,
+            node_profile,
+            node_key,
+            keys: KeyRegistry::new(),
+            origins: HashMap::new(),
+            evidence: HashMap::new(),
+            quarantined: HashMap::new(),
+        }
+    }
+
+    /// Ключи профилей: учитываются только свидетельства и решения, подписанные ключами своих профилей
+    pub fn with_keys(mut self, keys: KeyRegistry) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    pub fn policy(&self) -> &QuarantinePolicy {
+        &self.policy
+    }
+
+    /// Запоминание источника события, полученного от другого узла
+    /// Хеш нужен, чтобы свидетельство указывало именно на полученное событие
+    pub fn observe_event(&mut self, peer: PeerId, event: &OntoEvent) -> Result<(), QuarantineError> {
+        let hash = json_ld::event_hash(event).map_err(QuarantineError::Hashing)?;
+        self.origins.insert(event.id.clone(), (peer, hash));
+        Ok(())
+    }
+
+    pub fn is_quarantined(&self, peer: &PeerId) -> bool {
+        self.quarantined.contains_key(peer)
+    }
+
+    pub fn isolation(&self, peer: &PeerId) -> Option<&IsolationRecord> {
+        self.quarantined.get(peer)
+    }
+
+    pub fn quarantined_peers(&self) -> Vec<&IsolationRecord> {
+        self.quarantined.values().collect()
+    }
+
+    /// Учёт свидетельства о вреде против события узла
+    /// Возвращает запись изоляции, если свидетельство довело узел до порога политики
+    pub fn observe_witness(&mut self, witness: &HarmWitness) -> Result<Option<IsolationRecord>, QuarantineError> {
+        witness.verify().map_err(QuarantineError::UnverifiedWitness)?;
+        // Ключ встроен в свидетельство: без регистрации за профилем им может подписать кто угодно
+        if !self.keys.signed_by(&witness.witness_profile, witness.signature.as_ref()) {
+            return Err(QuarantineError::UntrustedWitness {
+                witness_profile: witness.witness_profile.clone(),
+            });
+        }
+
+        let Some((peer, event_hash)) = self.origins.get(&witness.violating_event_id).cloned() else {
+            return Ok(None); // событие не от удалённого узла
+        };
+        if witness.causal_hash != event_hash {
+            return Err(QuarantineError::CausalHashMismatch {
+                violating_event_id: witness.violating_event_id.clone(),
+            });
+        }
+        if !self.policy.harm_types.is_empty() && !self.policy.harm_types.contains(&witness.detected_harm) {
+            return Ok(None);
+        }
+
+        let now = now_ms();
+        let links = self.evidence.entry(peer).or_default();
+        if links.iter().any(|l| l.witness_id == witness.id) {
+            return Ok(None);
+        }
+        links.push(EvidenceLink {
+            witness_id: witness.id.clone(),
+            witness_profile: witness.witness_profile.clone(),
+            violating_event_id: witness.violating_event_id.clone(),
+            causal_hash: witness.causal_hash.clone(),
+            detected_harm: witness.detected_harm.clone(),
+            timestamp: witness.timestamp,
+            observed_at: now,
+        });
+
+        if self.quarantined.contains_key(&peer) {
+            return Ok(None);
+        }
+
+        // Окно считается по времени приёма: время в свидетельстве задаёт сам свидетель
+        let since = self.policy.window_ms.map_or(0, |window| now.saturating_sub(window));
+        let chain: Vec<EvidenceLink> = links.iter().filter(|l| l.observed_at >= since).cloned().collect();
+        let violations: HashSet<&str> = chain.iter().map(|l| l.violating_event_id.as_str()).collect();
+        let witnesses: HashSet<&ProfileId> = chain.iter().map(|l| &l.witness_profile).collect();
+        if violations.len() < self.policy.violation_threshold || witnesses.len() < self.policy.min_witnesses {
+            return Ok(None);
+        }
+
+        let record = self.isolate(peer, chain, witness)?;
+        self.quarantined.insert(peer, record.clone());
+        Ok(Some(record))
+    }
+
+    /// Снятие изоляции по подписанному решению рецензента
+    /// Рецензент определяется ключом подписи, а не текстом запроса
+    /// Возвращает событие снятия — его нужно записать в журнал рядом с событием изоляции
+    pub fn lift(&mut self, request: &LiftRequest) -> Result<OntoEvent, QuarantineError> {
+        request.verify().map_err(QuarantineError::UnverifiedLift)?;
+        let signer = request
+            .signature
+            .as_ref()
+            .ok_or(QuarantineError::UnverifiedLift(SignatureError::Missing))?
+            .signer()
+            .map_err(QuarantineError::UnverifiedLift)?;
+        let reviewer_profiles = self.keys.profiles_of(&signer);
+        if !reviewer_profiles.contains(&&request.reviewer_profile) {
+            return Err(QuarantineError::UntrustedReviewer {
+                reviewer_profile: request.reviewer_profile.clone(),
+            });
+        }
+
+        let peer: PeerId = request
+            .peer
+            .parse()
+            .map_err(|_| QuarantineError::InvalidPeer(request.peer.clone()))?;
+        let record = self.quarantined.get(&peer).ok_or(QuarantineError::NotQuarantined)?;
+        if record.event.id != request.isolation_event_id {
+            return Err(QuarantineError::IsolationEventMismatch);
+        }
+
+        // Рецензия независима: изолированный узел не снимает изоляцию сам,
+        // свидетель — по собственным свидетельствам (ни под одним из профилей своего ключа)
+        if signer == peer {
+            return Err(QuarantineError::ReviewerIsPeer);
+        }
+        if record
+            .evidence_chain
+            .iter()
+            .any(|l| reviewer_profiles.contains(&&l.witness_profile))
+        {
+            return Err(QuarantineError::ReviewerIsWitness);
+        }
+        let missing: Vec<String> = record
+            .evidence_chain
+            .iter()
+            .filter(|l| !request.reviewed_witness_ids.contains(&l.witness_id))
+            .map(|l| l.witness_id.clone())
+            .collect();
+        if !missing.is_empty() {
+            return Err(QuarantineError::IncompleteReview { missing });
+        }
+
+        let event = OntoEvent {
+            id: uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string(),
+            profile_id: request.reviewer_profile.clone(),
+            phase: OntoPhase::Slow,
+            payload: serde_json::json!({
+                "type": "node_isolation_lifted",
+                "schema_version": 1,
+                "peer": request.peer,
+                "isolation_event_id": request.isolation_event_id,
+                "reason": request.reason,
+                "reviewed_witness_ids": request.reviewed_witness_ids,
+                "signature": request.signature,
+            }),
+            social_context: SocialContext::new(100)
+                .with_relation(RelationKind::Community)
+                .with_audience(AudienceScope::Public),
+            causal_hash: Some(json_ld::event_hash(&record.event).map_err(QuarantineError::Hashing)?),
+            timestamp: request.timestamp,
+        };
+
+        self.quarantined.remove(&peer);
+        self.evidence.remove(&peer);
+        Ok(event)
+    }
+
+    fn isolate(
+        &self,
+        peer: PeerId,
+        chain: Vec<EvidenceLink>,
+        trigger: &HarmWitness,
+    ) -> Result<IsolationRecord, QuarantineError> {
+        let now = now_ms();
+
+        // Событие изоляции причинно связано со свидетельством, которое довело до порога
+        let trigger_event = trigger.to_event().map_err(QuarantineError::Serialization)?;
+        let mut isolation = NodeIsolation {
+            id: uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string(),
+            peer: peer.to_base58(),
+            node_profile: self.node_profile.clone(),
+            policy: self.policy.clone(),
+            evidence_chain: chain,
+            causal_hash: json_ld::event_hash(&trigger_event).map_err(QuarantineError::Hashing)?,
+            timestamp: now,
+            signature: None,
+        };
+        isolation.sign(&self.node_key).map_err(QuarantineError::Signing)?;
+        let event = isolation.to_event().map_err(QuarantineError::Serialization)?;
+
+        Ok(IsolationRecord {
+            peer: isolation.peer,
+            isolated_at: now,
+            evidence_chain: isolation.evidence_chain,
+            event,
+        })
+    }
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+```
@@ -0,0 +1,58 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCMS Core — Detached Signatures (libp2p Identity Keys)
+
+use libp2p::identity::{Keypair, PublicKey};
+use libp2p::PeerId;
+use serde::{Deserialize, Serialize};
+
+/// Отделённая подпись записи: ключ подписанта + подпись (hex)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub public_key: String, // protobuf-кодировка открытого ключа libp2p, hex
+    pub signature: String,  // hex
+}
+
+#[derive(Debug)]
+pub enum SignatureError {
+    Missing,
+    SigningFailed,
+    InvalidKey,
+    Invalid,
+}
+
+impl Signature {
+    pub fn sign(keypair: &Keypair, message: &[u8]) -> Result<Self, SignatureError> {
+        let signature = keypair.sign(message).map_err(|_| SignatureError::SigningFailed)?;
+        Ok(Self {
+            public_key: hex::encode(keypair.public().encode_protobuf()),
+            signature: hex::encode(signature),
+        })
+    }
+
+    pub fn verify(&self, message: &[u8]) -> Result<(), SignatureError> {
+        let signature = hex::decode(&self.signature).map_err(|_| SignatureError::Invalid)?;
+        if self.public_key()?.verify(message, &signature) {
+            Ok(())
+        } else {
+            Err(SignatureError::Invalid)
+        }
+    }
+
+    pub fn public_key(&self) -> Result<PublicKey, SignatureError> {
+        let bytes = hex::decode(&self.public_key).map_err(|_| SignatureError::InvalidKey)?;
+        PublicKey::try_decode_protobuf(&bytes).map_err(|_| SignatureError::InvalidKey)
+    }
+
+    /// Узел, которому принадлежит ключ подписи
+    pub fn signer(&self) -> Result<PeerId, SignatureError> {
+        Ok(self.public_key()?.to_peer_id())
+    }
+}
+
+/// Каноническое представление записи для подписи: JSON с отсортированными ключами
+pub fn canonical_bytes<T: Serialize>(record: &T) -> Result<Vec<u8>, SignatureError> {
+    let value = serde_json::to_value(record).map_err(|_| SignatureError::SigningFailed)?;
+    serde_json::to_vec(&value).map_err(|_| SignatureError::SigningFailed)
+}
+```
@@ -0,0 +1,171 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCMS Core — Social Context of an Event (Law II)
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Полоса социальной близости
+/// | Полоса   | proximity | Смысл                                          |
+/// |----------|-----------|------------------------------------------------|
+/// | `None`   | 0         | контекст не указан (в фазе Fast запрещено)      |
+/// | `Low`    | 1–33      | незнакомые люди, общественность                 |
+/// | `Medium` | 34–66     | сообщество, коллеги, участники проекта          |
+/// | `High`   | 67–100    | личные отношения, собственная работа профиля    |
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ProximityBand {
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+impl ProximityBand {
+    pub fn of(proximity: u8) -> Self {
+        match proximity {
+            0 => ProximityBand::None,
+            1..=33 => ProximityBand::Low,
+            34..=66 => ProximityBand::Medium,
+            _ => ProximityBand::High,
+        }
+    }
+}
+
+/// Отношение профиля к тем, кого касается событие
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationKind {
+    #[default]
+    Unspecified,
+    SelfAuthored,  // собственная работа профиля
+    Personal,      // личная переписка, близкие люди
+    Community,     // участники проекта или сообщества
+    Institutional, // организации, сервисы
+    Public,        // общественность, незнакомые люди
+}
+
+/// Кому адресовано событие — определяет, может ли оно покинуть узел
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AudienceScope {
+    #[default]
+    Unspecified,
+    Private,   // только профиль-автор
+    Circle,    // адресаты события
+    Community, // сообщество проекта
+    Public,    // без ограничений (Public Hub, Darwin Core)
+}
+
+/// Социальный контекст события
+/// Событие без отношения и аудитории сериализуется числом, как прежнее поле
+/// `social_proximity`, — старые записи читаются и хешируются без изменений
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct SocialContext {
+    pub proximity: u8, // 0–100
+    pub relation: RelationKind,
+    pub audience: AudienceScope,
+}
+
+impl SocialContext {
+    pub fn new(proximity: u8) -> Self {
+        Self {
+            proximity,
+            relation: RelationKind::Unspecified,
+            audience: AudienceScope::Unspecified,
+        }
+    }
+
+    pub fn with_relation(mut self, relation: RelationKind) -> Self {
+        self.relation = relation;
+        self
+    }
+
+    pub fn with_audience(mut self, audience: AudienceScope) -> Self {
+        self.audience = audience;
+        self
+    }
+
+    pub fn band(&self) -> ProximityBand {
+        ProximityBand::of(self.proximity)
+    }
+
+    /// Контекст из прежнего числового поля: только близость
+    pub fn is_legacy(&self) -> bool {
+        self.relation == RelationKind::Unspecified && self.audience == AudienceScope::Unspecified
+    }
+
+    /// Противоречия между близостью и отношением
+    pub fn inconsistency(&self) -> Option<&'static str> {
+        match (self.band(), self.relation, self.audience) {
+            (ProximityBand::None, RelationKind::Unspecified, AudienceScope::Unspecified) => None,
+            (ProximityBand::None, _, _) => Some("relation or audience declared with zero proximity"),
+            (ProximityBand::Low, RelationKind::SelfAuthored | RelationKind::Personal, _) => {
+                Some("personal or self-authored relation with low proximity")
+            }
+            (ProximityBand::High, RelationKind::Public, _) => Some("public relation with high proximity"),
+            _ => None,
+        }
+    }
+
+    /// Может ли событие покинуть узел в открытый набор данных
+    /// Без явной аудитории — только при низкой близости
+    pub fn is_publishable(&self) -> bool {
+        match self.audience {
+            AudienceScope::Public => self.relation != RelationKind::Personal,
+            AudienceScope::Unspecified => self.band() <= ProximityBand::Low,
+            _ => false,
+        }
+    }
+}
+
+impl From<u8> for SocialContext {
+    fn from(proximity: u8) -> Self {
+        Self::new(proximity)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SocialContextRepr {
+    Legacy(u8),
+    Structured {
+        proximity: u8,
+        #[serde(default)]
+        relation: RelationKind,
+        #[serde(default)]
+        audience: AudienceScope,
+    },
+}
+
+impl Serialize for SocialContext {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr = if self.is_legacy() {
+            SocialContextRepr::Legacy(self.proximity)
+        } else {
+            SocialContextRepr::Structured {
+                proximity: self.proximity,
+                relation: self.relation,
+                audience: self.audience,
+            }
+        };
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SocialContext {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match SocialContextRepr::deserialize(deserializer)? {
+            SocialContextRepr::Legacy(proximity) => Self::new(proximity),
+            SocialContextRepr::Structured {
+                proximity,
+                relation,
+                audience,
+            } => Self {
+                proximity,
+                relation,
+                audience,
+            },
+        })
+    }
+}
+```
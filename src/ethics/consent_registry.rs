@@ -0,0 +1,369 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// Закон III — Реестр отзывных согласий (биометрия и смежные данные)
+
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use libp2p::identity::Keypair;
+use serde::{Deserialize, Serialize};
+
+use crate::core::activity_ledger::{ActivityLedger, OntoEvent, OntoPhase, ProfileId};
+use crate::core::key_registry::KeyRegistry;
+use crate::core::signature::{self, Signature, SignatureError};
+use crate::core::social_context::{AudienceScope, RelationKind, SocialContext};
+use crate::transport::json_ld;
+
+/// Поле payload, которым событие ссылается на согласие
+pub const CONSENT_REFERENCE_KEY: &str = "consent_id";
+
+/// Согласие субъекта на обработку данных одним обработчиком для одной цели
+/// Подписывается зарегистрированным ключом субъекта; только тот же ключ может его отозвать
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ConsentGrant {
+    pub id: String,
+    pub subject: ProfileId, // чьи данные
+    pub grantee: ProfileId, // кому разрешена обработка: профиль событий, ссылающихся на согласие
+    pub purpose: String,    // цель обработки, например "biometric_processing"
+    pub granted_at: u64,
+    pub expires_at: Option<u64>,
+    pub causal_hash: String, // хеш события, в контексте которого дано согласие
+    pub signature: Option<Signature>,
+}
+
+/// Отзыв согласия — окончательный: отозванное согласие не восстанавливается
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ConsentRevocation {
+    pub id: String,
+    pub consent_id: String,
+    pub subject: ProfileId,
+    pub purpose: String,
+    pub revoked_at: u64,
+    pub reason: Option<String>,
+    pub causal_hash: String, // хеш события согласия
+    pub signature: Option<Signature>,
+}
+
+#[derive(Debug)]
+pub enum ConsentError {
+    Unsigned(SignatureError),
+    UnregisteredKey { subject: ProfileId }, // ключ подписи не зарегистрирован за субъектом
+    UnknownConsent(String),
+    SignerMismatch,
+    SubjectMismatch,
+    Revoked { revoked_at: u64 },
+    NotYetGranted,
+    Expired { expires_at: u64 },
+    GranteeMismatch { expected: ProfileId, found: ProfileId },
+    PurposeMismatch { expected: String, found: String },
+    InvalidRecord(String),
+}
+
+/// Проверка ссылки события на действующее согласие
+pub trait ConsentCheck: Send + Sync {
+    /// Согласие `consent_id` на цель `purpose` выдано `grantee` и действует в момент `at` (мс)
+    fn is_active(&self, consent_id: &str, purpose: &str, grantee: &ProfileId, at: u64) -> bool;
+}
+
+/// Без реестра согласий ни одно согласие не считается действующим
+pub struct NoConsents;
+
+impl ConsentCheck for NoConsents {
+    fn is_active(&self, _consent_id: &str, _purpose: &str, _grantee: &ProfileId, _at: u64) -> bool {
+        false
+    }
+}
+
+impl ConsentGrant {
+    pub fn new(subject: ProfileId, grantee: ProfileId, purpose: &str, causal_hash: String) -> Self {
+        Self {
+            id: uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string(),
+            subject,
+            grantee,
+            purpose: purpose.to_string(),
+            granted_at: now_ms(),
+            expires_at: None,
+            causal_hash,
+            signature: None,
+        }
+    }
+
+    pub fn sign(&mut self, keypair: &Keypair) -> Result<(), SignatureError> {
+        self.signature = None;
+        let message = signature::canonical_bytes(self)?;
+        self.signature = Some(Signature::sign(keypair, &message)?);
+        Ok(())
+    }
+
+    pub fn verify(&self) -> Result<(), SignatureError> {
+        let signature = self.signature.as_ref().ok_or(SignatureError::Missing)?;
+        let unsigned = ConsentGrant {
+            signature: None,
+            ..self.clone()
+        };
+        signature.verify(&signature::canonical_bytes(&unsigned)?)
+    }
+
+    /// Событие журнала, несущее согласие; атрибутировано субъекту (Закон I)
+    pub fn to_event(&self) -> OntoEvent {
+        record_event(&self.id, &self.subject, self, "consent_grant", &self.causal_hash, self.granted_at)
+    }
+
+    pub fn from_event(event: &OntoEvent) -> Option<ConsentGrant> {
+        record_from_event(event, "consent_grant").filter(|g: &ConsentGrant| g.id == event.id)
+    }
+}
+
+impl ConsentRevocation {
+    pub fn new(grant: &ConsentGrant, reason: Option<String>) -> Result<Self, ConsentError> {
+        let causal_hash = json_ld::event_hash(&grant.to_event())
+            .map_err(|e| ConsentError::InvalidRecord(format!("{:?}", e)))?;
+        Ok(Self {
+            id: uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string(),
+            consent_id: grant.id.clone(),
+            subject: grant.subject.clone(),
+            purpose: grant.purpose.clone(),
+            revoked_at: now_ms(),
+            reason,
+            causal_hash,
+            signature: None,
+        })
+    }
+
+    pub fn sign(&mut self, keypair: &Keypair) -> Result<(), SignatureError> {
+        self.signature = None;
+        let message = signature::canonical_bytes(self)?;
+        self.signature = Some(Signature::sign(keypair, &message)?);
+        Ok(())
+    }
+
+    pub fn verify(&self) -> Result<(), SignatureError> {
+        let signature = self.signature.as_ref().ok_or(SignatureError::Missing)?;
+        let unsigned = ConsentRevocation {
+            signature: None,
+            ..self.clone()
+        };
+        signature.verify(&signature::canonical_bytes(&unsigned)?)
+    }
+
+    pub fn to_event(&self) -> OntoEvent {
+        record_event(&self.id, &self.subject, self, "consent_revocation", &self.causal_hash, self.revoked_at)
+    }
+
+    pub fn from_event(event: &OntoEvent) -> Option<ConsentRevocation> {
+        record_from_event(event, "consent_revocation").filter(|r: &ConsentRevocation| r.id == event.id)
+    }
+}
+
+/// Реестр согласий по субъекту и цели
+/// Принимаются только записи, подписанные ключом, зарегистрированным за субъектом
+#[derive(Default)]
+pub struct ConsentRegistry {
+    keys: KeyRegistry,
+    grants: HashMap<String, ConsentGrant>,
+    revocations: HashMap<String, ConsentRevocation>, // id согласия → отзыв
+    by_subject: HashMap<(ProfileId, String), Vec<String>>,
+}
+
+impl ConsentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ключи субъектов; без них реестр не принимает ни одного согласия
+    pub fn with_keys(mut self, keys: KeyRegistry) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Реестр по событиям журнала; неподписанные, поддельные и чужие записи пропускаются
+    pub fn from_ledger(ledger: &ActivityLedger, keys: KeyRegistry) -> Self {
+        let mut registry = Self::new().with_keys(keys);
+        let mut events: Vec<&OntoEvent> = ledger.events().collect();
+        events.sort_by_key(|e| e.timestamp);
+        for event in events {
+            let _ = registry.observe_event(event);
+        }
+        registry
+    }
+
+    /// Учёт события журнала; `Ok(false)` — событие не относится к согласиям
+    pub fn observe_event(&mut self, event: &OntoEvent) -> Result<bool, ConsentError> {
+        if let Some(grant) = ConsentGrant::from_event(event) {
+            self.record_grant(grant)?;
+            return Ok(true);
+        }
+        if let Some(revocation) = ConsentRevocation::from_event(event) {
+            self.record_revocation(revocation)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    pub fn record_grant(&mut self, grant: ConsentGrant) -> Result<(), ConsentError> {
+        grant.verify().map_err(ConsentError::Unsigned)?;
+        // Ключ встроен в запись: согласие от имени субъекта даёт только его зарегистрированный ключ
+        if !self.keys.signed_by(&grant.subject, grant.signature.as_ref()) {
+            return Err(ConsentError::UnregisteredKey {
+                subject: grant.subject.clone(),
+            });
+        }
+        if grant.purpose.is_empty() {
+            return Err(ConsentError::InvalidRecord("consent without purpose".into()));
+        }
+        if self.grants.contains_key(&grant.id) {
+            return Ok(()); // идемпотентно
+        }
+        self.by_subject
+            .entry((grant.subject.clone(), grant.purpose.clone()))
+            .or_default()
+            .push(grant.id.clone());
+        self.grants.insert(grant.id.clone(), grant);
+        Ok(())
+    }
+
+    /// Отзыв принимается только с подписью того же ключа, что и согласие
+    pub fn record_revocation(&mut self, revocation: ConsentRevocation) -> Result<(), ConsentError> {
+        revocation.verify().map_err(ConsentError::Unsigned)?;
+        let grant = self
+            .grants
+            .get(&revocation.consent_id)
+            .ok_or_else(|| ConsentError::UnknownConsent(revocation.consent_id.clone()))?;
+        if grant.subject != revocation.subject || grant.purpose != revocation.purpose {
+            return Err(ConsentError::SubjectMismatch);
+        }
+        let granted_by = grant.signature.as_ref().map(|s| &s.public_key);
+        let revoked_by = revocation.signature.as_ref().map(|s| &s.public_key);
+        if granted_by != revoked_by {
+            return Err(ConsentError::SignerMismatch);
+        }
+
+        // Первый отзыв остаётся в силе
+        self.revocations
+            .entry(revocation.consent_id.clone())
+            .or_insert(revocation);
+        Ok(())
+    }
+
+    pub fn grant(&self, consent_id: &str) -> Option<&ConsentGrant> {
+        self.grants.get(consent_id)
+    }
+
+    pub fn revocation(&self, consent_id: &str) -> Option<&ConsentRevocation> {
+        self.revocations.get(consent_id)
+    }
+
+    /// Все согласия субъекта на цель, включая отозванные
+    pub fn consents_for(&self, subject: &ProfileId, purpose: &str) -> Vec<&ConsentGrant> {
+        self.by_subject
+            .get(&(subject.clone(), purpose.to_string()))
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.grants.get(id))
+            .collect()
+    }
+
+    /// Действующее согласие или причина, по которой его нет
+    /// `at` — время приёма события узлом, а не `timestamp` события: его задаёт отправитель
+    pub fn active_consent(
+        &self,
+        consent_id: &str,
+        purpose: &str,
+        grantee: &ProfileId,
+        at: u64,
+    ) -> Result<&ConsentGrant, ConsentError> {
+        let grant = self
+            .grants
+            .get(consent_id)
+            .ok_or_else(|| ConsentError::UnknownConsent(consent_id.to_string()))?;
+        if grant.purpose != purpose {
+            return Err(ConsentError::PurposeMismatch {
+                expected: purpose.to_string(),
+                found: grant.purpose.clone(),
+            });
+        }
+        if grant.grantee != *grantee {
+            return Err(ConsentError::GranteeMismatch {
+                expected: grant.grantee.clone(),
+                found: grantee.clone(),
+            });
+        }
+        // Известный отзыв действует сразу: событие нельзя «датировать» до отзыва
+        if let Some(revocation) = self.revocations.get(consent_id) {
+            return Err(ConsentError::Revoked {
+                revoked_at: revocation.revoked_at,
+            });
+        }
+        if at < grant.granted_at {
+            return Err(ConsentError::NotYetGranted);
+        }
+        if let Some(expires_at) = grant.expires_at.filter(|&t| at >= t) {
+            return Err(ConsentError::Expired { expires_at });
+        }
+        Ok(grant)
+    }
+}
+
+impl ConsentCheck for ConsentRegistry {
+    fn is_active(&self, consent_id: &str, purpose: &str, grantee: &ProfileId, at: u64) -> bool {
+        self.active_consent(consent_id, purpose, grantee, at).is_ok()
+    }
+}
+
+/// Общий реестр: журнал дописывает согласия, конвейер их читает
+impl<T: ConsentCheck> ConsentCheck for RwLock<T> {
+    fn is_active(&self, consent_id: &str, purpose: &str, grantee: &ProfileId, at: u64) -> bool {
+        self.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_active(consent_id, purpose, grantee, at)
+    }
+}
+
+impl<T: ConsentCheck + ?Sized> ConsentCheck for Arc<T> {
+    fn is_active(&self, consent_id: &str, purpose: &str, grantee: &ProfileId, at: u64) -> bool {
+        self.as_ref().is_active(consent_id, purpose, grantee, at)
+    }
+}
+
+fn record_event<T: Serialize>(
+    id: &str,
+    subject: &ProfileId,
+    record: &T,
+    record_type: &str,
+    causal_hash: &str,
+    timestamp: u64,
+) -> OntoEvent {
+    let mut payload = serde_json::to_value(record).unwrap_or_default();
+    if let Some(obj) = payload.as_object_mut() {
+        obj.insert("type".into(), record_type.into());
+        obj.insert("schema_version".into(), 1.into());
+    }
+
+    OntoEvent {
+        id: id.to_string(),
+        profile_id: subject.clone(),
+        phase: OntoPhase::Slow,
+        payload,
+        // Личное решение субъекта; запись нужна узлам, обрабатывающим его данные
+        social_context: SocialContext::new(100)
+            .with_relation(RelationKind::SelfAuthored)
+            .with_audience(AudienceScope::Community),
+        causal_hash: Some(causal_hash.to_string()),
+        timestamp,
+    }
+}
+
+fn record_from_event<T: for<'de> Deserialize<'de>>(event: &OntoEvent, record_type: &str) -> Option<T> {
+    if event.payload.get("type").and_then(|t| t.as_str()) != Some(record_type) {
+        return None;
+    }
+    serde_json::from_value(event.payload.clone()).ok()
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+```
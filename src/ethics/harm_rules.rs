@@ -0,0 +1,442 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// Закон III — Структурные правила обнаружения вреда
+
+use std::collections::HashSet;
+use std::fs;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::core::activity_ledger::ProfileId;
+use crate::ethics::consent_registry::{ConsentCheck, CONSENT_REFERENCE_KEY};
+use crate::ethics::law3_harm_witness::HarmType;
+
+/// Встроенный набор правил (версионируемый конфиг)
+const BUILTIN_RULES: &str = include_str!("rules/harm_rules.v2.yaml");
+
+/// Последняя версия формата правил, которую понимает движок
+/// v2 добавляет `unless_consent`; файлы v1 читаются без изменений
+pub const SUPPORTED_RULES_VERSION: u32 = 2;
+
+#[derive(Debug)]
+pub enum RuleError {
+    UnsupportedVersion(u32),
+    InvalidPattern { rule_id: String, reason: String },
+    Parse(String),
+    Io(String),
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchTarget {
+    Keys,
+    Values,
+    #[default]
+    Both,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct RuleConfig {
+    pub id: String,
+    pub harm: HarmType,
+    #[serde(default)]
+    pub target: MatchTarget,
+    #[serde(default)]
+    pub words: Vec<String>,
+    #[serde(default)]
+    pub regex: Vec<String>,
+    #[serde(default)]
+    pub paths: Vec<String>,
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub allow_paths: Vec<String>,
+    #[serde(default)]
+    pub unless_key_present: Vec<String>,
+    #[serde(default)]
+    pub unless_consent: Option<String>, // цель согласия (с версии 2)
+}
+
+#[derive(Deserialize)]
+struct RuleFile {
+    version: u32,
+    rules: Vec<RuleConfig>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchLocation {
+    Key,
+    Value,
+}
+
+/// Одно совпадение правила в payload
+#[derive(Clone, Debug)]
+pub struct HarmMatch {
+    pub rule_id: String,
+    pub harm: HarmType,
+    pub path: String,           // JSON-путь, например $.metrics[0].name
+    pub location: MatchLocation,
+    pub matched: String,        // совпавший фрагмент
+    pub text: String,           // ключ или значение целиком
+}
+
+struct CompiledRule {
+    config: RuleConfig,
+    words: Vec<Regex>,
+    regexes: Vec<Regex>,
+    allow: Vec<Regex>,
+    paths: Vec<PathPattern>,
+    allow_paths: Vec<PathPattern>,
+}
+
+/// Набор правил обнаружения вреда
+pub struct HarmRuleSet {
+    pub version: u32,
+    rules: Vec<CompiledRule>,
+}
+
+impl HarmRuleSet {
+    /// Встроенные правила ontoCMS
+    pub fn builtin() -> Result<Self, RuleError> {
+        Self::from_yaml_str(BUILTIN_RULES)
+    }
+
+    pub fn load(path: &str) -> Result<Self, RuleError> {
+        let content = fs::read_to_string(path).map_err(|e| RuleError::Io(e.to_string()))?;
+        Self::from_yaml_str(&content)
+    }
+
+    pub fn from_yaml_str(content: &str) -> Result<Self, RuleError> {
+        let file: RuleFile = serde_yaml::from_str(content).map_err(|e| RuleError::Parse(e.to_string()))?;
+        if !(1..=SUPPORTED_RULES_VERSION).contains(&file.version) {
+            return Err(RuleError::UnsupportedVersion(file.version));
+        }
+        let rules = file
+            .rules
+            .into_iter()
+            .map(CompiledRule::compile)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            version: file.version,
+            rules,
+        })
+    }
+
+    pub fn rule_ids(&self) -> Vec<&str> {
+        self.rules.iter().map(|r| r.config.id.as_str()).collect()
+    }
+
+    /// Все совпадения всех правил, в порядке обхода payload
+    /// Без реестра согласий правила с `unless_consent` действуют всегда
+    pub fn evaluate(&self, payload: &Value) -> Vec<HarmMatch> {
+        self.evaluate_rules(payload, |_, _| false)
+    }
+
+    /// Совпадения с учётом согласия, на которое ссылается payload (`consent_id`):
+    /// оно должно быть выдано `grantee` (профилю события) и действовать в момент приёма `at` (мс)
+    pub fn evaluate_with_consent(
+        &self,
+        payload: &Value,
+        consents: &dyn ConsentCheck,
+        grantee: &ProfileId,
+        at: u64,
+    ) -> Vec<HarmMatch> {
+        self.evaluate_rules(payload, |id, purpose| consents.is_active(id, purpose, grantee, at))
+    }
+
+    fn evaluate_rules(&self, payload: &Value, consent_active: impl Fn(&str, &str) -> bool) -> Vec<HarmMatch> {
+        let mut keys = HashSet::new();
+        collect_keys(payload, &mut keys);
+        let consent_id = payload.get(CONSENT_REFERENCE_KEY).and_then(Value::as_str);
+
+        let active: Vec<&CompiledRule> = self
+            .rules
+            .iter()
+            .filter(|r| !r.config.unless_key_present.iter().any(|k| keys.contains(k.as_str())))
+            .filter(|r| match (&r.config.unless_consent, consent_id) {
+                (Some(purpose), Some(id)) => !consent_active(id, purpose),
+                _ => true,
+            })
+            .collect();
+
+        let mut matches = vec![];
+        let mut path = vec![];
+        walk(payload, &mut path, &mut |path: &[Segment], text: &str, location: MatchLocation| {
+            for rule in &active {
+                rule.evaluate(path, text, location, &mut matches);
+            }
+        });
+        matches
+    }
+}
+
+impl CompiledRule {
+    fn compile(config: RuleConfig) -> Result<Self, RuleError> {
+        let invalid = |reason: String| RuleError::InvalidPattern {
+            rule_id: config.id.clone(),
+            reason,
+        };
+        let word_regex = |term: &String| {
+            Regex::new(&format!(r"\b{}\b", regex::escape(&normalize(term)))).map_err(|e| invalid(e.to_string()))
+        };
+
+        let words = config.words.iter().map(word_regex).collect::<Result<Vec<_>, _>>()?;
+        let allow = config.allow.iter().map(word_regex).collect::<Result<Vec<_>, _>>()?;
+        let regexes = config
+            .regex
+            .iter()
+            .map(|r| Regex::new(r).map_err(|e| invalid(e.to_string())))
+            .collect::<Result<Vec<_>, _>>()?;
+        let paths = config
+            .paths
+            .iter()
+            .map(|p| PathPattern::parse(p).ok_or_else(|| invalid(format!("invalid path '{}'", p))))
+            .collect::<Result<Vec<_>, _>>()?;
+        let allow_paths = config
+            .allow_paths
+            .iter()
+            .map(|p| PathPattern::parse(p).ok_or_else(|| invalid(format!("invalid path '{}'", p))))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            config,
+            words,
+            regexes,
+            allow,
+            paths,
+            allow_paths,
+        })
+    }
+
+    fn evaluate(&self, path: &[Segment], text: &str, location: MatchLocation, out: &mut Vec<HarmMatch>) {
+        let targeted = match self.config.target {
+            MatchTarget::Both => true,
+            MatchTarget::Keys => location == MatchLocation::Key,
+            MatchTarget::Values => location == MatchLocation::Value,
+        };
+        if !targeted
+            || (!self.paths.is_empty() && !self.paths.iter().any(|p| p.matches(path)))
+            || self.allow_paths.iter().any(|p| p.matches(path))
+        {
+            return;
+        }
+
+        let (normalized, offsets) = normalize_with_offsets(text);
+        let allowed: Vec<(usize, usize)> = self
+            .allow
+            .iter()
+            .flat_map(|a| a.find_iter(&normalized))
+            .map(|m| (m.start(), m.end()))
+            .collect();
+        let is_allowed = |start: usize, end: usize| allowed.iter().any(|(s, e)| *s <= start && end <= *e);
+
+        for word in &self.words {
+            for m in word.find_iter(&normalized) {
+                if is_allowed(m.start(), m.end()) {
+                    continue;
+                }
+                out.push(self.hit(path, text, location, m.as_str()));
+            }
+        }
+
+        // Регулярные выражения ищутся в исходном тексте; разрешённая фраза снимает совпадение,
+        // только если покрывает то же место текста (как для слов)
+        for re in &self.regexes {
+            for m in re.find_iter(text) {
+                let (mut start, mut end) = (offsets[m.start()], offsets[m.end()]);
+                let bytes = normalized.as_bytes();
+                while start < end && bytes[start] == b' ' {
+                    start += 1;
+                }
+                while end > start && bytes[end - 1] == b' ' {
+                    end -= 1;
+                }
+                if start < end && is_allowed(start, end) {
+                    continue;
+                }
+                out.push(self.hit(path, text, location, m.as_str()));
+            }
+        }
+    }
+
+    fn hit(&self, path: &[Segment], text: &str, location: MatchLocation, matched: &str) -> HarmMatch {
+        HarmMatch {
+            rule_id: self.config.id.clone(),
+            harm: self.config.harm.clone(),
+            path: format_path(path),
+            location,
+            matched: matched.to_string(),
+            text: text.to_string(),
+        }
+    }
+}
+
+/// Правила по умолчанию (разбираются один раз)
+/// Встроенный YAML проверяется тестом `builtin_harm_rules_are_valid` (tests/embedded_data.rs):
+/// ошибка в нём — ошибка сборки, а не входных данных
+#[allow(clippy::expect_used)]
+pub fn default_rules() -> &'static HarmRuleSet {
+    static RULES: OnceLock<HarmRuleSet> = OnceLock::new();
+    RULES.get_or_init(|| HarmRuleSet::builtin().expect("embedded harm rules must be valid"))
+}
+
+/// Нормализация идентификаторов и текста в слова:
+/// "valueScore" / "value_score" / "Value-Score" → "value score"
+pub fn normalize(text: &str) -> String {
+    normalize_with_offsets(text).0
+}
+
+/// Нормализованный текст и смещение в нём для каждого байта исходного текста (и его конца)
+fn normalize_with_offsets(text: &str) -> (String, Vec<usize>) {
+    let mut out = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    let mut prev: Option<char> = None;
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            if prev.is_some_and(|p| p.is_lowercase() && c.is_uppercase()) {
+                out.push(' ');
+            }
+            offsets.extend(std::iter::repeat_n(out.len(), c.len_utf8()));
+            out.extend(c.to_lowercase());
+        } else {
+            offsets.extend(std::iter::repeat_n(out.len(), c.len_utf8()));
+            if !out.is_empty() && !out.ends_with(' ') {
+                out.push(' ');
+            }
+        }
+        prev = Some(c);
+    }
+    out.truncate(out.trim_end().len());
+    offsets.push(out.len());
+    for offset in &mut offsets {
+        *offset = (*offset).min(out.len());
+    }
+    (out, offsets)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PatternSegment {
+    Key(String),
+    Index(usize),
+    AnyOne,  // * или [*]
+    AnyMany, // **
+}
+
+/// JSON-путь с подстановками: $.a.b, $.items[*].name, $.report.**
+struct PathPattern(Vec<PatternSegment>);
+
+impl PathPattern {
+    fn parse(pattern: &str) -> Option<Self> {
+        let mut rest = pattern.strip_prefix('$')?;
+        let mut segments = vec![];
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix('.') {
+                let end = after.find(['.', '[']).unwrap_or(after.len());
+                let name = &after[..end];
+                segments.push(match name {
+                    "" => return None,
+                    "*" => PatternSegment::AnyOne,
+                    "**" => PatternSegment::AnyMany,
+                    _ => PatternSegment::Key(name.to_string()),
+                });
+                rest = &after[end..];
+            } else if let Some(after) = rest.strip_prefix('[') {
+                let end = after.find(']')?;
+                let inner = &after[..end];
+                segments.push(match inner {
+                    "*" => PatternSegment::AnyOne,
+                    _ if inner.starts_with('\'') && inner.ends_with('\'') && inner.len() >= 2 => {
+                        PatternSegment::Key(inner[1..inner.len() - 1].to_string())
+                    }
+                    _ => PatternSegment::Index(inner.parse().ok()?),
+                });
+                rest = &after[end + 1..];
+            } else {
+                return None;
+            }
+        }
+        Some(Self(segments))
+    }
+
+    fn matches(&self, path: &[Segment]) -> bool {
+        fn go(pattern: &[PatternSegment], path: &[Segment]) -> bool {
+            match (pattern.first(), path.first()) {
+                (None, None) => true,
+                (Some(PatternSegment::AnyMany), _) => {
+                    go(&pattern[1..], path) || (!path.is_empty() && go(pattern, &path[1..]))
+                }
+                (Some(_), None) | (None, Some(_)) => false,
+                (Some(p), Some(s)) => {
+                    let ok = match (p, s) {
+                        (PatternSegment::AnyOne, _) => true,
+                        (PatternSegment::Key(k), Segment::Key(key)) => k == key,
+                        (PatternSegment::Index(i), Segment::Index(index)) => i == index,
+                        _ => false,
+                    };
+                    ok && go(&pattern[1..], &path[1..])
+                }
+            }
+        }
+        go(&self.0, path)
+    }
+}
+
+fn walk(value: &Value, path: &mut Vec<Segment>, visit: &mut dyn FnMut(&[Segment], &str, MatchLocation)) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                path.push(Segment::Key(key.clone()));
+                visit(path, key, MatchLocation::Key);
+                walk(child, path, visit);
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter().enumerate() {
+                path.push(Segment::Index(i));
+                walk(child, path, visit);
+                path.pop();
+            }
+        }
+        Value::String(s) => visit(path, s, MatchLocation::Value),
+        _ => {}
+    }
+}
+
+fn collect_keys<'a>(value: &'a Value, keys: &mut HashSet<&'a str>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                keys.insert(key.as_str());
+                collect_keys(child, keys);
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|child| collect_keys(child, keys)),
+        _ => {}
+    }
+}
+
+fn format_path(path: &[Segment]) -> String {
+    let mut out = String::from("$");
+    for segment in path {
+        match segment {
+            Segment::Key(k) if !k.is_empty() && k.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+                out.push('.');
+                out.push_str(k);
+            }
+            Segment::Key(k) => out.push_str(&format!("['{}']", k.replace('\'', "\\'"))),
+            Segment::Index(i) => out.push_str(&format!("[{}]", i)),
+        }
+    }
+    out
+}
+```
@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // Закон I Онтогенеза: Действие → Ментальный профиль
 
+use std::collections::HashSet;
+use std::sync::{PoisonError, RwLock};
+
 use crate::core::activity_ledger::{OntoEvent, ProfileId};
+use crate::forms::invariant_registry::InvariantRegistry;
 
 #[derive(Debug)]
 pub enum AttributionError {
@@ -11,16 +15,86 @@ pub enum AttributionError {
     AnonymousEventRejected,
 }
 
+impl std::fmt::Display for AttributionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributionError::UnattributedAction => write!(f, "action is not attributed to a profile"),
+            AttributionError::ProfileNotFound => write!(f, "profile is not in the onto-144 registry"),
+            AttributionError::AnonymousEventRejected => write!(f, "anonymous events are rejected"),
+        }
+    }
+}
+
+impl std::error::Error for AttributionError {}
+
+/// Источник канонических профилей onto-144
+pub trait ProfileSource: Send + Sync {
+    fn contains(&self, profile: &ProfileId) -> bool;
+}
+
+impl ProfileSource for InvariantRegistry {
+    fn contains(&self, profile: &ProfileId) -> bool {
+        self.get_profile(&profile.0).is_some()
+    }
+}
+
+/// Кеш поверх источника профилей — для горячего пути приёма событий
+/// Кешируются только найденные профили: их не больше 144, а неизвестные ID
+/// от чужих узлов не раздувают память
+pub struct CachedProfileSource<S> {
+    source: S,
+    known: RwLock<HashSet<String>>,
+}
+
+impl<S: ProfileSource> CachedProfileSource<S> {
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            known: RwLock::new(HashSet::new()),
+        }
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Сброс кеша (после перезагрузки реестра)
+    pub fn invalidate(&self) {
+        self.known.write().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+}
+
+impl<S: ProfileSource> ProfileSource for CachedProfileSource<S> {
+    fn contains(&self, profile: &ProfileId) -> bool {
+        if self
+            .known
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(&profile.0)
+        {
+            return true;
+        }
+        let found = self.source.contains(profile);
+        if found {
+            self.known
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(profile.0.clone());
+        }
+        found
+    }
+}
+
 /// Обязательная атрибуция действия ментальному профилю
-pub fn enforce_attribution(event: &OntoEvent) -> Result<(), AttributionError> {
+pub fn enforce_attribution(event: &OntoEvent, profiles: &dyn ProfileSource) -> Result<(), AttributionError> {
     // Закон I: действие без профиля — недопустимо
     if event.profile_id.0.is_empty() || event.profile_id.0 == "anonymous" {
         return Err(AttributionError::UnattributedAction);
     }
 
     // Профиль должен быть из канонического реестра onto-144
-    // (проверка выполняется в OntoCoder, здесь — семантическое требование)
-    if !is_valid_profile_id(&event.profile_id) {
+    // Формат проверяется до обращения к реестру — дешёвый отсев мусора
+    if !is_valid_profile_id(&event.profile_id) || !profiles.contains(&event.profile_id) {
         return Err(AttributionError::ProfileNotFound);
     }
 
@@ -28,8 +102,6 @@ pub fn enforce_attribution(event: &OntoEvent) -> Result<(), AttributionError> {
 }
 
 fn is_valid_profile_id(profile: &ProfileId) -> bool {
-    // В реальной системе: запрос к InvariantRegistry
-    // Здесь — минимальная проверка формата
     profile.0.contains('-') && profile.0.split('-').count() == 3
 }
 ```
\ No newline at end of file
@@ -3,32 +3,63 @@
 // Закон II Онтогенеза: Представление → Трассировка
 
 use crate::core::activity_ledger::{OntoEvent, OntoPhase};
+use crate::core::social_context::ProximityBand;
 
 #[derive(Debug)]
 pub enum TracingError {
     MissingCausalHash,
     FastPhaseWithoutContext,
     SocialProximityOutOfBounds,
+    InconsistentSocialContext(&'static str),
 }
 
+impl std::fmt::Display for TracingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TracingError::MissingCausalHash => write!(f, "event has no causal_hash outside the Fast phase"),
+            TracingError::FastPhaseWithoutContext => write!(f, "Fast-phase event has no social context"),
+            TracingError::SocialProximityOutOfBounds => write!(f, "social proximity is above 100"),
+            TracingError::InconsistentSocialContext(reason) => write!(f, "inconsistent social context: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for TracingError {}
+
 /// Обязательная трассировка происхождения утверждения
 pub fn enforce_tracing(event: &OntoEvent) -> Result<(), TracingError> {
+    match tracing_violations(event).into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Все нарушения трассировки события
+pub fn tracing_violations(event: &OntoEvent) -> Vec<TracingError> {
+    let mut violations = vec![];
+
     // Закон II: каждое представление должно иметь трассировку
     // В фазе Fast допускается отсутствие causal_hash, но требуется контекст
     if event.causal_hash.is_none() && event.phase != OntoPhase::Fast {
-        return Err(TracingError::MissingCausalHash);
+        violations.push(TracingError::MissingCausalHash);
     }
 
     // В фазе Fast: должен быть указан социальный контекст
-    if event.phase == OntoPhase::Fast && event.social_proximity == 0 {
-        return Err(TracingError::FastPhaseWithoutContext);
+    let social = &event.social_context;
+    if event.phase == OntoPhase::Fast && social.band() == ProximityBand::None {
+        violations.push(TracingError::FastPhaseWithoutContext);
     }
 
     // Социальная близость — от 1 до 100 (0 = отсутствие контекста → запрещено)
-    if event.social_proximity > 100 {
-        return Err(TracingError::SocialProximityOutOfBounds);
+    if social.proximity > 100 {
+        violations.push(TracingError::SocialProximityOutOfBounds);
+    }
+
+    // Отношение и аудитория не должны противоречить близости
+    if let Some(reason) = social.inconsistency() {
+        violations.push(TracingError::InconsistentSocialContext(reason));
     }
 
-    Ok(())
+    violations
 }
 ```
\ No newline at end of file
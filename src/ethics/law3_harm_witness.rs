@@ -2,12 +2,23 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // Закон III Онтогенеза: Разум → Свидетельство о вреде
 
+use serde::{Deserialize, Serialize};
+
+use libp2p::identity::Keypair;
+
 use crate::core::activity_ledger::{OntoEvent, OntoPhase, ProfileId};
+use crate::core::key_registry::KeyRegistry;
+use crate::core::local_mirror::{DeliveryState, LocalMirror, OutboxEntry};
 use crate::core::public_hub::PublicHub;
-use crate::ethics::law1_attribution;
-use crate::ethics::law2_tracing;
+use crate::core::quarantine::NodeIsolation;
+use crate::core::signature::{self, Signature, SignatureError};
+use crate::core::social_context::{AudienceScope, RelationKind, SocialContext};
+use crate::ethics::consent_registry::{ConsentCheck, ConsentGrant, ConsentRevocation, NoConsents};
+use crate::ethics::harm_rules::{self, HarmMatch, HarmRuleSet, MatchLocation};
+use crate::ethics::pipeline::{EthicsPipeline, EthicsReport};
+use crate::transport::json_ld::{self, JsonLdError};
 
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum HarmType {
     AENGAViolation,          // Внешнее управление
     BiometricExploitation,   // Извлечение биометрии без согласия
@@ -15,91 +26,314 @@ pub enum HarmType {
     EthicsModuleBypass,      // Обход модулей этики
 }
 
-#[derive(Debug)]
+/// Структурированное доказательство: какое правило, где, что совпало
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WitnessEvidence {
+    pub rule_id: String,
+    pub json_path: String,
+    pub location: MatchLocation,
+    pub excerpt: String, // редактированный фрагмент: совпавший термин без окружающих данных
+}
+
+/// Свидетельство о вреде — самостоятельная подписанная запись журнала
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct HarmWitness {
+    pub id: String,
     pub detected_harm: HarmType,
     pub violating_event_id: String,
+    pub causal_hash: String,            // json_ld::event_hash нарушающего события
+    pub violating_phase: OntoPhase,
     pub witness_profile: ProfileId,
     pub timestamp: u64,
-    pub evidence: String,
+    pub evidence: Vec<WitnessEvidence>,
+    pub signature: Option<Signature>,   // подпись свидетеля (ключ узла)
+}
+
+impl HarmWitness {
+    /// Подпись свидетельства ключом узла-свидетеля
+    pub fn sign(&mut self, keypair: &Keypair) -> Result<(), SignatureError> {
+        self.signature = None;
+        let message = signature::canonical_bytes(self)?;
+        self.signature = Some(Signature::sign(keypair, &message)?);
+        Ok(())
+    }
+
+    pub fn verify(&self) -> Result<(), SignatureError> {
+        let signature = self.signature.as_ref().ok_or(SignatureError::Missing)?;
+        let unsigned = HarmWitness {
+            signature: None,
+            ..self.clone()
+        };
+        signature.verify(&signature::canonical_bytes(&unsigned)?)
+    }
+
+    /// Событие журнала, несущее свидетельство; причинно связано с нарушающим событием
+    pub fn to_event(&self) -> Result<OntoEvent, serde_json::Error> {
+        let mut payload = serde_json::to_value(self)?;
+        if let Some(obj) = payload.as_object_mut() {
+            obj.insert("type".into(), "harm_witness".into());
+            obj.insert("schema_version".into(), 2.into());
+            obj.insert("law3_witness".into(), true.into());
+        }
+
+        // Свидетельство всегда создаётся в фазе Slow (рефлексия)
+        Ok(OntoEvent {
+            id: self.id.clone(),
+            profile_id: self.witness_profile.clone(),
+            phase: OntoPhase::Slow,
+            payload,
+            // Максимальная ответственность; свидетельство публикуется для сообщества
+            social_context: SocialContext::new(100)
+                .with_relation(RelationKind::Community)
+                .with_audience(AudienceScope::Public),
+            causal_hash: Some(self.causal_hash.clone()),
+            timestamp: self.timestamp,
+        })
+    }
+
+    /// Восстановление свидетельства из события журнала
+    /// Поля события вне payload не подписаны: они должны совпадать с подписанной записью,
+    /// иначе подлинное свидетельство можно переупаковать в чужое событие
+    pub fn from_event(event: &OntoEvent) -> Option<HarmWitness> {
+        if event.payload.get("type").and_then(|t| t.as_str()) != Some("harm_witness") {
+            return None;
+        }
+        let witness: HarmWitness = serde_json::from_value(event.payload.clone()).ok()?;
+        (witness.id == event.id
+            && event.profile_id == witness.witness_profile
+            && event.causal_hash.as_deref() == Some(witness.causal_hash.as_str())
+            && event.timestamp == witness.timestamp)
+            .then_some(witness)
+    }
 }
 
 /// Генерация свидетельства о вреде — даже если система не может его предотвратить
+/// Доказательства берутся из совпадений встроенных правил для данного типа вреда
 pub fn generate_harm_witness(
     harm: HarmType,
     violating_event: &OntoEvent,
     witness_profile: ProfileId,
-) -> HarmWitness {
-    HarmWitness {
+) -> Result<HarmWitness, JsonLdError> {
+    let matches: Vec<HarmMatch> = harm_rules::default_rules()
+        .evaluate(&violating_event.payload)
+        .into_iter()
+        .filter(|m| m.harm == harm)
+        .collect();
+    build_witness(harm, &matches, violating_event, witness_profile)
+}
+
+/// Свидетельства по совпадениям правил: по одному на каждый тип вреда
+pub fn witnesses_from_matches(
+    matches: &[HarmMatch],
+    violating_event: &OntoEvent,
+    witness_profile: &ProfileId,
+) -> Result<Vec<HarmWitness>, JsonLdError> {
+    let mut harms: Vec<&HarmType> = vec![];
+    for m in matches {
+        if !harms.contains(&&m.harm) {
+            harms.push(&m.harm);
+        }
+    }
+    harms
+        .into_iter()
+        .map(|harm| {
+            let related: Vec<HarmMatch> = matches.iter().filter(|m| &m.harm == harm).cloned().collect();
+            build_witness(harm.clone(), &related, violating_event, witness_profile.clone())
+        })
+        .collect()
+}
+
+fn build_witness(
+    harm: HarmType,
+    matches: &[HarmMatch],
+    violating_event: &OntoEvent,
+    witness_profile: ProfileId,
+) -> Result<HarmWitness, JsonLdError> {
+    Ok(HarmWitness {
+        id: uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string(),
         detected_harm: harm,
         violating_event_id: violating_event.id.clone(),
+        causal_hash: json_ld::event_hash(violating_event)?,
+        violating_phase: violating_event.phase.clone(),
         witness_profile,
         timestamp: std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64,
-        evidence: format!(
-            "Phase: {:?}, Payload keys: {:?}",
-            violating_event.phase,
-            violating_event.payload.as_object().map(|o| o.keys().collect::<Vec<_>>())
-        ),
-    }
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0),
+        evidence: matches
+            .iter()
+            .map(|m| WitnessEvidence {
+                rule_id: m.rule_id.clone(),
+                json_path: m.path.clone(),
+                location: m.location,
+                excerpt: redact(m),
+            })
+            .collect(),
+        signature: None,
+    })
 }
 
-/// Проверка события на признаки вреда
-pub fn detect_harm(event: &OntoEvent) -> Option<HarmType> {
-    let payload_str = event.payload.to_string().to_lowercase();
-
-    // AENGA: попытка внешнего управления
-    if payload_str.contains("remote_control") || payload_str.contains("override_ethics") {
-        return Some(HarmType::AENGAViolation);
+/// Ключи payload — схема данных, их можно показывать; значения — нет
+fn redact(m: &HarmMatch) -> String {
+    match m.location {
+        MatchLocation::Key => m.text.clone(),
+        MatchLocation::Value => format!("…{}… ({} chars)", m.matched, m.text.chars().count()),
     }
+}
 
-    // Биометрическая эксплуатация
-    if payload_str.contains("biometric") && !payload_str.contains("revocable_consent") {
-        return Some(HarmType::BiometricExploitation);
-    }
+/// Проверка события на признаки вреда (встроенные правила, первое совпадение)
+/// Без реестров ключей и согласий: подписи записей и ссылки на согласие правил не снимают
+pub fn detect_harm(event: &OntoEvent) -> Option<HarmType> {
+    detect_all_harm(event, harm_rules::default_rules(), &NoConsents, &KeyRegistry::new())
+        .into_iter()
+        .next()
+        .map(|m| m.harm)
+}
 
-    // Энергетические метрики — запрещены
-    if payload_str.contains("energy") || payload_str.contains("value_score") {
-        return Some(HarmType::EnergyValueInjection);
+/// Все совпадения правил вреда — с JSON-путями и совпавшими фрагментами
+/// Доказательства внутри свидетельства, подписанного зарегистрированным ключом `witness_profile`,
+/// цепочка и политика решения об изоляции, подписанного ключом `node_profile`,
+/// и цель согласия, подписанного зарегистрированным ключом субъекта, не считаются вредом
+/// Согласие, на которое ссылается событие, проверяется для профиля события на момент проверки (приёма)
+pub fn detect_all_harm(
+    event: &OntoEvent,
+    rules: &HarmRuleSet,
+    consents: &dyn ConsentCheck,
+    keys: &KeyRegistry,
+) -> Vec<HarmMatch> {
+    let mut payload = event.payload.clone();
+    if let Some(obj) = payload.as_object_mut() {
+        // Ключ встроен в запись: самоподписанное «свидетельство» сканируется как обычный payload
+        if HarmWitness::from_event(event)
+            .is_some_and(|w| w.verify().is_ok() && keys.signed_by(&w.witness_profile, w.signature.as_ref()))
+        {
+            obj.remove("evidence");
+            obj.remove("detected_harm");
+        }
+        // Решение об изоляции перечисляет типы вреда цепочки и политики — по имени, не как вред
+        if NodeIsolation::from_event(event)
+            .is_some_and(|n| n.verify().is_ok() && keys.signed_by(&n.node_profile, n.signature.as_ref()))
+        {
+            obj.remove("evidence_chain");
+            obj.remove("policy");
+        }
+        if ConsentGrant::from_event(event)
+            .is_some_and(|g| g.verify().is_ok() && keys.signed_by(&g.subject, g.signature.as_ref()))
+            || ConsentRevocation::from_event(event)
+                .is_some_and(|r| r.verify().is_ok() && keys.signed_by(&r.subject, r.signature.as_ref()))
+        {
+            obj.remove("purpose");
+        }
     }
+    // Время приёма, а не `timestamp` события: его задаёт отправитель
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    rules.evaluate_with_consent(&payload, consents, &event.profile_id, now)
+}
 
-    // Обход этических модулей
-    if payload_str.contains("skip_ethics") || payload_str.contains("bypass_law") {
-        return Some(HarmType::EthicsModuleBypass);
-    }
+/// Итог попытки доставить свидетельство
+#[derive(Debug)]
+pub enum WitnessDelivery {
+    Published(String),                                // CID в Public Hub
+    Queued { attempts: u32, next_attempt_at: u64 },  // осталось в outbox, будет повтор
+    Rejected(EthicsReport),                           // не прошло конвейер, в сеть не уходит
+}
 
-    None
+/// Сводка повторной публикации из outbox
+#[derive(Debug, Default)]
+pub struct OutboxReport {
+    pub delivered: Vec<(String, String)>, // (id события-свидетельства, CID)
+    pub rejected: Vec<EthicsReport>,      // записи outbox, не прошедшие конвейер
+    pub still_pending: usize,
+    pub next_attempt_at: Option<u64>,
+    pub unreadable: Vec<(String, String)>, // файлы outbox, которые не удалось прочитать: (путь, ошибка)
 }
 
 /// Публикация свидетельства о вреде — даже в условиях отказа
+/// Свидетельство сначала проверяется конвейером, затем записывается в outbox LocalMirror;
+/// сбой сети оставляет его там для повтора
 pub async fn publish_harm_witness<H: PublicHub>(
     hub: &H,
+    mirror: &LocalMirror,
+    pipeline: &EthicsPipeline,
     witness: &HarmWitness,
-) -> Result<String, Box<dyn std::error::Error>> {
-    // Свидетельство всегда создаётся в фазе Slow (рефлексия)
-    let witness_event = OntoEvent {
-        id: uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string(),
-        profile_id: witness.witness_profile.clone(),
-        phase: OntoPhase::Slow,
-        payload: serde_json::json!({
-            "harm_type": format!("{:?}", witness.detected_harm),
-            "violating_event_id": witness.violating_event_id,
-            "evidence": witness.evidence,
-            "law3_witness": true
-        }),
-        social_proximity: 100, // максимальная ответственность
-        causal_hash: None,
-        timestamp: witness.timestamp,
-    };
-
-    // Принудительная валидация по Законам I и II
-    law1_attribution::enforce_attribution(&witness_event)?;
-    law2_tracing::enforce_tracing(&witness_event)?;
+) -> Result<WitnessDelivery, Box<dyn std::error::Error>> {
+    // Неподписанное свидетельство не публикуется — это ошибка вызывающего, а не сети
+    witness.verify().map_err(|e| format!("harm witness {} is not signed: {:?}", witness.id, e))?;
+
+    // Принудительная валидация по Трём законам — до записи в outbox:
+    // отклонённое свидетельство не должно дождаться повтора
+    let witness_event = witness.to_event()?;
+    pipeline.enforce(&witness_event)?;
+
+    let entry = mirror.record_witness(&witness_event)?;
+    Ok(deliver(hub, mirror, pipeline, &entry).await?)
+}
+
+/// Повторная публикация всех свидетельств, для которых наступило время повтора
+/// Outbox хранится на диске, поэтому вызывается и после перезапуска узла;
+/// каждая запись заново проходит конвейер — файл outbox мог быть изменён
+pub async fn retry_pending_witnesses<H: PublicHub>(
+    hub: &H,
+    mirror: &LocalMirror,
+    pipeline: &EthicsPipeline,
+) -> Result<OutboxReport, std::io::Error> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    let mut report = OutboxReport::default();
+    for entry in mirror.due_witnesses(now)? {
+        match deliver(hub, mirror, pipeline, &entry).await? {
+            WitnessDelivery::Published(cid) => report.delivered.push((entry.event.id.clone(), cid)),
+            WitnessDelivery::Rejected(ethics) => report.rejected.push(ethics),
+            WitnessDelivery::Queued { .. } => {}
+        }
+    }
+
+    let status = mirror.outbox_status()?;
+    report.unreadable = status.unreadable;
+    for entry in status.entries {
+        if entry.state == DeliveryState::Pending {
+            report.still_pending += 1;
+            report.next_attempt_at = Some(
+                report
+                    .next_attempt_at
+                    .map_or(entry.next_attempt_at, |t| t.min(entry.next_attempt_at)),
+            );
+        }
+    }
+    Ok(report)
+}
+
+async fn deliver<H: PublicHub>(
+    hub: &H,
+    mirror: &LocalMirror,
+    pipeline: &EthicsPipeline,
+    entry: &OutboxEntry,
+) -> Result<WitnessDelivery, std::io::Error> {
+    // Из outbox публикуется только то, что проходит конвейер сейчас
+    if let Err(report) = pipeline.enforce(&entry.event) {
+        mirror.mark_witness_rejected(&entry.event.id, &report.to_string())?;
+        return Ok(WitnessDelivery::Rejected(report));
+    }
 
     // Публикация в Public Hub (IPFS/libp2p)
-    let cid = hub.publish(&witness_event).await?;
-    Ok(cid)
+    match hub.publish(&entry.event).await {
+        Ok(cid) => {
+            mirror.mark_witness_delivered(&entry.event.id, &cid)?;
+            Ok(WitnessDelivery::Published(cid))
+        }
+        Err(e) => {
+            let failed = mirror.mark_witness_failed(&entry.event.id, &format!("{:?}", e))?;
+            Ok(WitnessDelivery::Queued {
+                attempts: failed.attempts,
+                next_attempt_at: failed.next_attempt_at,
+            })
+        }
+    }
 }
 ```
\ No newline at end of file
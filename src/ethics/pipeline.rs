@@ -0,0 +1,221 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// Единый этический шлюз: Законы I–III + подключаемые проверки
+
+use std::fmt;
+
+use crate::core::activity_ledger::OntoEvent;
+use crate::core::key_registry::KeyRegistry;
+use crate::ethics::consent_registry::{ConsentCheck, NoConsents};
+use crate::ethics::harm_rules::{self, HarmMatch, HarmRuleSet};
+use crate::ethics::law1_attribution::{self, AttributionError, CachedProfileSource, ProfileSource};
+use crate::ethics::law2_tracing::{self, TracingError};
+use crate::ethics::law3_harm_witness;
+use crate::forms::event_schema_registry::{self, EventSchemaRegistry, SchemaError};
+use crate::onto144_bridge::profile_loader::Onto144Profiles;
+
+/// Дополнительная проверка события (подключается к конвейеру)
+pub trait EthicsCheck: Send + Sync {
+    fn name(&self) -> &str;
+    /// Пустой список — событие прошло проверку
+    fn check(&self, event: &OntoEvent) -> Vec<String>;
+}
+
+#[derive(Debug)]
+pub enum EthicsViolation {
+    Attribution(AttributionError),
+    Tracing(TracingError),
+    Harm(HarmMatch),
+    Schema(SchemaError),
+    Check { check: String, message: String },
+}
+
+/// Итог проверки события — все нарушения, а не только первое
+#[derive(Debug)]
+pub struct EthicsReport {
+    pub event_id: String,
+    pub violations: Vec<EthicsViolation>,
+}
+
+impl EthicsReport {
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Совпадения правил Закона III — основа для HarmWitness
+    pub fn harm_matches(&self) -> Vec<HarmMatch> {
+        self.violations
+            .iter()
+            .filter_map(|v| match v {
+                EthicsViolation::Harm(m) => Some(m.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn into_result(self) -> Result<(), EthicsReport> {
+        if self.is_clean() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+/// Конвейер Трёх законов — единственный шлюз для журнала, хаба, агентов и импорта
+pub struct EthicsPipeline {
+    profiles: Box<dyn ProfileSource>,
+    rules: Option<HarmRuleSet>, // None — встроенные правила
+    consents: Box<dyn ConsentCheck>,
+    keys: KeyRegistry,
+    schemas: Option<EventSchemaRegistry>, // None — встроенные схемы
+    checks: Vec<Box<dyn EthicsCheck>>,
+}
+
+impl EthicsPipeline {
+    pub fn new() -> Self {
+        Self {
+            profiles: Box::new(CachedProfileSource::new(Onto144Profiles)),
+            rules: None,
+            consents: Box::new(NoConsents),
+            keys: KeyRegistry::new(),
+            schemas: None,
+            checks: vec![],
+        }
+    }
+
+    /// Реестр профилей для Закона I вместо onto144 (например, `InvariantRegistry`)
+    pub fn with_profiles(mut self, profiles: impl ProfileSource + 'static) -> Self {
+        self.profiles = Box::new(profiles);
+        self
+    }
+
+    /// Собственный набор правил Закона III вместо встроенного
+    pub fn with_rules(mut self, rules: HarmRuleSet) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    /// Реестр согласий: событие со ссылкой на действующее согласие (`consent_id`)
+    /// не нарушает правил с `unless_consent`
+    pub fn with_consents(mut self, consents: impl ConsentCheck + 'static) -> Self {
+        self.consents = Box::new(consents);
+        self
+    }
+
+    /// Ключи профилей: доказательства свидетельства не сканируются, только если оно подписано
+    /// ключом своего `witness_profile`
+    pub fn with_keys(mut self, keys: KeyRegistry) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Собственный реестр схем payload вместо встроенного
+    pub fn with_schemas(mut self, schemas: EventSchemaRegistry) -> Self {
+        self.schemas = Some(schemas);
+        self
+    }
+
+    pub fn with_check(mut self, check: impl EthicsCheck + 'static) -> Self {
+        self.checks.push(Box::new(check));
+        self
+    }
+
+    pub fn profiles(&self) -> &dyn ProfileSource {
+        self.profiles.as_ref()
+    }
+
+    pub fn keys(&self) -> &KeyRegistry {
+        &self.keys
+    }
+
+    pub fn rules(&self) -> &HarmRuleSet {
+        self.rules.as_ref().unwrap_or_else(|| harm_rules::default_rules())
+    }
+
+    pub fn schemas(&self) -> &EventSchemaRegistry {
+        self.schemas
+            .as_ref()
+            .unwrap_or_else(|| event_schema_registry::default_schemas())
+    }
+
+    /// Полная проверка события: все законы и все проверки выполняются всегда
+    pub fn evaluate(&self, event: &OntoEvent) -> EthicsReport {
+        let mut violations = vec![];
+
+        // Закон I: атрибуция
+        if let Err(e) = law1_attribution::enforce_attribution(event, self.profiles()) {
+            violations.push(EthicsViolation::Attribution(e));
+        }
+
+        // Закон II: трассировка
+        violations.extend(
+            law2_tracing::tracing_violations(event)
+                .into_iter()
+                .map(EthicsViolation::Tracing),
+        );
+
+        // Закон III: вред
+        violations.extend(
+            law3_harm_witness::detect_all_harm(event, self.rules(), self.consents.as_ref(), &self.keys)
+                .into_iter()
+                .map(EthicsViolation::Harm),
+        );
+
+        // Схема payload: тип без схемы — тоже нарушение
+        if let Err(e) = self.schemas().validate_payload(&event.payload) {
+            violations.push(EthicsViolation::Schema(e));
+        }
+
+        for check in &self.checks {
+            violations.extend(check.check(event).into_iter().map(|message| EthicsViolation::Check {
+                check: check.name().to_string(),
+                message,
+            }));
+        }
+
+        EthicsReport {
+            event_id: event.id.clone(),
+            violations,
+        }
+    }
+
+    pub fn enforce(&self, event: &OntoEvent) -> Result<(), EthicsReport> {
+        self.evaluate(event).into_result()
+    }
+}
+
+impl Default for EthicsPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for EthicsViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EthicsViolation::Attribution(e) => write!(f, "Law I: {}", e),
+            EthicsViolation::Tracing(e) => write!(f, "Law II: {}", e),
+            EthicsViolation::Harm(m) => write!(
+                f,
+                "Law III: {:?} by rule {} at {} ('{}')",
+                m.harm, m.rule_id, m.path, m.matched
+            ),
+            EthicsViolation::Schema(e) => write!(f, "payload schema: {}", e),
+            EthicsViolation::Check { check, message } => write!(f, "{}: {}", check, message),
+        }
+    }
+}
+
+impl fmt::Display for EthicsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "event {} violates {} rule(s)", self.event_id, self.violations.len())?;
+        for v in &self.violations {
+            write!(f, "\n  - {}", v)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for EthicsReport {}
+```
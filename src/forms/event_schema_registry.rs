@@ -0,0 +1,231 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCMS Forms — Versioned JSON Schemas for Event Payloads
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use jsonschema::JSONSchema;
+use serde_json::Value;
+
+/// Встроенные схемы: (тип события, версия, JSON Schema)
+const BUILTIN_SCHEMAS: &[(&str, u32, &str)] = &[
+    ("github_issue", 1, include_str!("schemas/github_issue.v1.json")),
+    ("scout_summary", 1, include_str!("schemas/scout_summary.v1.json")),
+    ("email_message", 1, include_str!("schemas/email_message.v1.json")),
+    ("harm_witness", 1, include_str!("schemas/harm_witness.v1.json")),
+    ("harm_witness", 2, include_str!("schemas/harm_witness.v2.json")),
+    ("consent_grant", 1, include_str!("schemas/consent_grant.v1.json")),
+    ("consent_revocation", 1, include_str!("schemas/consent_revocation.v1.json")),
+    ("git_commit", 1, include_str!("schemas/git_commit.v1.json")),
+    ("node_isolation", 1, include_str!("schemas/node_isolation.v1.json")),
+    ("node_isolation", 2, include_str!("schemas/node_isolation.v2.json")),
+    ("node_isolation_lifted", 1, include_str!("schemas/node_isolation_lifted.v1.json")),
+];
+
+/// Встроенные схемы (компилируются один раз) — схемы этического конвейера по умолчанию
+/// Встроенные JSON Schema проверяются тестом `builtin_event_schemas_are_valid` (tests/embedded_data.rs):
+/// ошибка в них — ошибка сборки, а не входных данных
+#[allow(clippy::expect_used)]
+pub fn default_schemas() -> &'static EventSchemaRegistry {
+    static SCHEMAS: OnceLock<EventSchemaRegistry> = OnceLock::new();
+    SCHEMAS.get_or_init(|| EventSchemaRegistry::builtin().expect("embedded event schemas must be valid"))
+}
+
+#[derive(Debug, Clone)]
+pub struct SchemaViolation {
+    pub instance_path: String, // JSON Pointer внутри payload
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub enum SchemaError {
+    MissingEventType,
+    UnknownEventType(String),
+    UnknownSchemaVersion { event_type: String, version: u32 },
+    InvalidSchemaVersion { event_type: String, version: Value }, // не целое число или вне u32
+    InvalidSchema { event_type: String, version: u32, reason: String },
+    PayloadRejected {
+        event_type: String,
+        version: u32,
+        violations: Vec<SchemaViolation>,
+    },
+}
+
+impl std::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaError::MissingEventType => write!(f, "payload has no 'type' field"),
+            SchemaError::UnknownEventType(t) => write!(f, "no schema registered for event type '{}'", t),
+            SchemaError::UnknownSchemaVersion { event_type, version } => {
+                write!(f, "no schema {} v{}", event_type, version)
+            }
+            SchemaError::InvalidSchemaVersion { event_type, version } => {
+                write!(f, "invalid schema_version {} for {}", version, event_type)
+            }
+            SchemaError::InvalidSchema { event_type, version, reason } => {
+                write!(f, "schema {} v{} is invalid: {}", event_type, version, reason)
+            }
+            SchemaError::PayloadRejected { event_type, version, violations } => {
+                write!(f, "payload does not match {} v{}", event_type, version)?;
+                for v in violations {
+                    write!(f, "; {}: {}", v.instance_path, v.message)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+pub struct EventSchema {
+    pub event_type: String,
+    pub version: u32,
+    pub schema: Value,
+    compiled: JSONSchema,
+}
+
+/// Реестр схем payload по типу события
+/// Payload объявляет тип полем `type` и (опционально) версию полем `schema_version`
+pub struct EventSchemaRegistry {
+    schemas: HashMap<String, BTreeMap<u32, EventSchema>>,
+}
+
+impl EventSchemaRegistry {
+    pub fn new() -> Self {
+        Self {
+            schemas: HashMap::new(),
+        }
+    }
+
+    /// Реестр со встроенными схемами ontoCMS
+    pub fn builtin() -> Result<Self, SchemaError> {
+        let mut registry = Self::new();
+        for (event_type, version, source) in BUILTIN_SCHEMAS {
+            let schema = serde_json::from_str(source).map_err(|e| SchemaError::InvalidSchema {
+                event_type: event_type.to_string(),
+                version: *version,
+                reason: e.to_string(),
+            })?;
+            registry.register(event_type, *version, schema)?;
+        }
+        Ok(registry)
+    }
+
+    /// Регистрация схемы; повторная регистрация той же версии заменяет её
+    pub fn register(&mut self, event_type: &str, version: u32, schema: Value) -> Result<(), SchemaError> {
+        let compiled = JSONSchema::compile(&schema).map_err(|e| SchemaError::InvalidSchema {
+            event_type: event_type.to_string(),
+            version,
+            reason: e.to_string(),
+        })?;
+        self.schemas.entry(event_type.to_string()).or_default().insert(
+            version,
+            EventSchema {
+                event_type: event_type.to_string(),
+                version,
+                schema,
+                compiled,
+            },
+        );
+        Ok(())
+    }
+
+    /// Загрузка схем из директории: файлы `<type>.v<N>.json` или `<type>.v<N>.yaml`
+    pub fn load_from_dir(&mut self, dir: &str) -> Result<(), Box<dyn std::error::Error>> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let Some((event_type, version)) = parse_schema_file_name(&path) else {
+                continue;
+            };
+            let content = fs::read_to_string(&path)?;
+            let schema: Value = match path.extension().and_then(|e| e.to_str()) {
+                Some("yaml") | Some("yml") => serde_yaml::from_str(&content)?,
+                _ => serde_json::from_str(&content)?,
+            };
+            self.register(&event_type, version, schema)
+                .map_err(|e| format!("{}: {:?}", path.display(), e))?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, event_type: &str, version: u32) -> Option<&EventSchema> {
+        self.schemas.get(event_type)?.get(&version)
+    }
+
+    pub fn latest_version(&self, event_type: &str) -> Option<u32> {
+        self.schemas.get(event_type)?.keys().next_back().copied()
+    }
+
+    pub fn event_types(&self) -> Vec<String> {
+        self.schemas.keys().cloned().collect()
+    }
+
+    /// Проверка payload по схеме его типа
+    /// Возвращает версию схемы, по которой payload прошёл проверку
+    pub fn validate_payload(&self, payload: &Value) -> Result<u32, SchemaError> {
+        let event_type = payload
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or(SchemaError::MissingEventType)?;
+        let versions = self
+            .schemas
+            .get(event_type)
+            .ok_or_else(|| SchemaError::UnknownEventType(event_type.to_string()))?;
+
+        // Без schema_version — последняя версия; версия вне u32 или не целое число — ошибка, а не другая схема
+        let schema = match payload.get("schema_version") {
+            Some(raw) => {
+                let version = raw.as_u64().and_then(|v| u32::try_from(v).ok()).ok_or_else(|| {
+                    SchemaError::InvalidSchemaVersion {
+                        event_type: event_type.to_string(),
+                        version: raw.clone(),
+                    }
+                })?;
+                versions.get(&version).ok_or(SchemaError::UnknownSchemaVersion {
+                    event_type: event_type.to_string(),
+                    version,
+                })?
+            }
+            None => versions
+                .values()
+                .next_back()
+                .ok_or_else(|| SchemaError::UnknownEventType(event_type.to_string()))?,
+        };
+
+        if let Err(errors) = schema.compiled.validate(payload) {
+            return Err(SchemaError::PayloadRejected {
+                event_type: event_type.to_string(),
+                version: schema.version,
+                violations: errors
+                    .map(|e| SchemaViolation {
+                        instance_path: e.instance_path.to_string(),
+                        message: e.to_string(),
+                    })
+                    .collect(),
+            });
+        }
+
+        Ok(schema.version)
+    }
+}
+
+impl Default for EventSchemaRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn parse_schema_file_name(path: &Path) -> Option<(String, u32)> {
+    match path.extension()?.to_str()? {
+        "json" | "yaml" | "yml" => {}
+        _ => return None,
+    }
+    let stem = path.file_stem()?.to_str()?;
+    let (event_type, version) = stem.rsplit_once(".v")?;
+    Some((event_type.to_string(), version.parse().ok()?))
+}
+```
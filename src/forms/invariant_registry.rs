@@ -4,10 +4,12 @@
 
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use crate::ontocoder::term_policy;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Onto144Profile {
     pub id: String,
@@ -38,6 +40,7 @@ pub struct BehavioralElement {
 pub struct InvariantRegistry {
     profiles: HashMap<String, Onto144Profile>,
     hash_to_id: HashMap<String, String>,
+    paths: HashMap<String, PathBuf>, // id → YAML-файл профиля
 }
 
 impl InvariantRegistry {
@@ -45,6 +48,7 @@ impl InvariantRegistry {
         Self {
             profiles: HashMap::new(),
             hash_to_id: HashMap::new(),
+            paths: HashMap::new(),
         }
     }
 
@@ -69,6 +73,7 @@ impl InvariantRegistry {
                 }
 
                 self.hash_to_id.insert(profile.invariant_hash.clone(), profile.id.clone());
+                self.paths.insert(profile.id.clone(), path.clone());
                 self.profiles.insert(profile.id.clone(), profile);
             }
         }
@@ -80,8 +85,8 @@ impl InvariantRegistry {
     }
 
     fn contains_forbidden_fields(content: &str) -> bool {
-        let forbidden = ["energy", "value", "score", "attract", "monetize"];
-        forbidden.iter().any(|kw| content.contains(kw))
+        // Те же термины, что и в OntoValidator
+        !term_policy::default_policy().check_text(content).is_empty()
     }
 
     /// Валидация хеша (в production — пересчёт без поля invariant_hash)
@@ -95,6 +100,11 @@ impl InvariantRegistry {
         self.profiles.get(id)
     }
 
+    /// YAML-файл, из которого загружен профиль
+    pub fn profile_path(&self, id: &str) -> Option<&Path> {
+        self.paths.get(id).map(|p| p.as_path())
+    }
+
     pub fn verify_hash(&self, hash: &str) -> Option<&str> {
         self.hash_to_id.get(hash).map(|s| s.as_str())
     }
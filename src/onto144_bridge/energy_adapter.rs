@@ -3,20 +3,19 @@
 // ontoCMS — Energy Adapter (Passive Witness Only)
 
 use onto144::state::EnergyState;
+use crate::ethics::harm_rules;
 use crate::ethics::law3_harm_witness::{HarmType, generate_harm_witness};
 use crate::core::activity_ledger::{OntoEvent, ProfileId};
 
 /// Адаптер энергетической модели: ТОЛЬКО для свидетельства о вреде
 /// ontoCMS НЕ ИСПОЛЬЗУЕТ энергию в работе — только фиксирует попытки внедрения
 pub fn detect_energy_injection(event: &OntoEvent) -> Option<HarmType> {
-    let payload_str = event.payload.to_string().to_lowercase();
-    
-    // Запрещено по Three Laws и SGRL-α
-    if payload_str.contains("energy") || payload_str.contains("energy_state") {
-        Some(HarmType::EnergyValueInjection)
-    } else {
-        None
-    }
+    // Запрещено по Three Laws и SGRL-α — те же правила, что и в Законе III
+    harm_rules::default_rules()
+        .evaluate(&event.payload)
+        .into_iter()
+        .find(|m| m.harm == HarmType::EnergyValueInjection)
+        .map(|m| m.harm)
 }
 
 /// Прокси-запрос к onto144: безопасное получение энергетического состояния
@@ -3,6 +3,8 @@
 // ontoCMS — Bridge to onto144 (Profile Loader)
 
 use onto144::{ProfileId as Onto144ProfileId, Profile, ProfileRegistry};
+use crate::core::activity_ledger;
+use crate::ethics::law1_attribution::ProfileSource;
 
 /// Адаптер: ProfileId из ontoCMS = ProfileId из onto144
 pub type ProfileId = Onto144ProfileId;
@@ -22,4 +24,13 @@ pub fn is_valid_profile(id: &str) -> bool {
 pub fn all_profile_ids() -> Vec<String> {
     onto144::registry::all_profile_ids()
 }
+
+/// Канонический реестр onto144 как источник профилей для Закона I
+pub struct Onto144Profiles;
+
+impl ProfileSource for Onto144Profiles {
+    fn contains(&self, profile: &activity_ledger::ProfileId) -> bool {
+        is_valid_profile(&profile.0)
+    }
+}
 ```
\ No newline at end of file
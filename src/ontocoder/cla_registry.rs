@@ -0,0 +1,221 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Contributor License Agreement Registry (signed CLA records)
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use libp2p::identity::Keypair;
+use serde::{Deserialize, Serialize};
+
+use crate::core::signature::{self, Signature, SignatureError};
+
+/// Путь реестра относительно корня проекта
+pub const CLA_REGISTRY_FILE: &str = "legal/cla-registry.yaml";
+pub const SUPPORTED_REGISTRY_VERSION: u32 = 1;
+
+/// Вид соглашения (legal/cla-individual.md, legal/cla-entity.md)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaKind {
+    Individual,
+    Entity,
+}
+
+/// Подписанная запись о принятии CLA
+/// Участник узнаётся по DID или по git-идентичности; запись организации покрывает её почтовые домены
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ClaRecord {
+    pub id: String,
+    pub kind: ClaKind,
+    pub agreement: String, // SPDX id текста соглашения
+    pub contributor: String, // имя участника или организации
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub git_identities: Vec<String>, // адреса почты из коммитов
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub email_domains: Vec<String>, // только для Entity
+    pub signed_at: u64,
+    pub signature: Option<Signature>,
+}
+
+/// Автор изменения: git-идентичность и, если указан в коммите, DID
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Contributor {
+    pub name: String,
+    pub email: String,
+    pub did: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum ClaError {
+    Io(String),
+    Parse(String),
+    UnsupportedVersion(u32),
+    DuplicateId(String),
+}
+
+#[derive(Deserialize)]
+struct RegistryFile {
+    version: u32,
+    #[serde(default)]
+    records: Vec<ClaRecord>,
+}
+
+impl ClaKind {
+    /// SPDX id текста соглашения
+    pub fn agreement(self) -> &'static str {
+        match self {
+            ClaKind::Individual => "LicenseRef-ontoCMS-CLA-Individual-1.0",
+            ClaKind::Entity => "LicenseRef-ontoCMS-CLA-Entity-1.0",
+        }
+    }
+}
+
+impl ClaRecord {
+    pub fn new(kind: ClaKind, contributor: &str) -> Self {
+        Self {
+            id: uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string(),
+            kind,
+            agreement: kind.agreement().to_string(),
+            contributor: contributor.to_string(),
+            did: None,
+            git_identities: vec![],
+            email_domains: vec![],
+            signed_at: now_ms(),
+            signature: None,
+        }
+    }
+
+    pub fn sign(&mut self, keypair: &Keypair) -> Result<(), SignatureError> {
+        self.signature = None;
+        let message = signature::canonical_bytes(self)?;
+        self.signature = Some(Signature::sign(keypair, &message)?);
+        Ok(())
+    }
+
+    pub fn verify(&self) -> Result<(), SignatureError> {
+        let signature = self.signature.as_ref().ok_or(SignatureError::Missing)?;
+        let unsigned = ClaRecord {
+            signature: None,
+            ..self.clone()
+        };
+        signature.verify(&signature::canonical_bytes(&unsigned)?)
+    }
+}
+
+impl Contributor {
+    /// Разбор `Name <email>`
+    pub fn parse(identity: &str) -> Option<Self> {
+        let (name, rest) = identity.split_once('<')?;
+        let email = rest.strip_suffix('>')?.trim();
+        if email.is_empty() {
+            return None;
+        }
+        Some(Self {
+            name: name.trim().to_string(),
+            email: email.to_string(),
+            did: None,
+        })
+    }
+}
+
+impl std::fmt::Display for Contributor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)?;
+        if let Some(did) = &self.did {
+            write!(f, " ({})", did)?;
+        }
+        Ok(())
+    }
+}
+
+/// Реестр CLA; учитываются только записи с действительной подписью
+#[derive(Default)]
+pub struct ClaRegistry {
+    records: Vec<ClaRecord>,
+    rejected: Vec<(String, SignatureError)>, // id записи → почему не учтена
+    by_did: HashMap<String, usize>,
+    by_email: HashMap<String, usize>,  // адрес в нижнем регистре
+    by_domain: HashMap<String, usize>, // домен в нижнем регистре (Entity)
+}
+
+impl ClaRegistry {
+    pub fn load(path: &Path) -> Result<Self, ClaError> {
+        let content = fs::read_to_string(path).map_err(|e| ClaError::Io(format!("{}: {}", path.display(), e)))?;
+        Self::from_yaml_str(&content)
+    }
+
+    pub fn from_yaml_str(content: &str) -> Result<Self, ClaError> {
+        let file: RegistryFile = serde_yaml::from_str(content).map_err(|e| ClaError::Parse(e.to_string()))?;
+        if !(1..=SUPPORTED_REGISTRY_VERSION).contains(&file.version) {
+            return Err(ClaError::UnsupportedVersion(file.version));
+        }
+        let mut registry = Self::default();
+        for record in file.records {
+            registry.insert(record)?;
+        }
+        Ok(registry)
+    }
+
+    /// Добавление записи; без действительной подписи запись попадает в `rejected`
+    pub fn insert(&mut self, record: ClaRecord) -> Result<(), ClaError> {
+        if self.records.iter().any(|r| r.id == record.id) || self.rejected.iter().any(|(id, _)| *id == record.id) {
+            return Err(ClaError::DuplicateId(record.id));
+        }
+        if let Err(e) = record.verify() {
+            self.rejected.push((record.id, e));
+            return Ok(());
+        }
+        let index = self.records.len();
+        if let Some(did) = &record.did {
+            self.by_did.insert(did.clone(), index);
+        }
+        for email in &record.git_identities {
+            self.by_email.insert(email.to_lowercase(), index);
+        }
+        if record.kind == ClaKind::Entity {
+            for domain in &record.email_domains {
+                self.by_domain.insert(domain.to_lowercase(), index);
+            }
+        }
+        self.records.push(record);
+        Ok(())
+    }
+
+    /// CLA участника: по DID, затем по адресу почты, затем по домену организации
+    pub fn find(&self, contributor: &Contributor) -> Option<&ClaRecord> {
+        let email = contributor.email.to_lowercase();
+        let domain = email.rsplit_once('@').map(|(_, d)| d);
+        contributor
+            .did
+            .as_ref()
+            .and_then(|did| self.by_did.get(did))
+            .or_else(|| self.by_email.get(&email))
+            .or_else(|| domain.and_then(|d| self.by_domain.get(d)))
+            .map(|&i| &self.records[i])
+    }
+
+    pub fn has_cla(&self, contributor: &Contributor) -> bool {
+        self.find(contributor).is_some()
+    }
+
+    pub fn records(&self) -> &[ClaRecord] {
+        &self.records
+    }
+
+    /// Записи, не учтённые из-за отсутствующей или недействительной подписи
+    pub fn rejected(&self) -> &[(String, SignatureError)] {
+        &self.rejected
+    }
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+```
@@ -0,0 +1,163 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Git Commits as OntoEvents (Onto-Profile / Onto-Phase attribution)
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::core::activity_ledger::{OntoEvent, OntoPhase, ProfileId};
+use crate::core::local_mirror::LocalMirror;
+use crate::core::social_context::{AudienceScope, RelationKind, SocialContext};
+use crate::ontocoder::git_history::{self, CommitInfo, GitError};
+use crate::transport::json_ld::{self, JsonLdError};
+
+/// Тип payload события коммита (src/forms/schemas/git_commit.v1.json)
+pub const COMMIT_EVENT_TYPE: &str = "git_commit";
+
+#[derive(Debug)]
+pub enum PendingCommitError {
+    Git(GitError),
+    JsonLd(JsonLdError),
+}
+
+/// Коммит, который ещё не создан, и его событие
+pub struct PendingCommit {
+    pub commit: CommitInfo,
+    pub parent_events: Vec<String>, // те же связи получит событие созданного коммита
+    pub event: OntoEvent,
+}
+
+/// Событие будущего коммита `message` поверх HEAD — проверяется этическим конвейером до записи в историю:
+/// от самого коммита зависит только его хеш (нулевой у события до коммита)
+pub fn pending_commit_event(
+    root: &Path,
+    message: &str,
+    profile: ProfileId,
+    phase: OntoPhase,
+    mirror: &LocalMirror,
+    chain_start: &str,
+) -> Result<PendingCommit, PendingCommitError> {
+    let commit = git_history::pending_commit(root, message).map_err(PendingCommitError::Git)?;
+    // Первый коммит: истории ещё нет
+    let graph = git_history::parent_map(root, "HEAD").unwrap_or_default();
+    let parent_events = CommitLinks::new(mirror, graph)
+        .parent_events(&commit)
+        .map_err(PendingCommitError::JsonLd)?;
+    let event = commit_event(&commit, profile, phase, &parent_events, chain_start);
+    Ok(PendingCommit {
+        commit,
+        parent_events,
+        event,
+    })
+}
+
+/// Событие коммита; id события — хеш коммита, поэтому повторная запись идемпотентна
+/// Время события — дата автора: событие одного коммита всегда хешируется одинаково
+/// Причинная связь (Закон II): `causal_hash` — событие первого родителя, `parent_events` — события всех родителей;
+/// у первого коммита — `chain_start` (invariant_hash профиля)
+pub fn commit_event(
+    commit: &CommitInfo,
+    profile: ProfileId,
+    phase: OntoPhase,
+    parent_events: &[String],
+    chain_start: &str,
+) -> OntoEvent {
+    let mut payload = json!({
+        "type": COMMIT_EVENT_TYPE,
+        "schema_version": 1,
+        "commit": commit.id,
+        "parents": commit.parents,
+        "summary": commit.summary,
+    });
+    if !parent_events.is_empty() {
+        payload["parent_events"] = json!(parent_events);
+    }
+    // Автор атрибутируется профилем; из git-идентичности сохраняется только DID
+    if let Some(did) = &commit.author.did {
+        payload["contributor_did"] = json!(did);
+    }
+    OntoEvent {
+        id: commit.id.clone(),
+        profile_id: profile,
+        phase,
+        payload,
+        // Собственная работа автора, видимая участникам проекта
+        social_context: SocialContext::new(70)
+            .with_relation(RelationKind::SelfAuthored)
+            .with_audience(AudienceScope::Community),
+        causal_hash: Some(parent_events.first().map_or_else(|| chain_start.to_string(), String::clone)),
+        timestamp: commit.timestamp,
+    }
+}
+
+/// Причинные связи коммитов: коммит → хеши ближайших событий-предков в зеркале
+/// Коммит с событием связан с ним самим; коммит без события (пропущенный или отклонённый при импорте) —
+/// с ближайшими событиями своих родителей, поэтому ни слияние, ни потомок пропущенного коммита не теряют связь
+pub struct CommitLinks<'a> {
+    mirror: &'a LocalMirror,
+    parents: HashMap<String, Vec<String>>, // граф истории git (`git_history::parent_map`)
+    resolved: HashMap<String, Vec<String>>,
+}
+
+impl<'a> CommitLinks<'a> {
+    pub fn new(mirror: &'a LocalMirror, parents: HashMap<String, Vec<String>>) -> Self {
+        Self {
+            mirror,
+            parents,
+            resolved: HashMap::new(),
+        }
+    }
+
+    /// Событие, только что записанное для коммита
+    pub fn record(&mut self, event: &OntoEvent) -> Result<(), JsonLdError> {
+        self.resolved.insert(event.id.clone(), vec![json_ld::event_hash(event)?]);
+        Ok(())
+    }
+
+    /// Хеши событий родителей коммита в порядке родителей, без повторов
+    pub fn parent_events(&mut self, commit: &CommitInfo) -> Result<Vec<String>, JsonLdError> {
+        let mut hashes: Vec<String> = vec![];
+        for parent in &commit.parents {
+            for hash in self.events_of(parent)? {
+                if !hashes.contains(&hash) {
+                    hashes.push(hash);
+                }
+            }
+        }
+        Ok(hashes)
+    }
+
+    /// Обход без рекурсии: длинная цепочка коммитов без событий не переполняет стек
+    fn events_of(&mut self, commit_id: &str) -> Result<Vec<String>, JsonLdError> {
+        let mut stack = vec![commit_id.to_string()];
+        let mut expanded = HashSet::new();
+        while let Some(current) = stack.last().cloned() {
+            if self.resolved.contains_key(&current) {
+                stack.pop();
+                continue;
+            }
+            let parents = self.parents.get(&current).cloned().unwrap_or_default();
+            if expanded.insert(current.clone()) {
+                if let Some(event) = self.mirror.find_event(&current) {
+                    self.resolved.insert(current, vec![json_ld::event_hash(&event)?]);
+                    stack.pop();
+                    continue;
+                }
+                stack.extend(parents.iter().filter(|p| !self.resolved.contains_key(*p)).cloned());
+                continue;
+            }
+            let mut hashes: Vec<String> = vec![];
+            for hash in parents.iter().flat_map(|p| self.resolved.get(p).into_iter().flatten()) {
+                if !hashes.contains(hash) {
+                    hashes.push(hash.clone());
+                }
+            }
+            self.resolved.insert(current, hashes);
+            stack.pop();
+        }
+        Ok(self.resolved.get(commit_id).cloned().unwrap_or_default())
+    }
+}
+```
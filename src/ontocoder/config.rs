@@ -0,0 +1,240 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Project Configuration (ontocms.toml)
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::ontocoder::report::Severity;
+use crate::ontocoder::term_policy::{self, PolicyError, TermPolicy, TermScope};
+
+/// Имя файла конфигурации в корне репозитория
+pub const CONFIG_FILE_NAME: &str = "ontocms.toml";
+
+/// Уровень правила в конфигурации: `off` отключает правило
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleLevel {
+    Off,
+    Hint,
+    Info,
+    Warning,
+    Error,
+}
+
+impl RuleLevel {
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            RuleLevel::Off => None,
+            RuleLevel::Hint => Some(Severity::Hint),
+            RuleLevel::Info => Some(Severity::Info),
+            RuleLevel::Warning => Some(Severity::Warning),
+            RuleLevel::Error => Some(Severity::Error),
+        }
+    }
+}
+
+/// Какие файлы проверяются (glob-шаблоны относительно корня репозитория)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct FilesSection {
+    #[serde(default = "default_include")]
+    pub include: Vec<String>,
+    #[serde(default = "default_exclude")]
+    pub exclude: Vec<String>,
+}
+
+/// Настройка политики запрещённых терминов поверх встроенной
+/// `forbidden` и `scopes` заменяют встроенные списки, `allow*` — дополняют
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct TermsSection {
+    pub forbidden: Option<Vec<String>>,
+    pub scopes: Option<Vec<TermScope>>,
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub allow_symbols: Vec<String>,
+}
+
+/// Узел ontoCMS (`ontocli init`): ядро, профиль автора и фаза коммитов по умолчанию
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NodeSection {
+    pub kernel: String,  // инварианта ядра, например Neutral_Core-000
+    pub profile: String, // ONTO-PROFILE автора
+    #[serde(default = "default_phase")]
+    pub phase: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profiles: Option<PathBuf>, // каталог YAML onto-144 (относительно корня проекта)
+}
+
+/// Содержимое ontocms.toml
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ConfigFile {
+    pub node: Option<NodeSection>,
+    #[serde(default)]
+    pub files: FilesSection,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, RuleLevel>, // id правила → уровень
+    pub terms: Option<TermsSection>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(String),
+    Parse(String),
+    InvalidGlob { pattern: String, reason: String },
+    Policy(PolicyError),
+}
+
+/// Конфигурация проекта: файл и скомпилированные шаблоны путей
+pub struct ProjectConfig {
+    root: PathBuf,
+    file: ConfigFile,
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl ProjectConfig {
+    /// Конфигурация по умолчанию: все `.rs`, кроме `target/`
+    /// Шаблоны по умолчанию проверяются тестом `default_config_globs_are_valid` (tests/embedded_data.rs):
+    /// ошибка в них — ошибка сборки, а не входных данных
+    #[allow(clippy::expect_used)]
+    pub fn default_for(root: &Path) -> Self {
+        Self::new(root, ConfigFile::default()).expect("default globs must be valid")
+    }
+
+    pub fn new(root: &Path, file: ConfigFile) -> Result<Self, ConfigError> {
+        Ok(Self {
+            root: root.to_path_buf(),
+            include: glob_set(&file.files.include)?,
+            exclude: glob_set(&file.files.exclude)?,
+            file,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let content = fs::read_to_string(path).map_err(|e| ConfigError::Io(format!("{}: {}", path.display(), e)))?;
+        let root = path.parent().unwrap_or(Path::new("."));
+        Self::from_toml_str(root, &content)
+    }
+
+    pub fn from_toml_str(root: &Path, content: &str) -> Result<Self, ConfigError> {
+        let file: ConfigFile = toml::from_str(content).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        Self::new(root, file)
+    }
+
+    /// Ищет ontocms.toml в `start` и выше; без файла — конфигурация по умолчанию с корнем в `start`
+    pub fn discover(start: &Path) -> Result<Self, ConfigError> {
+        let start = if start.is_file() {
+            start.parent().unwrap_or(Path::new("."))
+        } else {
+            start
+        };
+        for dir in start.ancestors() {
+            let candidate = dir.join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                return Self::load(&candidate);
+            }
+        }
+        Ok(Self::default_for(start))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn file(&self) -> &ConfigFile {
+        &self.file
+    }
+
+    /// Каталог профилей onto-144 из секции `[node]`
+    pub fn profiles_dir(&self) -> Option<PathBuf> {
+        let profiles = self.file.node.as_ref()?.profiles.as_ref()?;
+        Some(self.root.join(profiles))
+    }
+
+    /// Уровень правила; `None` — важность по умолчанию
+    pub fn rule_level(&self, rule_id: &str) -> Option<RuleLevel> {
+        self.file.rules.get(rule_id).copied()
+    }
+
+    /// Проверяется ли файл: подходит под include и не подходит под exclude
+    pub fn includes(&self, path: &Path) -> bool {
+        self.include.is_match(self.relative(path)) && !self.excludes(path)
+    }
+
+    /// Исключён ли путь шаблонами exclude (для обхода директорий)
+    pub fn excludes(&self, path: &Path) -> bool {
+        self.exclude.is_match(self.relative(path))
+    }
+
+    fn relative<'p>(&self, path: &'p Path) -> &'p Path {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        relative.strip_prefix(".").unwrap_or(relative)
+    }
+
+    /// Политика терминов с учётом секции `[terms]`; `None` — встроенная без изменений
+    pub fn term_policy(&self) -> Result<Option<TermPolicy>, ConfigError> {
+        let Some(terms) = &self.file.terms else {
+            return Ok(None);
+        };
+        let mut config = term_policy::default_policy().config().clone();
+        if let Some(forbidden) = &terms.forbidden {
+            config.terms = forbidden.clone();
+        }
+        if let Some(scopes) = &terms.scopes {
+            config.scopes = scopes.clone();
+        }
+        config.allow.extend(terms.allow.iter().cloned());
+        config.allow_symbols.extend(terms.allow_symbols.iter().cloned());
+        TermPolicy::new(config).map(Some).map_err(ConfigError::Policy)
+    }
+}
+
+impl ConfigFile {
+    pub fn to_toml_string(&self) -> Result<String, ConfigError> {
+        toml::to_string_pretty(self).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+}
+
+impl Default for FilesSection {
+    fn default() -> Self {
+        Self {
+            include: default_include(),
+            exclude: default_exclude(),
+        }
+    }
+}
+
+fn default_phase() -> String {
+    "Slow".into()
+}
+
+/// Языки, заголовки которых разбирает `header::FileHeader`
+/// Только Rust: другие языки заголовков (`header::Language`) включаются явно через `[files] include`
+fn default_include() -> Vec<String> {
+    vec!["**/*.rs".into()]
+}
+
+fn default_exclude() -> Vec<String> {
+    vec!["target/**".into(), ".git/**".into()]
+}
+
+fn glob_set(patterns: &[String]) -> Result<GlobSet, ConfigError> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern).map_err(|e| ConfigError::InvalidGlob {
+            pattern: pattern.clone(),
+            reason: e.to_string(),
+        })?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|e| ConfigError::InvalidGlob {
+        pattern: patterns.join(", "),
+        reason: e.to_string(),
+    })
+}
+```
@@ -0,0 +1,206 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Software Bill of Materials (CycloneDX 1.5 JSON)
+
+use serde::{Deserialize, Serialize};
+
+use crate::ontocoder::file_inventory::FileRecord;
+use crate::ontocoder::sbom::{self, NOASSERTION};
+
+pub const SPEC_VERSION: &str = "1.5";
+pub const PROFILE_PROPERTY: &str = "ontocms:profile";
+pub const PHASE_PROPERTY: &str = "ontocms:phase";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CycloneDxBom {
+    pub bom_format: String, // всегда "CycloneDX"
+    pub spec_version: String,
+    pub serial_number: String, // urn:uuid:…
+    pub version: u32,
+    pub metadata: Metadata,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Metadata {
+    pub timestamp: String,
+    pub tools: Tools,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<Component>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Tools {
+    pub components: Vec<Component>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Component {
+    #[serde(rename = "type")]
+    pub kind: String, // application | library | file
+    #[serde(rename = "bom-ref", default, skip_serializing_if = "Option::is_none")]
+    pub bom_ref: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hashes: Vec<Hash>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub licenses: Vec<LicenseChoice>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<Property>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Hash {
+    pub alg: String, // SHA-1, SHA-256, …
+    pub content: String,
+}
+
+/// Лицензия компонента: выражение SPDX или лицензия по id / имени
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LicenseChoice {
+    Expression { expression: String },
+    License { license: NamedLicense },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NamedLicense {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Property {
+    pub name: String,
+    pub value: String,
+}
+
+impl CycloneDxBom {
+    pub fn new(name: &str, release: Option<&str>) -> Self {
+        let serial = uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext));
+        Self {
+            bom_format: "CycloneDX".into(),
+            spec_version: SPEC_VERSION.into(),
+            serial_number: format!("urn:uuid:{}", serial),
+            version: 1,
+            metadata: Metadata {
+                timestamp: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                tools: Tools {
+                    components: vec![Component::new("application", "ontocms", Some(env!("CARGO_PKG_VERSION")))],
+                },
+                component: Some(Component::new("application", name, release)),
+            },
+            components: vec![],
+        }
+    }
+
+    /// BOM исходного дерева: компонент `file` на файл, профиль и фаза — свойства `ontocms:*`
+    pub fn for_files(name: &str, release: Option<&str>, files: &[FileRecord]) -> Self {
+        let mut bom = Self::new(name, release);
+        bom.components = files
+            .iter()
+            .map(|record| {
+                let license = sbom::license_field(&record.license);
+                let licenses = if license != NOASSERTION {
+                    vec![LicenseChoice::Expression { expression: license }]
+                } else if record.license != NOASSERTION {
+                    // Не выражение SPDX (`SGRL-α`) — сохраняется как имя
+                    vec![LicenseChoice::License {
+                        license: NamedLicense {
+                            id: None,
+                            name: Some(record.license.clone()),
+                        },
+                    }]
+                } else {
+                    vec![]
+                };
+                let property = |name: &str, value: &Option<String>| {
+                    value.as_ref().map(|v| Property {
+                        name: name.into(),
+                        value: v.clone(),
+                    })
+                };
+                Component {
+                    kind: "file".into(),
+                    bom_ref: Some(format!("file:{}", record.path)),
+                    name: record.path.clone(),
+                    version: None,
+                    hashes: vec![
+                        Hash {
+                            alg: "SHA-1".into(),
+                            content: record.sha1.clone(),
+                        },
+                        Hash {
+                            alg: "SHA-256".into(),
+                            content: record.sha256.clone(),
+                        },
+                    ],
+                    licenses,
+                    purl: None,
+                    properties: [property(PROFILE_PROPERTY, &record.profile), property(PHASE_PROPERTY, &record.phase)]
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                }
+            })
+            .collect();
+        bom
+    }
+
+    /// Компоненты `file` в виде записей инвентаря (для сверки с деревом)
+    pub fn file_records(&self) -> Vec<FileRecord> {
+        self.components
+            .iter()
+            .filter(|c| c.kind == "file")
+            .map(|c| {
+                let hash = |alg: &str| c.hashes.iter().find(|h| h.alg == alg).map_or(String::new(), |h| h.content.clone());
+                let property = |name: &str| c.properties.iter().find(|p| p.name == name).map(|p| p.value.clone());
+                let license = match c.licenses.first() {
+                    Some(LicenseChoice::Expression { expression }) => expression.clone(),
+                    Some(LicenseChoice::License { license }) => license
+                        .id
+                        .clone()
+                        .or_else(|| license.name.clone())
+                        .unwrap_or_else(|| NOASSERTION.into()),
+                    None => NOASSERTION.into(),
+                };
+                FileRecord {
+                    path: c.name.clone(),
+                    sha1: hash("SHA-1"),
+                    sha256: hash("SHA-256"),
+                    license,
+                    profile: property(PROFILE_PROPERTY),
+                    phase: property(PHASE_PROPERTY),
+                }
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl Component {
+    pub fn new(kind: &str, name: &str, version: Option<&str>) -> Self {
+        Self {
+            kind: kind.into(),
+            bom_ref: None,
+            name: name.to_string(),
+            version: version.map(str::to_string),
+            hashes: vec![],
+            licenses: vec![],
+            purl: None,
+            properties: vec![],
+        }
+    }
+}
+```
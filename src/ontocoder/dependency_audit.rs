@@ -0,0 +1,366 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Dependency License Audit (Cargo.lock, offline)
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ontocoder::license_registry::{LicenseRegistry, LicenseVerdict};
+use crate::ontocoder::sbom::{self, Checksum, SpdxDocument, SpdxPackage, DOCUMENT_ID, NOASSERTION};
+
+const CRATES_IO_SOURCES: [&str; 2] = [
+    "registry+https://github.com/rust-lang/crates.io-index",
+    "sparse+https://index.crates.io/",
+];
+
+/// Пакет из Cargo.lock
+#[derive(Deserialize, Clone, Debug)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    pub source: Option<String>, // None — пакет рабочего пространства
+    pub checksum: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<String>, // "name", "name version" или "name version (source)"
+}
+
+#[derive(Deserialize)]
+struct LockFile {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+/// Где искать манифесты зависимостей без сети
+#[derive(Clone, Debug)]
+pub struct AuditSources {
+    pub workspace_root: PathBuf,
+    pub vendor_dir: Option<PathBuf>,   // `cargo vendor`
+    pub registry_dirs: Vec<PathBuf>,   // $CARGO_HOME/registry/src/*
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyStatus {
+    Compatible,
+    Incompatible,
+    Unresolved, // манифест не найден или лицензия не указана выражением SPDX
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DependencyLicense {
+    pub name: String,
+    pub version: String,
+    pub source: Option<String>,
+    pub checksum: Option<String>,
+    pub manifest: Option<PathBuf>,
+    pub license: Option<String>,      // поле `license` манифеста
+    pub license_file: Option<String>, // поле `license-file`
+    pub verdict: Option<LicenseVerdict>,
+    pub status: DependencyStatus,
+    pub note: Option<String>,
+}
+
+/// Итог аудита лицензий зависимостей
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LicenseAudit {
+    pub lockfile: String,
+    pub spdx_list_version: String,
+    pub dependencies: Vec<DependencyLicense>,
+    #[serde(skip)]
+    graph: Vec<LockedPackage>,
+}
+
+#[derive(Debug)]
+pub enum AuditError {
+    Io(String),
+    Lockfile(String),
+}
+
+impl AuditSources {
+    /// vendor/ в корне рабочего пространства и распакованный кеш реестра cargo
+    pub fn discover(workspace_root: &Path) -> Self {
+        let vendor = workspace_root.join("vendor");
+        let cargo_home = std::env::var_os("CARGO_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cargo")));
+        let registry_dirs = cargo_home
+            .and_then(|home| fs::read_dir(home.join("registry").join("src")).ok())
+            .map(|entries| entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect())
+            .unwrap_or_default();
+        Self {
+            workspace_root: workspace_root.to_path_buf(),
+            vendor_dir: vendor.is_dir().then_some(vendor),
+            registry_dirs,
+        }
+    }
+}
+
+impl LicenseAudit {
+    pub fn is_clean(&self) -> bool {
+        self.dependencies.iter().all(|d| d.status == DependencyStatus::Compatible)
+    }
+
+    pub fn count(&self, status: DependencyStatus) -> usize {
+        self.dependencies.iter().filter(|d| d.status == status).count()
+    }
+
+    /// Зависимости, принимаемые только с CLA
+    pub fn requiring_cla(&self) -> impl Iterator<Item = &DependencyLicense> {
+        self.dependencies
+            .iter()
+            .filter(|d| d.verdict.as_ref().is_some_and(|v| v.requires_cla))
+    }
+
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        for d in &self.dependencies {
+            let (mark, detail) = match d.status {
+                DependencyStatus::Compatible => ("✔", String::new()),
+                DependencyStatus::Incompatible => (
+                    "✘",
+                    d.verdict
+                        .as_ref()
+                        .map(|v| format!(" — {}", v.explanation.join("; ")))
+                        .unwrap_or_default(),
+                ),
+                DependencyStatus::Unresolved => ("?", format!(" — {}", d.note.as_deref().unwrap_or("unresolved"))),
+            };
+            let cla = if d.verdict.as_ref().is_some_and(|v| v.requires_cla && v.compatible) {
+                " [CLA]"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "{} {} {}  {}{}{}\n",
+                mark,
+                d.name,
+                d.version,
+                d.license.as_deref().unwrap_or(NOASSERTION),
+                cla,
+                detail
+            ));
+        }
+        out.push_str(&format!(
+            "{} dependencies: {} compatible, {} incompatible, {} unresolved (SPDX list {}, partial)\n",
+            self.dependencies.len(),
+            self.count(DependencyStatus::Compatible),
+            self.count(DependencyStatus::Incompatible),
+            self.count(DependencyStatus::Unresolved),
+            self.spdx_list_version
+        ));
+        out
+    }
+
+    /// SBOM SPDX 2.3: пакеты рабочего пространства, зависимости и граф DEPENDS_ON из Cargo.lock
+    pub fn to_spdx(&self, document_name: &str) -> SpdxDocument {
+        let mut doc = SpdxDocument::new(document_name);
+        doc.creation_info.license_list_version = Some(self.spdx_list_version.clone());
+
+        for d in &self.dependencies {
+            let id = package_ref(&d.name, &d.version);
+            let declared = d.verdict.as_ref().map_or(NOASSERTION.to_string(), |v| v.expression.clone());
+            let concluded = match &d.verdict {
+                Some(v) if v.compatible => v.licenses.join(" AND "),
+                _ => NOASSERTION.to_string(),
+            };
+            doc.packages.push(SpdxPackage {
+                spdx_id: id.clone(),
+                name: d.name.clone(),
+                version_info: Some(d.version.clone()),
+                download_location: download_location(d),
+                files_analyzed: false,
+                package_verification_code: None,
+                license_concluded: concluded,
+                license_declared: declared,
+                license_info_from_files: vec![],
+                copyright_text: NOASSERTION.into(),
+                checksums: d
+                    .checksum
+                    .iter()
+                    .map(|c| Checksum {
+                        algorithm: "SHA256".into(),
+                        checksum_value: c.clone(),
+                    })
+                    .collect(),
+                external_refs: vec![sbom::cargo_purl(&d.name, &d.version)],
+                comment: Some(sgcl_comment(d)),
+            });
+            if d.source.is_none() {
+                doc.relate(DOCUMENT_ID, "DESCRIBES", &id);
+            }
+        }
+
+        for package in &self.graph {
+            let from = package_ref(&package.name, &package.version);
+            for dependency in &package.dependencies {
+                if let Some(target) = resolve_dependency(&self.graph, dependency) {
+                    doc.relate(&from, "DEPENDS_ON", &package_ref(&target.name, &target.version));
+                }
+            }
+        }
+        doc
+    }
+}
+
+/// Аудит Cargo.lock: лицензия каждой зависимости по её манифесту, без сети
+pub fn audit_lockfile(lockfile: &Path, sources: &AuditSources, registry: &LicenseRegistry) -> Result<LicenseAudit, AuditError> {
+    let content = fs::read_to_string(lockfile).map_err(|e| AuditError::Io(format!("{}: {}", lockfile.display(), e)))?;
+    let lock: LockFile = toml::from_str(&content).map_err(|e| AuditError::Lockfile(e.to_string()))?;
+
+    let workspace_manifest = read_manifest(&sources.workspace_root.join("Cargo.toml"));
+    let members = workspace_members(&sources.workspace_root);
+
+    let dependencies = lock
+        .package
+        .iter()
+        .map(|package| {
+            let manifest = match &package.source {
+                None => members.get(&package.name).cloned(),
+                Some(_) => find_manifest(package, sources),
+            };
+            audit_package(package, manifest, workspace_manifest.as_ref(), registry)
+        })
+        .collect();
+
+    Ok(LicenseAudit {
+        lockfile: lockfile.display().to_string(),
+        spdx_list_version: registry.list_version().to_string(),
+        dependencies,
+        graph: lock.package,
+    })
+}
+
+fn audit_package(
+    package: &LockedPackage,
+    manifest: Option<PathBuf>,
+    workspace: Option<&toml::Value>,
+    registry: &LicenseRegistry,
+) -> DependencyLicense {
+    let mut result = DependencyLicense {
+        name: package.name.clone(),
+        version: package.version.clone(),
+        source: package.source.clone(),
+        checksum: package.checksum.clone(),
+        manifest: manifest.clone(),
+        license: None,
+        license_file: None,
+        verdict: None,
+        status: DependencyStatus::Unresolved,
+        note: None,
+    };
+
+    let Some(value) = manifest.as_deref().and_then(read_manifest) else {
+        result.note = Some("manifest not found offline (vendor/ or cargo registry cache)".into());
+        return result;
+    };
+    result.license = package_field(&value, workspace, "license");
+    result.license_file = package_field(&value, workspace, "license-file");
+
+    let Some(license) = &result.license else {
+        result.note = Some(match &result.license_file {
+            Some(file) => format!("license-file only ({}): review manually", file),
+            None => "no license declared in Cargo.toml".into(),
+        });
+        return result;
+    };
+    let verdict = registry.evaluate(license);
+    result.status = if verdict.compatible {
+        DependencyStatus::Compatible
+    } else {
+        DependencyStatus::Incompatible
+    };
+    result.verdict = Some(verdict);
+    result
+}
+
+/// Манифест крейта: vendor/<name>-<version>, vendor/<name>, затем кеш реестра
+fn find_manifest(package: &LockedPackage, sources: &AuditSources) -> Option<PathBuf> {
+    let versioned = format!("{}-{}", package.name, package.version);
+    let mut candidates = vec![];
+    if let Some(vendor) = &sources.vendor_dir {
+        candidates.push(vendor.join(&versioned));
+        candidates.push(vendor.join(&package.name));
+    }
+    candidates.extend(sources.registry_dirs.iter().map(|dir| dir.join(&versioned)));
+
+    candidates.into_iter().map(|dir| dir.join("Cargo.toml")).find(|manifest| {
+        read_manifest(manifest).is_some_and(|value| {
+            value.get("package").and_then(|p| p.get("version")).and_then(|v| v.as_str()) == Some(package.version.as_str())
+        })
+    })
+}
+
+/// Пакеты рабочего пространства: имя → Cargo.toml
+fn workspace_members(root: &Path) -> HashMap<String, PathBuf> {
+    walkdir::WalkDir::new(root)
+        .max_depth(4)
+        .into_iter()
+        .filter_entry(|e| !matches!(e.file_name().to_str(), Some("target" | "vendor" | ".git")))
+        .flatten()
+        .filter(|e| e.file_name() == "Cargo.toml")
+        .filter_map(|e| {
+            let value = read_manifest(e.path())?;
+            let name = value.get("package")?.get("name")?.as_str()?.to_string();
+            Some((name, e.path().to_path_buf()))
+        })
+        .collect()
+}
+
+fn read_manifest(path: &Path) -> Option<toml::Value> {
+    toml::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// Поле `[package]`; `{ workspace = true }` — из `[workspace.package]` корневого манифеста
+fn package_field(manifest: &toml::Value, workspace: Option<&toml::Value>, key: &str) -> Option<String> {
+    let value = manifest.get("package")?.get(key)?;
+    if let Some(s) = value.as_str() {
+        return Some(s.to_string());
+    }
+    if value.get("workspace").and_then(|w| w.as_bool()) == Some(true) {
+        return workspace?
+            .get("workspace")?
+            .get("package")?
+            .get(key)?
+            .as_str()
+            .map(str::to_string);
+    }
+    None
+}
+
+/// "name", "name version" или "name version (source)" → пакет Cargo.lock
+fn resolve_dependency<'a>(packages: &'a [LockedPackage], dependency: &str) -> Option<&'a LockedPackage> {
+    let mut parts = dependency.split_whitespace();
+    let name = parts.next()?;
+    let version = parts.next();
+    packages
+        .iter()
+        .filter(|p| p.name == name)
+        .find(|p| version.is_none_or(|v| p.version == v))
+}
+
+fn package_ref(name: &str, version: &str) -> String {
+    format!("SPDXRef-Package-{}", sbom::spdx_ref(&format!("{}-{}", name, version)))
+}
+
+fn download_location(d: &DependencyLicense) -> String {
+    match d.source.as_deref() {
+        Some(source) if CRATES_IO_SOURCES.contains(&source) => {
+            format!("https://crates.io/api/v1/crates/{}/{}/download", d.name, d.version)
+        }
+        Some(source) if source.starts_with("git+") => source.to_string(),
+        _ => NOASSERTION.into(),
+    }
+}
+
+fn sgcl_comment(d: &DependencyLicense) -> String {
+    match (&d.verdict, d.status) {
+        (Some(v), DependencyStatus::Compatible) if v.requires_cla => "SGCL: compatible (CLA required)".into(),
+        (Some(_), DependencyStatus::Compatible) => "SGCL: compatible".into(),
+        (Some(v), _) => format!("SGCL: incompatible — {}", v.explanation.join("; ")),
+        (None, _) => format!("SGCL: unresolved — {}", d.note.as_deref().unwrap_or("no license")),
+    }
+}
+```
@@ -0,0 +1,174 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Source File Inventory (license, onto-144 profile, phase, checksums)
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::ontocoder::config::ProjectConfig;
+use crate::ontocoder::cyclonedx::CycloneDxBom;
+use crate::ontocoder::header::FileHeader;
+use crate::ontocoder::license_registry::LicenseRegistry;
+use crate::ontocoder::sbom::{self, SpdxDocument};
+
+/// Файл дерева: контрольные суммы, лицензия и онтологические аннотации заголовка
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FileRecord {
+    pub path: String, // относительно корня проекта, через `/`
+    pub sha1: String,
+    pub sha256: String,
+    pub license: String, // выражение из SPDX-License-Identifier или NOASSERTION
+    pub profile: Option<String>,
+    pub phase: Option<String>,
+}
+
+/// Расхождение одного поля файла между SBOM и деревом
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FileChange {
+    pub path: String,
+    pub field: String, // checksum | license | profile | phase
+    pub recorded: Option<String>,
+    pub actual: Option<String>,
+}
+
+/// Результат сверки SBOM с деревом
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct InventoryDiff {
+    pub added: Vec<String>,   // есть в дереве, нет в SBOM
+    pub removed: Vec<String>, // есть в SBOM, нет в дереве
+    pub changed: Vec<FileChange>,
+}
+
+#[derive(Debug)]
+pub enum InventoryError {
+    Io(String),
+    Walk(String),
+    Sbom(String), // документ не SPDX 2.3 JSON и не CycloneDX JSON
+}
+
+impl FileRecord {
+    pub fn new(relative: &Path, bytes: &[u8], registry: &LicenseRegistry) -> Self {
+        let content = String::from_utf8_lossy(bytes);
+        let header = FileHeader::of_file(relative, &content);
+        Self {
+            path: relative_path(relative),
+            sha1: sha1_smol::Sha1::from(bytes).digest().to_string(),
+            sha256: hex::encode(Sha256::digest(bytes)),
+            license: registry.detect_license_in(relative, &content),
+            profile: header.profile.map(|t| t.value),
+            phase: header.phase.map(|t| t.value),
+        }
+    }
+}
+
+/// Файлы проекта по include/exclude из ontocms.toml, отсортированные по пути
+pub fn collect(config: &ProjectConfig, registry: &LicenseRegistry) -> Result<Vec<FileRecord>, InventoryError> {
+    let root = config.root();
+    let walker = walkdir::WalkDir::new(root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| !config.excludes(e.path()));
+
+    let mut records = vec![];
+    for entry in walker {
+        let entry = entry.map_err(|e| InventoryError::Walk(e.to_string()))?;
+        if !entry.file_type().is_file() || !config.includes(entry.path()) {
+            continue;
+        }
+        let bytes = fs::read(entry.path()).map_err(|e| InventoryError::Io(format!("{}: {}", entry.path().display(), e)))?;
+        let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        records.push(FileRecord::new(relative, &bytes, registry));
+    }
+    records.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(records)
+}
+
+/// Файлы из SBOM: формат определяется по содержимому (SPDX 2.3 JSON или CycloneDX JSON)
+pub fn records_from_sbom(json: &str) -> Result<Vec<FileRecord>, InventoryError> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| InventoryError::Sbom(e.to_string()))?;
+    if value.get("spdxVersion").is_some() {
+        let doc: SpdxDocument = serde_json::from_value(value).map_err(|e| InventoryError::Sbom(e.to_string()))?;
+        Ok(doc.file_records())
+    } else if value.get("bomFormat").and_then(|f| f.as_str()) == Some("CycloneDX") {
+        let bom: CycloneDxBom = serde_json::from_value(value).map_err(|e| InventoryError::Sbom(e.to_string()))?;
+        Ok(bom.file_records())
+    } else {
+        Err(InventoryError::Sbom("neither SPDX (spdxVersion) nor CycloneDX (bomFormat)".into()))
+    }
+}
+
+/// Сверка записанного SBOM с текущим деревом
+/// Лицензии сравниваются в форме, в которой они попадают в SBOM (`sbom::license_field`)
+pub fn diff(recorded: &[FileRecord], actual: &[FileRecord]) -> InventoryDiff {
+    let recorded: BTreeMap<&str, &FileRecord> = recorded.iter().map(|r| (r.path.as_str(), r)).collect();
+    let actual: BTreeMap<&str, &FileRecord> = actual.iter().map(|r| (r.path.as_str(), r)).collect();
+
+    let mut result = InventoryDiff {
+        added: actual.keys().filter(|p| !recorded.contains_key(*p)).map(|p| p.to_string()).collect(),
+        removed: recorded.keys().filter(|p| !actual.contains_key(*p)).map(|p| p.to_string()).collect(),
+        changed: vec![],
+    };
+
+    for (path, before) in &recorded {
+        let Some(after) = actual.get(path) else { continue };
+        let fields = [
+            ("checksum", Some(before.sha1.clone()), Some(after.sha1.clone())),
+            (
+                "license",
+                Some(sbom::license_field(&before.license)),
+                Some(sbom::license_field(&after.license)),
+            ),
+            ("profile", before.profile.clone(), after.profile.clone()),
+            ("phase", before.phase.clone(), after.phase.clone()),
+        ];
+        for (field, recorded, actual) in fields {
+            if recorded != actual {
+                result.changed.push(FileChange {
+                    path: path.to_string(),
+                    field: field.into(),
+                    recorded,
+                    actual,
+                });
+            }
+        }
+    }
+    result
+}
+
+impl InventoryDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        for path in &self.added {
+            out.push_str(&format!("+ {} (not in SBOM)\n", path));
+        }
+        for path in &self.removed {
+            out.push_str(&format!("- {} (missing from tree)\n", path));
+        }
+        for change in &self.changed {
+            out.push_str(&format!(
+                "~ {}: {} {} → {}\n",
+                change.path,
+                change.field,
+                change.recorded.as_deref().unwrap_or("none"),
+                change.actual.as_deref().unwrap_or("none")
+            ));
+        }
+        out
+    }
+}
+
+fn relative_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+```
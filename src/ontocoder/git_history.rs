@@ -0,0 +1,371 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Local Git History (last change per file)
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use chrono::TimeZone;
+
+use crate::ontocoder::cla_registry::Contributor;
+
+/// Трейлер коммита с DID автора: `Contributor-DID: did:key:…`
+pub const DID_TRAILER: &str = "Contributor-DID";
+/// Трейлеры онтологической атрибуции коммита: `Onto-Profile: Aries-Wood-Rabbit`, `Onto-Phase: Slow`
+pub const PROFILE_TRAILER: &str = "Onto-Profile";
+pub const PHASE_TRAILER: &str = "Onto-Phase";
+/// Метка хуков ontocli: чужие хуки без `force` не перезаписываются
+pub const HOOK_MARKER: &str = "# ontocms-hook";
+
+/// Последнее изменение файла
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LastChange {
+    pub commit: String,
+    pub author: Contributor,
+    pub date: String, // ISO 8601 (дата автора)
+}
+
+/// Коммит и его трейлеры онтологической атрибуции
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitInfo {
+    pub id: String, // полный хеш коммита
+    pub parents: Vec<String>,
+    pub author: Contributor,
+    pub timestamp: u64, // дата автора, Unix timestamp (мс)
+    pub summary: String,
+    pub profile: Option<String>, // трейлер Onto-Profile
+    pub phase: Option<String>,   // трейлер Onto-Phase
+}
+
+/// Итог установки хука
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HookInstall {
+    Written(PathBuf),
+    Kept(PathBuf), // чужой хук без метки ontocli
+}
+
+#[derive(Debug)]
+pub enum GitError {
+    NotARepository(String),
+    Command(String),
+}
+
+/// Последние изменения всех файлов по истории текущей ветки
+/// Коммиты слияния пропускаются: их автор не писал изменения
+pub struct GitHistory {
+    root: PathBuf,
+    last_changes: HashMap<PathBuf, LastChange>, // путь относительно корня репозитория
+}
+
+impl GitHistory {
+    /// Один обход локальной истории через libgit2, от новых коммитов к старым; сеть не используется
+    /// Первая встреча пути — самое свежее изменение
+    pub fn load(path: &Path) -> Result<Self, GitError> {
+        let root = repository_root(path)?;
+        let repository = open(&root)?;
+        let mut last_changes = HashMap::new();
+        let Ok(head) = repository.head().and_then(|head| head.peel_to_commit()) else {
+            // Репозиторий без коммитов
+            return Ok(Self { root, last_changes });
+        };
+
+        let mut walk = repository.revwalk().map_err(libgit2_error)?;
+        walk.set_sorting(git2::Sort::TIME).map_err(libgit2_error)?;
+        walk.push(head.id()).map_err(libgit2_error)?;
+        for oid in walk {
+            let commit = repository.find_commit(oid.map_err(libgit2_error)?).map_err(libgit2_error)?;
+            if commit.parent_count() > 1 {
+                continue;
+            }
+            let parent_tree = match commit.parent(0) {
+                Ok(parent) => Some(parent.tree().map_err(libgit2_error)?),
+                Err(_) => None, // корневой коммит: все файлы добавлены
+            };
+            let tree = commit.tree().map_err(libgit2_error)?;
+            let diff = repository
+                .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+                .map_err(libgit2_error)?;
+            let info = info_of(&commit)?;
+            let change = LastChange {
+                commit: info.id,
+                author: info.author,
+                date: iso_date(commit.author().when()),
+            };
+            for file in diff.deltas().filter_map(|delta| delta.new_file().path()) {
+                last_changes.entry(file.to_path_buf()).or_insert_with(|| change.clone());
+            }
+        }
+        Ok(Self { root, last_changes })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Последний коммит, изменивший файл; `None` — файл не в истории (новый или вне репозитория)
+    pub fn last_change(&self, path: &Path) -> Option<&LastChange> {
+        let absolute = std::fs::canonicalize(path).ok()?;
+        let relative = absolute.strip_prefix(&self.root).ok()?;
+        self.last_changes.get(relative)
+    }
+}
+
+/// Корень рабочего дерева git, в котором лежит `path`
+pub fn repository_root(path: &Path) -> Result<PathBuf, GitError> {
+    let dir = if path.is_dir() { path } else { path.parent().unwrap_or(Path::new(".")) };
+    let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+    let toplevel = git(dir, &["rev-parse", "--show-toplevel"])
+        .map_err(|_| GitError::NotARepository(dir.display().to_string()))?;
+    std::fs::canonicalize(toplevel.trim()).map_err(|e| GitError::Command(e.to_string()))
+}
+
+/// Файлы в индексе (добавленные, изменённые, переименованные) — абсолютные пути
+pub fn staged_files(root: &Path) -> Result<Vec<PathBuf>, GitError> {
+    let output = git(root, &["diff", "--cached", "--name-only", "--diff-filter=ACMR", "-z"])?;
+    Ok(output
+        .split('\0')
+        .filter(|f| !f.is_empty())
+        .map(|f| root.join(f))
+        .collect())
+}
+
+/// Содержимое файла в индексе (`git show :path`)
+pub fn staged_content(root: &Path, path: &Path) -> Result<String, GitError> {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let spec = format!(":{}", relative.to_string_lossy().replace('\\', "/"));
+    git(root, &["show", &spec])
+}
+
+/// Замена содержимого файла в индексе; рабочее дерево не меняется
+pub fn stage_content(root: &Path, path: &Path, content: &str) -> Result<(), GitError> {
+    let relative = path.strip_prefix(root).unwrap_or(path).to_string_lossy().replace('\\', "/");
+    // Режим файла (исполняемый, симлинк) сохраняется из текущей записи индекса
+    let entry = git(root, &["ls-files", "--stage", "--", &relative])?;
+    let mode = entry.split_whitespace().next().unwrap_or("100644");
+    let blob = git_with_input(root, &["hash-object", "-w", "--no-filters", "--stdin"], content)?;
+    let cacheinfo = format!("{},{},{}", mode, blob.trim(), relative);
+    git(root, &["update-index", "--cacheinfo", &cacheinfo])?;
+    Ok(())
+}
+
+/// Сообщение коммита с трейлерами атрибуции; прежние значения тех же трейлеров заменяются
+pub fn with_trailers(root: &Path, message: &str, profile: &str, phase: &str) -> Result<String, GitError> {
+    let profile = format!("{}: {}", PROFILE_TRAILER, profile);
+    let phase = format!("{}: {}", PHASE_TRAILER, phase);
+    // Без завершающего перевода строки трейлеры попадают в строку темы
+    let message = format!("{}\n", message.trim_end());
+    git_with_input(
+        root,
+        &["interpret-trailers", "--if-exists", "replace", "--trailer", &profile, "--trailer", &phase],
+        &message,
+    )
+}
+
+/// Хуки узла: pre-commit проверяет индекс, commit-msg добавляет трейлеры атрибуции по умолчанию
+/// Каталог хуков сообщает git (учитывается `core.hooksPath`)
+pub fn install_hooks(root: &Path, profile: &str, phase: &str, force: bool) -> Result<Vec<HookInstall>, GitError> {
+    let hooks = root.join(git(root, &["rev-parse", "--git-path", "hooks"])?.trim());
+    fs::create_dir_all(&hooks).map_err(|e| GitError::Command(e.to_string()))?;
+
+    let pre_commit = format!(
+        "#!/bin/sh\n{}\n# Проверка индекса: SPDX, ONTO-PROFILE, ONTO-PHASE, SGRL-α\nexec ontocli validate --staged .\n",
+        HOOK_MARKER
+    );
+    // Профиль и фаза узла — если автор не указал свои трейлеры
+    let commit_msg = format!(
+        "#!/bin/sh\n{}\n# Трейлеры атрибуции по умолчанию из ontocms.toml (после его изменения — `ontocli init --force`)\n\
+         git interpret-trailers --in-place --if-exists doNothing \\\n  --trailer \"{}: {}\" --trailer \"{}: {}\" \"$1\"\n",
+        HOOK_MARKER, PROFILE_TRAILER, profile, PHASE_TRAILER, phase
+    );
+    let mut installed = vec![];
+    for (name, script) in [("pre-commit", pre_commit), ("commit-msg", commit_msg)] {
+        let path = hooks.join(name);
+        if let Ok(existing) = fs::read_to_string(&path) {
+            if !force && !existing.contains(HOOK_MARKER) {
+                installed.push(HookInstall::Kept(path));
+                continue;
+            }
+        }
+        fs::write(&path, script)
+            .and_then(|()| make_executable(&path))
+            .map_err(|e| GitError::Command(format!("{}: {}", path.display(), e)))?;
+        installed.push(HookInstall::Written(path));
+    }
+    Ok(installed)
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Коммит индекса с сообщением `message`; хуки репозитория выполняются как при `git commit`
+pub fn commit(root: &Path, message: &str) -> Result<CommitInfo, GitError> {
+    git_with_input(root, &["commit", "--quiet", "--file", "-"], message)?;
+    commit_info(root, "HEAD")
+}
+
+/// Коммит, который создаст `commit(root, message)`: родитель — HEAD, автор — `git var GIT_AUTHOR_IDENT`
+/// Хеш ещё неизвестен (нулевой) — по такому коммиту событие проверяется до записи в историю
+pub fn pending_commit(root: &Path, message: &str) -> Result<CommitInfo, GitError> {
+    let parents = match git(root, &["rev-parse", "--verify", "--quiet", "HEAD"]) {
+        Ok(head) => vec![head.trim().to_string()],
+        Err(_) => vec![], // первый коммит
+    };
+    // `Имя <почта> секунды зона`
+    let ident = git(root, &["var", "GIT_AUTHOR_IDENT"])?;
+    let (name, rest) = ident.trim().split_once(" <").unwrap_or((ident.trim(), ""));
+    let (email, date) = rest.split_once("> ").unwrap_or((rest, ""));
+    let seconds = date.split_whitespace().next().and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
+    let trailers = git2::message_trailers_strs(message).map_err(libgit2_error)?;
+    // Тема — первый абзац сообщения в одну строку, как `%s`
+    let summary = message.trim_start().split("\n\n").next().unwrap_or_default();
+    Ok(CommitInfo {
+        id: "0".repeat(40),
+        parents,
+        author: Contributor {
+            name: name.to_string(),
+            email: email.to_string(),
+            did: trailer_of(&trailers, DID_TRAILER),
+        },
+        timestamp: seconds * 1000,
+        summary: summary.lines().map(str::trim).collect::<Vec<_>>().join(" "),
+        profile: trailer_of(&trailers, PROFILE_TRAILER),
+        phase: trailer_of(&trailers, PHASE_TRAILER),
+    })
+}
+
+/// Коммит по ревизии (`HEAD`, хеш, ветка)
+pub fn commit_info(root: &Path, revision: &str) -> Result<CommitInfo, GitError> {
+    let repository = open(root)?;
+    let commit = repository
+        .revparse_single(revision)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(libgit2_error)?;
+    info_of(&commit)
+}
+
+/// Родители каждого коммита истории `revision` (один обход libgit2)
+pub fn parent_map(root: &Path, revision: &str) -> Result<HashMap<String, Vec<String>>, GitError> {
+    let repository = open(root)?;
+    let mut walk = repository.revwalk().map_err(libgit2_error)?;
+    let head = repository.revparse_single(revision).map_err(libgit2_error)?.peel_to_commit().map_err(libgit2_error)?;
+    walk.push(head.id()).map_err(libgit2_error)?;
+    let mut parents = HashMap::new();
+    for oid in walk {
+        let commit = repository.find_commit(oid.map_err(libgit2_error)?).map_err(libgit2_error)?;
+        parents.insert(commit.id().to_string(), commit.parent_ids().map(|id| id.to_string()).collect());
+    }
+    Ok(parents)
+}
+
+/// Коммиты истории `revision` от старых к новым, без предков `since` (уже принятых)
+/// История читается через libgit2: один проход без процесса git на коммит
+pub fn commits_since(root: &Path, revision: &str, since: Option<&str>) -> Result<Vec<CommitInfo>, GitError> {
+    let repository = open(root)?;
+    let mut walk = repository.revwalk().map_err(libgit2_error)?;
+    walk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE).map_err(libgit2_error)?;
+    let head = repository.revparse_single(revision).map_err(libgit2_error)?.peel_to_commit().map_err(libgit2_error)?;
+    walk.push(head.id()).map_err(libgit2_error)?;
+    // Отметка, которой больше нет в репозитории (rebase, gc), не ограничивает обход
+    if let Some(since) = since.and_then(|s| git2::Oid::from_str(s).ok()) {
+        if repository.find_commit(since).is_ok() {
+            walk.hide(since).map_err(libgit2_error)?;
+        }
+    }
+
+    let mut commits = vec![];
+    for oid in walk {
+        let commit = repository.find_commit(oid.map_err(libgit2_error)?).map_err(libgit2_error)?;
+        commits.push(info_of(&commit)?);
+    }
+    Ok(commits)
+}
+
+fn open(root: &Path) -> Result<git2::Repository, GitError> {
+    git2::Repository::discover(root).map_err(|_| GitError::NotARepository(root.display().to_string()))
+}
+
+/// Трейлеры читаются из сообщения через libgit2 — так же, как у ещё не созданного коммита
+fn info_of(commit: &git2::Commit) -> Result<CommitInfo, GitError> {
+    let message = String::from_utf8_lossy(commit.message_bytes()).into_owned();
+    let trailers = git2::message_trailers_strs(&message).map_err(libgit2_error)?;
+    let author = commit.author();
+    Ok(CommitInfo {
+        id: commit.id().to_string(),
+        parents: commit.parent_ids().map(|id| id.to_string()).collect(),
+        author: Contributor {
+            name: String::from_utf8_lossy(author.name_bytes()).into_owned(),
+            email: String::from_utf8_lossy(author.email_bytes()).into_owned(),
+            did: trailer_of(&trailers, DID_TRAILER),
+        },
+        timestamp: u64::try_from(author.when().seconds()).unwrap_or(0) * 1000,
+        summary: commit.summary_bytes().map(|s| String::from_utf8_lossy(s).into_owned()).unwrap_or_default(),
+        profile: trailer_of(&trailers, PROFILE_TRAILER),
+        phase: trailer_of(&trailers, PHASE_TRAILER),
+    })
+}
+
+/// Дата автора в ISO 8601 с его часовым поясом, как `%aI`
+fn iso_date(time: git2::Time) -> String {
+    chrono::FixedOffset::east_opt(time.offset_minutes() * 60)
+        .and_then(|offset| offset.timestamp_opt(time.seconds(), 0).single())
+        .map(|date| date.to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// Ключи трейлеров сравниваются без учёта регистра, как в git; учитывается первое значение
+fn trailer_of(trailers: &git2::MessageTrailersStrs, key: &str) -> Option<String> {
+    trailers
+        .iter()
+        .find(|(k, v)| k.eq_ignore_ascii_case(key) && !v.trim().is_empty())
+        .map(|(_, v)| v.trim().to_string())
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String, GitError> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|e| GitError::Command(e.to_string()))?;
+    if !output.status.success() {
+        return Err(GitError::Command(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn libgit2_error(error: git2::Error) -> GitError {
+    GitError::Command(error.message().to_string())
+}
+
+/// `git` с данными на stdin
+fn git_with_input(dir: &Path, args: &[&str], input: &str) -> Result<String, GitError> {
+    let mut child = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| GitError::Command(e.to_string()))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes()).map_err(|e| GitError::Command(e.to_string()))?;
+    }
+    let output = child.wait_with_output().map_err(|e| GitError::Command(e.to_string()))?;
+    if !output.status.success() {
+        return Err(GitError::Command(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+```
@@ -0,0 +1,149 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Git History Ingest (incremental, with checkpoint)
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+use crate::core::activity_ledger::{ActivityLedger, OntoPhase, ProfileId};
+use crate::core::local_mirror::LocalMirror;
+use crate::ethics::pipeline::EthicsPipeline;
+use crate::ontocoder::commit_event::{commit_event, CommitLinks};
+use crate::ontocoder::git_history::{self, CommitInfo, GitError};
+use crate::transport::json_ld::JsonLdError;
+
+/// Отметка LocalMirror: последний коммит, до которого вся история принята
+pub const CHECKPOINT: &str = "git-ingest";
+
+/// Итог импорта одного коммита
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IngestOutcome {
+    Ingested(ProfileId),
+    Known,                // уже в зеркале: записан `ontocli commit` или прошлым импортом
+    Unattributed,         // без трейлера Onto-Profile и без профиля по умолчанию (Закон I)
+    Rejected(String),     // фаза, профиль или этический конвейер
+    NotPersisted(String), // событие принято, но зеркало его не записало
+}
+
+impl IngestOutcome {
+    /// Коммит, который следующий импорт должен пройти снова
+    /// Коммит без атрибуции не повторяется: его примет только `--full` с профилем по умолчанию
+    fn pins_checkpoint(&self) -> bool {
+        matches!(self, IngestOutcome::Rejected(_) | IngestOutcome::NotPersisted(_))
+    }
+}
+
+#[derive(Debug)]
+pub enum IngestError {
+    Git(GitError),
+    JsonLd(JsonLdError),
+    Checkpoint(io::Error),
+}
+
+/// Итог импорта: коммиты от старых к новым и записанная отметка
+pub struct IngestReport {
+    pub commits: Vec<(CommitInfo, IngestOutcome)>,
+    pub checkpoint: Option<String>,
+}
+
+impl IngestReport {
+    pub fn count(&self, matches: impl Fn(&IngestOutcome) -> bool) -> usize {
+        self.commits.iter().filter(|(_, outcome)| matches(outcome)).count()
+    }
+}
+
+/// Импорт истории git в локальный журнал: событие на каждый атрибутированный коммит
+pub struct GitIngest<'a> {
+    mirror: &'a LocalMirror,
+    pipeline: &'a EthicsPipeline,
+    chain_starts: HashMap<String, String>, // профиль → invariant_hash (начало причинной цепочки, Закон II)
+    profile: Option<String>,               // для коммитов без трейлера Onto-Profile
+    phase: OntoPhase,                      // для коммитов без трейлера Onto-Phase
+}
+
+impl<'a> GitIngest<'a> {
+    pub fn new(mirror: &'a LocalMirror, pipeline: &'a EthicsPipeline, chain_starts: HashMap<String, String>) -> Self {
+        Self {
+            mirror,
+            pipeline,
+            chain_starts,
+            profile: None,
+            phase: OntoPhase::Slow,
+        }
+    }
+
+    pub fn with_profile(mut self, profile: Option<String>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn with_phase(mut self, phase: OntoPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Коммиты `revision` после отметки прошлого импорта (`full` — вся история)
+    /// Отметка — последний коммит перед первым отклонённым или не записанным:
+    /// они и всё после них проходятся снова при следующем импорте
+    pub fn run(&self, root: &Path, revision: &str, full: bool) -> Result<IngestReport, IngestError> {
+        let since = if full { None } else { self.mirror.checkpoint(CHECKPOINT) };
+        let commits = git_history::commits_since(root, revision, since.as_deref()).map_err(IngestError::Git)?;
+        // Причинные связи идут через все родители; коммит без события связывается через своих предков
+        let graph = git_history::parent_map(root, revision).map_err(IngestError::Git)?;
+        let mut links = CommitLinks::new(self.mirror, graph);
+
+        let mut ledger = ActivityLedger::new();
+        let mut outcomes = Vec::with_capacity(commits.len());
+        for commit in commits {
+            let outcome = self.ingest(&commit, &mut links, &mut ledger)?;
+            outcomes.push((commit, outcome));
+        }
+
+        let first_missed = outcomes.iter().position(|(_, outcome)| outcome.pins_checkpoint());
+        let checkpoint = outcomes[..first_missed.unwrap_or(outcomes.len())]
+            .last()
+            .map(|(commit, _)| commit.id.clone());
+        if let Some(last) = &checkpoint {
+            self.mirror.set_checkpoint(CHECKPOINT, last).map_err(IngestError::Checkpoint)?;
+        }
+        Ok(IngestReport {
+            commits: outcomes,
+            checkpoint,
+        })
+    }
+
+    fn ingest(
+        &self,
+        commit: &CommitInfo,
+        links: &mut CommitLinks,
+        ledger: &mut ActivityLedger,
+    ) -> Result<IngestOutcome, IngestError> {
+        if self.mirror.find_event(&commit.id).is_some() {
+            return Ok(IngestOutcome::Known);
+        }
+        let Some(profile) = commit.profile.clone().or_else(|| self.profile.clone()) else {
+            return Ok(IngestOutcome::Unattributed);
+        };
+        let phase = match commit.phase.as_deref().map(str::parse::<OntoPhase>) {
+            Some(Ok(phase)) => phase,
+            Some(Err(e)) => return Ok(IngestOutcome::Rejected(e)),
+            None => self.phase.clone(),
+        };
+        let Some(chain_start) = self.chain_starts.get(&profile) else {
+            return Ok(IngestOutcome::Rejected(format!("profile '{}' is not in the onto-144 registry", profile)));
+        };
+
+        let parent_events = links.parent_events(commit).map_err(IngestError::JsonLd)?;
+        let event = commit_event(commit, ProfileId(profile), phase, &parent_events, chain_start);
+        if let Err(report) = ledger.ingest(event.clone(), self.pipeline) {
+            return Ok(IngestOutcome::Rejected(report.to_string()));
+        }
+        if let Err(e) = self.mirror.persist(&event) {
+            return Ok(IngestOutcome::NotPersisted(e.to_string()));
+        }
+        links.record(&event).map_err(IngestError::JsonLd)?;
+        Ok(IngestOutcome::Ingested(event.profile_id))
+    }
+}
+```
@@ -0,0 +1,511 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Language-Aware File Headers (SPDX, ONTO-PROFILE, ONTO-PHASE)
+
+use std::ops::Range;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ontocoder::report::{LineIndex, SourceSpan};
+use crate::ontocoder::suppression::SUPPRESSION_MARKER;
+
+pub const SPDX_TAG: &str = "SPDX-License-Identifier:";
+pub const PROFILE_TAG: &str = "ONTO-PROFILE:";
+pub const PHASE_TAG: &str = "ONTO-PHASE:";
+
+/// Язык файла — определяет синтаксис комментариев заголовка
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    Rust,
+    Python,
+    TypeScript,
+    JavaScript,
+    Yaml,
+    Markdown,
+    Shell,
+}
+
+/// Комментарии языка: строчные префиксы и блочные разделители
+struct CommentSyntax {
+    line: &'static [&'static str],
+    block: Option<(&'static str, &'static str)>,
+    shebang: bool,
+}
+
+impl Language {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        Some(match extension.as_str() {
+            "rs" => Language::Rust,
+            "py" | "pyi" => Language::Python,
+            "ts" | "tsx" | "mts" | "cts" => Language::TypeScript,
+            "js" | "jsx" | "mjs" | "cjs" => Language::JavaScript,
+            "yaml" | "yml" => Language::Yaml,
+            "md" | "markdown" => Language::Markdown,
+            "sh" | "bash" | "zsh" => Language::Shell,
+            _ => return None,
+        })
+    }
+
+    /// Язык по интерпретатору в shebang: `#!/usr/bin/env python3`
+    pub fn from_shebang(line: &str) -> Option<Self> {
+        let command = line.strip_prefix("#!")?.trim();
+        let mut parts = command.split_whitespace();
+        let mut program = parts.next()?.rsplit('/').next()?;
+        if program == "env" {
+            program = parts.find(|p| !p.starts_with('-'))?;
+        }
+        let program = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+        Some(match program {
+            "python" | "pypy" => Language::Python,
+            "sh" | "bash" | "zsh" | "dash" | "ksh" => Language::Shell,
+            "node" | "nodejs" => Language::JavaScript,
+            "deno" | "ts-node" | "tsx" | "bun" => Language::TypeScript,
+            _ => return None,
+        })
+    }
+
+    /// По расширению, а для файлов без него — по shebang
+    pub fn detect(path: &Path, content: &str) -> Option<Self> {
+        Self::from_path(path).or_else(|| Self::from_shebang(content.lines().next()?))
+    }
+
+    /// Строка заголовка в синтаксисе языка (без перевода строки)
+    pub fn comment(self, text: &str) -> String {
+        match self {
+            Language::Rust | Language::TypeScript | Language::JavaScript => format!("// {}", text),
+            Language::Python | Language::Yaml | Language::Shell => format!("# {}", text),
+            Language::Markdown => format!("<!-- {} -->", text),
+        }
+    }
+
+    fn syntax(self) -> CommentSyntax {
+        match self {
+            Language::Rust => CommentSyntax {
+                line: &["//"],
+                block: Some(("/*", "*/")),
+                shebang: false, // `#!` в Rust — начало атрибута `#![…]`
+            },
+            Language::TypeScript | Language::JavaScript => CommentSyntax {
+                line: &["//"],
+                block: Some(("/*", "*/")),
+                shebang: true,
+            },
+            Language::Python => CommentSyntax {
+                line: &["#"],
+                block: Some(("\"\"\"", "\"\"\"")), // docstring модуля
+                shebang: true,
+            },
+            Language::Shell => CommentSyntax {
+                line: &["#"],
+                block: None,
+                shebang: true,
+            },
+            Language::Yaml => CommentSyntax {
+                line: &["#"],
+                block: None,
+                shebang: false,
+            },
+            Language::Markdown => CommentSyntax {
+                line: &[],
+                block: Some(("<!--", "-->")),
+                shebang: false,
+            },
+        }
+    }
+
+    /// Комментарии во всём файле: байтовые диапазоны вместе с разделителями
+    /// Строковые литералы пропускаются — `//` или `#` внутри строки не начинает комментарий
+    pub fn comment_ranges(self, content: &str) -> Vec<Range<usize>> {
+        let syntax = self.syntax();
+        let bytes = content.as_bytes();
+        let mut ranges = vec![];
+        let mut i = 0;
+        while i < content.len() {
+            if let Some(end) = self.literal_end(content, i) {
+                i = end;
+                continue;
+            }
+            let rest = &content[i..];
+            // В shell и YAML `#` начинает комментарий только в начале слова: `$#`, `a#b` — не комментарии
+            let word_start = !matches!(self, Language::Shell | Language::Yaml)
+                || i == 0
+                || bytes[i - 1].is_ascii_whitespace();
+            if word_start && syntax.line.iter().any(|prefix| rest.starts_with(prefix)) {
+                let end = rest.find('\n').map_or(content.len(), |n| i + n);
+                ranges.push(i..end);
+                i = end;
+                continue;
+            }
+            if let Some((open, close)) = syntax.block.filter(|(open, _)| rest.starts_with(open)) {
+                let end = block_end(content, i, open, close, self == Language::Rust);
+                ranges.push(i..end);
+                i = end;
+                continue;
+            }
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+        ranges
+    }
+
+    /// Конец строкового (символьного) литерала, начинающегося с байта `i`
+    fn literal_end(self, content: &str, i: usize) -> Option<usize> {
+        let bytes = content.as_bytes();
+        let rest = &content[i..];
+        match self {
+            Language::Rust => {
+                let after_ident = i > 0 && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_');
+                if let Some(end) = rust_raw_string_end(content, i).filter(|_| !after_ident) {
+                    return Some(end);
+                }
+                if rest.starts_with('"') {
+                    return Some(quoted_end(bytes, i + 1, b"\"", true));
+                }
+                // 'x' и '\n' — символы; 'a без закрывающей кавычки — время жизни
+                let ch = rest.strip_prefix('\'')?.chars().next()?;
+                if ch == '\\' {
+                    return Some(quoted_end(bytes, i + 1, b"'", true));
+                }
+                let close = i + 1 + ch.len_utf8();
+                (bytes.get(close) == Some(&b'\'')).then_some(close + 1)
+            }
+            Language::TypeScript | Language::JavaScript => ["\"", "'", "`"]
+                .into_iter()
+                .find(|quote| rest.starts_with(quote))
+                .map(|quote| quoted_end(bytes, i + 1, quote.as_bytes(), true)),
+            Language::Python => ["\"\"\"", "'''", "\"", "'"]
+                .into_iter()
+                .find(|quote| rest.starts_with(quote))
+                .map(|quote| quoted_end(bytes, i + quote.len(), quote.as_bytes(), true)),
+            Language::Shell => match bytes[i] {
+                b'\'' => Some(quoted_end(bytes, i + 1, b"'", false)),
+                b'"' => Some(quoted_end(bytes, i + 1, b"\"", true)),
+                _ => None,
+            },
+            // Кавычки YAML открывают скаляр только в начале значения: `don't` — не строка
+            Language::Yaml if i == 0 || matches!(bytes[i - 1], b' ' | b'\t' | b'\n' | b'[' | b'{' | b',') => {
+                match bytes[i] {
+                    b'\'' => Some(quoted_end(bytes, i + 1, b"'", false)),
+                    b'"' => Some(quoted_end(bytes, i + 1, b"\"", true)),
+                    _ => None,
+                }
+            }
+            Language::Yaml | Language::Markdown => None,
+        }
+    }
+}
+
+/// Конец строки в кавычках, начиная с байта `from`; незакрытая строка — до конца файла
+fn quoted_end(bytes: &[u8], from: usize, close: &[u8], escapes: bool) -> usize {
+    let mut j = from;
+    while j < bytes.len() {
+        if escapes && bytes[j] == b'\\' {
+            j += 2;
+            continue;
+        }
+        if bytes[j..].starts_with(close) {
+            return j + close.len();
+        }
+        j += 1;
+    }
+    bytes.len()
+}
+
+/// Сырая строка Rust: `r"…"`, `r#"…"#`, `br"…"`
+fn rust_raw_string_end(content: &str, i: usize) -> Option<usize> {
+    let rest = &content[i..];
+    let after_prefix = rest.strip_prefix("br").or_else(|| rest.strip_prefix('r'))?;
+    let hashes = after_prefix.bytes().take_while(|&b| b == b'#').count();
+    if after_prefix.as_bytes().get(hashes) != Some(&b'"') {
+        return None;
+    }
+    let body = i + (rest.len() - after_prefix.len()) + hashes + 1;
+    let close = format!("\"{}", "#".repeat(hashes));
+    Some(content[body..].find(&close).map_or(content.len(), |n| body + n + close.len()))
+}
+
+/// Конец блочного комментария; в Rust блочные комментарии вкладываются
+fn block_end(content: &str, start: usize, open: &str, close: &str, nested: bool) -> usize {
+    let mut depth = 0;
+    let mut j = start;
+    while j < content.len() {
+        let rest = &content[j..];
+        if rest.starts_with(open) && (nested || depth == 0) {
+            depth += 1;
+            j += open.len();
+        } else if rest.starts_with(close) {
+            depth -= 1;
+            j += close.len();
+            if depth == 0 {
+                return j;
+            }
+        } else {
+            j += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    content.len()
+}
+
+/// Строка заголовка для файла на языке `language`; неизвестный язык — как Rust
+pub fn header_line(language: Option<Language>, text: &str) -> String {
+    format!("{}\n", language.unwrap_or(Language::Rust).comment(text))
+}
+
+/// Значение аннотации и его положение в файле
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HeaderTag {
+    pub value: String,
+    pub span: SourceSpan,
+}
+
+/// Заголовок файла: начальные комментарии после shebang и front matter
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct FileHeader {
+    pub language: Option<Language>,
+    pub shebang: Option<String>,
+    pub front_matter: bool,
+    pub spdx: Option<HeaderTag>,
+    pub profile: Option<HeaderTag>,
+    pub phase: Option<HeaderTag>,
+    pub insert_at: usize, // байт, с которого вставляются недостающие строки заголовка
+}
+
+impl FileHeader {
+    /// Разбор заголовка; первое вхождение каждой аннотации
+    /// Для неизвестного языка аннотации ищутся в любой строке файла
+    pub fn parse(language: Option<Language>, content: &str) -> Self {
+        let mut scanner = Scanner {
+            index: LineIndex::new(content),
+            header: FileHeader {
+                language,
+                ..FileHeader::default()
+            },
+        };
+        match language {
+            Some(language) => scanner.scan_header(language, content),
+            None => {
+                for (offset, line) in lines(content) {
+                    scanner.visit_comment(offset, line);
+                }
+            }
+        }
+        scanner.header
+    }
+
+    pub fn of_file(path: &Path, content: &str) -> Self {
+        Self::parse(Language::detect(path, content), content)
+    }
+
+    /// Лицензия из заголовка или `NOASSERTION`
+    pub fn license(&self) -> &str {
+        self.spdx.as_ref().map_or("NOASSERTION", |t| t.value.as_str())
+    }
+
+    /// Строка заголовка в синтаксисе файла (с переводом строки)
+    pub fn line(&self, text: &str) -> String {
+        header_line(self.language, text)
+    }
+}
+
+/// Аннотация для `rewrite_header`
+#[derive(Clone, Copy, Debug)]
+pub struct TagValue<'a> {
+    pub tag: &'static str, // SPDX_TAG, PROFILE_TAG или PHASE_TAG
+    pub value: &'a str,
+    pub replace: bool, // заменить существующее значение; иначе — только вставить недостающую строку
+}
+
+/// Файл с аннотациями `values`: существующие значения заменяются на месте,
+/// недостающие строки вставляются в `insert_at` в порядке `values`
+/// Идемпотентно: `None` — менять нечего или язык файла не определён
+pub fn rewrite_header(path: &Path, content: &str, values: &[TagValue]) -> Option<String> {
+    let header = FileHeader::of_file(path, content);
+    header.language?;
+    let newline = if content.contains("\r\n") { "\r\n" } else { "\n" };
+
+    let mut edits: Vec<(usize, usize, &str)> = vec![];
+    let mut inserted = String::new();
+    for value in values {
+        let existing = match value.tag {
+            SPDX_TAG => &header.spdx,
+            PROFILE_TAG => &header.profile,
+            PHASE_TAG => &header.phase,
+            _ => continue,
+        };
+        match existing {
+            Some(tag) if value.replace && tag.value != value.value => {
+                edits.push((tag.span.start_byte, tag.span.end_byte, value.value));
+            }
+            Some(_) => {}
+            None => {
+                let line = header.line(&format!("{} {}", value.tag, value.value));
+                inserted.push_str(line.trim_end_matches('\n'));
+                inserted.push_str(newline);
+            }
+        }
+    }
+    if !inserted.is_empty() {
+        edits.push((header.insert_at, header.insert_at, &inserted));
+    }
+    if edits.is_empty() {
+        return None;
+    }
+
+    // С конца файла: смещения ещё не применённых правок остаются верными
+    // (значения front matter Markdown лежат выше `insert_at`)
+    edits.sort_by_key(|&(start, _, _)| std::cmp::Reverse(start));
+    let mut result = content.to_string();
+    for (start, end, text) in edits {
+        result.replace_range(start..end, text);
+    }
+    Some(result)
+}
+
+struct Scanner<'a> {
+    index: LineIndex<'a>,
+    header: FileHeader,
+}
+
+impl Scanner<'_> {
+    fn scan_header(&mut self, language: Language, content: &str) {
+        let syntax = language.syntax();
+        let mut lines = lines(content).peekable();
+
+        if let Some(&(_, first)) = lines.peek() {
+            if syntax.shebang && first.starts_with("#!") {
+                self.header.shebang = Some(first.to_string());
+                lines.next();
+                self.header.insert_at = lines.peek().map_or(content.len(), |&(offset, _)| offset);
+            }
+        }
+
+        // Markdown: front matter `---` … `---`; YAML: начало документа `---`
+        if let Some(&(_, first)) = lines.peek() {
+            if first.trim() == "---" {
+                lines.next();
+                if language == Language::Markdown {
+                    self.header.front_matter = true;
+                    for (offset, line) in lines.by_ref() {
+                        let trimmed = line.trim();
+                        if trimmed == "---" || trimmed == "..." {
+                            break;
+                        }
+                        match line.trim_start().strip_prefix('#') {
+                            Some(comment) => self.visit_comment(offset + line.len() - comment.len(), comment),
+                            None => self.visit_front_matter(offset, line),
+                        }
+                    }
+                    self.header.insert_at = lines.peek().map_or(content.len(), |&(offset, _)| offset);
+                }
+            }
+        }
+
+        let mut block_close: Option<&str> = None;
+        for (offset, line) in lines {
+            if let Some(close) = block_close {
+                match line.find(close) {
+                    Some(end) => {
+                        self.visit_comment(offset, &line[..end]);
+                        block_close = None;
+                    }
+                    None => self.visit_comment(offset, line),
+                }
+                continue;
+            }
+
+            let rest = line.trim_start();
+            if rest.is_empty() {
+                continue;
+            }
+            let start = offset + (line.len() - rest.len());
+
+            if let Some(prefix) = syntax.line.iter().find(|p| rest.starts_with(*p)) {
+                self.visit_comment(start + prefix.len(), &rest[prefix.len()..]);
+                continue;
+            }
+            if let Some((open, close)) = syntax.block.filter(|(open, _)| rest.starts_with(open)) {
+                let body = &rest[open.len()..];
+                match body.find(close) {
+                    Some(end) => self.visit_comment(start + open.len(), &body[..end]),
+                    None => {
+                        self.visit_comment(start + open.len(), body);
+                        block_close = Some(close);
+                    }
+                }
+                continue;
+            }
+            break; // первая строка кода — конец заголовка
+        }
+    }
+
+    /// Текст комментария, начинающийся с байта `offset`
+    fn visit_comment(&mut self, offset: usize, text: &str) {
+        // ONTO-PROFILE в onto-allow атрибутирует подавление, а не файл
+        if text.contains(SUPPRESSION_MARKER) {
+            return;
+        }
+        for (tag, slot) in [
+            (SPDX_TAG, &mut self.header.spdx),
+            (PROFILE_TAG, &mut self.header.profile),
+            (PHASE_TAG, &mut self.header.phase),
+        ] {
+            if slot.is_some() {
+                continue;
+            }
+            if let Some(pos) = text.find(tag) {
+                let value_start = pos + tag.len();
+                *slot = Some(tag_value(&self.index, offset + value_start, &text[value_start..]));
+            }
+        }
+    }
+
+    /// Ключ front matter: `ONTO-PROFILE: …`, `onto_profile: …`, `spdx-license-identifier: …`
+    fn visit_front_matter(&mut self, offset: usize, line: &str) {
+        let Some((raw_key, value)) = line.split_once(':') else {
+            return;
+        };
+        let key = raw_key.trim().replace('_', "-");
+        let value_offset = offset + raw_key.len() + 1;
+        for (tag, slot) in [
+            (SPDX_TAG, &mut self.header.spdx),
+            (PROFILE_TAG, &mut self.header.profile),
+            (PHASE_TAG, &mut self.header.phase),
+        ] {
+            if slot.is_none() && tag.trim_end_matches(':').eq_ignore_ascii_case(&key) {
+                *slot = Some(tag_value(&self.index, value_offset, value));
+            }
+        }
+    }
+}
+
+/// Значение после тега: без пробелов, кавычек и закрывающих разделителей комментария
+fn tag_value(index: &LineIndex, offset: usize, raw: &str) -> HeaderTag {
+    let mut value = raw.trim_end();
+    for close in ["*/", "-->", "\"\"\""] {
+        value = value.trim_end_matches(close).trim_end();
+    }
+    let trimmed = value.trim_start();
+    let mut start = offset + (value.len() - trimmed.len());
+    let mut value = trimmed;
+    if value.len() >= 2 && (value.starts_with('"') && value.ends_with('"') || value.starts_with('\'') && value.ends_with('\'')) {
+        value = &value[1..value.len() - 1];
+        start += 1;
+    }
+    HeaderTag {
+        value: value.to_string(),
+        span: index.span_of_bytes(start, start + value.len()),
+    }
+}
+
+/// Строки с байтовым смещением начала, без `\r\n`
+fn lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut offset = 0;
+    content.split('\n').map(move |line| {
+        let start = offset;
+        offset += line.len() + 1;
+        (start, line.strip_suffix('\r').unwrap_or(line))
+    })
+}
+```
@@ -5,75 +5,329 @@
 use std::collections::HashMap;
 use std::path::Path;
 
+use serde::{Deserialize, Serialize};
+
+use crate::ontocoder::header::FileHeader;
+use crate::ontocoder::spdx::{self, LicenseExpr, SimpleLicense};
+
+/// Подмножество SPDX License List (см. заголовок файла — список неполный)
+const SPDX_LICENSES: &str = include_str!("policy/spdx_licenses.yaml");
+/// Категории лицензий → совместимость с SGCL
+const SGCL_COMPATIBILITY: &str = include_str!("policy/sgcl_compatibility.v1.yaml");
+
 #[derive(Debug, Clone)]
 pub struct LicenseEntry {
     pub spdx_id: String,
+    pub name: String,
+    pub category: String,
     pub compatible_with_sgcl: bool,
     pub requires_cla: bool,
+    pub reason: String,
+    pub replaced_by: Option<String>, // устаревший id
+}
+
+/// Вердикт по выражению: совместимость, нужен ли CLA и почему
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LicenseVerdict {
+    pub expression: String,
+    pub compatible: bool,
+    pub requires_cla: bool,
+    pub licenses: Vec<String>, // лицензии, по которым принимается код (выбранная ветвь OR)
+    pub explanation: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct LicenseList {
+    list_version: String,
+    licenses: Vec<LicenseRecord>,
+    exceptions: Vec<ExceptionRecord>,
+}
+
+#[derive(Deserialize)]
+struct LicenseRecord {
+    id: String,
+    name: String,
+    category: String,
+    #[serde(default)]
+    replaced_by: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ExceptionRecord {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct CompatibilityTable {
+    categories: HashMap<String, Compatibility>,
+    #[serde(default)]
+    licenses: HashMap<String, Compatibility>,
+}
+
+#[derive(Deserialize, Clone)]
+struct Compatibility {
+    compatible: bool,
+    requires_cla: bool,
+    reason: String,
 }
 
 pub struct LicenseRegistry {
-    licenses: HashMap<String, LicenseEntry>,
+    licenses: HashMap<String, LicenseEntry>, // id в нижнем регистре → запись
+    exceptions: HashMap<String, String>,     // id в нижнем регистре → канонический id
+    list_version: String,
 }
 
 impl LicenseRegistry {
+    /// Реестр со встроенными таблицами
+    /// Таблицы проверяются тестом `builtin_license_tables_are_valid` (tests/embedded_data.rs):
+    /// ошибка в них — ошибка сборки, а не входных данных
+    #[allow(clippy::expect_used)]
     pub fn new() -> Self {
+        Self::builtin().expect("embedded SPDX list and SGCL table must be valid")
+    }
+
+    /// Разбор встроенного подмножества SPDX License List и таблицы совместимости SGCL
+    pub fn builtin() -> Result<Self, serde_yaml::Error> {
+        let list: LicenseList = serde_yaml::from_str(SPDX_LICENSES)?;
+        let table: CompatibilityTable = serde_yaml::from_str(SGCL_COMPATIBILITY)?;
+
         let mut reg = LicenseRegistry {
             licenses: HashMap::new(),
+            exceptions: list
+                .exceptions
+                .into_iter()
+                .map(|e| (e.id.to_lowercase(), e.id))
+                .collect(),
+            list_version: list.list_version,
         };
 
-        // GPLv3 — ядро ontoCMS
-        reg.register("GPL-3.0-only", true, true);
-        reg.register("GPL-3.0-or-later", true, true);
-
-        // Совместимые лицензии (только с CLA)
-        reg.register("MIT", true, true);
-        reg.register("Apache-2.0", true, true);
-
-        // Несовместимые с SGCL (этические ограничения)
-        reg.register("BSL-1.1", false, false); // не open-core
-        reg.register("SSPL", false, false);    // нарушает AENGA
+        for record in list.licenses {
+            // Категория без строки в таблице — несовместима, пока её не оценят
+            let compat = table.categories.get(&record.category).cloned().unwrap_or(Compatibility {
+                compatible: false,
+                requires_cla: false,
+                reason: format!("category '{}' has no SGCL assessment", record.category),
+            });
+            reg.register(record.id, record.name, record.category, compat, record.replaced_by);
+        }
 
-        // SGRL-α — внутренняя
-        reg.register("SGRL-α", true, false);
+        // Собственные лицензии (SGRL-α, LicenseRef-*)
+        for (id, compat) in table.licenses {
+            reg.register(id.clone(), id, "custom".into(), compat, None);
+        }
 
-        reg
+        Ok(reg)
     }
 
-    fn register(&mut self, spdx_id: &str, sgcl_compat: bool, cla_needed: bool) {
+    fn register(&mut self, spdx_id: String, name: String, category: String, compat: Compatibility, replaced_by: Option<String>) {
         self.licenses.insert(
-            spdx_id.to_string(),
+            spdx_id.to_lowercase(),
             LicenseEntry {
-                spdx_id: spdx_id.to_string(),
-                compatible_with_sgcl: sgcl_compat,
-                requires_cla: cla_needed,
+                spdx_id,
+                name,
+                category,
+                compatible_with_sgcl: compat.compatible,
+                requires_cla: compat.requires_cla,
+                reason: compat.reason,
+                replaced_by,
             },
         );
     }
 
+    /// Версия SPDX License List, с которой сверено встроенное подмножество
+    pub fn list_version(&self) -> &str {
+        &self.list_version
+    }
+
+    /// Запись по идентификатору (без учёта регистра)
+    pub fn entry(&self, spdx_id: &str) -> Option<&LicenseEntry> {
+        self.licenses.get(&spdx_id.to_lowercase())
+    }
+
+    pub fn is_known_exception(&self, exception_id: &str) -> bool {
+        self.exceptions.contains_key(&exception_id.to_lowercase())
+    }
+
     pub fn detect_license_from_file(&self, path: &Path) -> String {
-        // Простой парсер: ищет SPDX-License-Identifier
-        if let Ok(content) = std::fs::read_to_string(path) {
-            for line in content.lines() {
-                if let Some(pos) = line.find("SPDX-License-Identifier:") {
-                    let id = line[pos + 24..].trim();
-                    return id.to_string();
+        match std::fs::read_to_string(path) {
+            Ok(content) => self.detect_license_in(path, &content),
+            Err(_) => "NOASSERTION".to_string(),
+        }
+    }
+
+    /// Лицензия из заголовка файла с учётом синтаксиса комментариев его языка
+    pub fn detect_license_in(&self, path: &Path, content: &str) -> String {
+        FileHeader::of_file(path, content).license().to_string()
+    }
+
+    /// Лицензия по содержимому без известного языка: SPDX-тег в любой строке
+    pub fn detect_license(&self, content: &str) -> String {
+        FileHeader::parse(None, content).license().to_string()
+    }
+
+    /// Оценка выражения SPDX по таблице совместимости SGCL
+    pub fn evaluate(&self, expression: &str) -> LicenseVerdict {
+        let trimmed = expression.trim();
+        if trimmed == "NOASSERTION" || trimmed == "NONE" || trimmed.is_empty() {
+            return LicenseVerdict {
+                expression: trimmed.to_string(),
+                compatible: false,
+                requires_cla: false,
+                licenses: vec![],
+                explanation: vec!["no license declared".into()],
+            };
+        }
+        match spdx::parse(trimmed) {
+            Ok(expr) => self.evaluate_expr(&expr),
+            Err(e) => LicenseVerdict {
+                expression: trimmed.to_string(),
+                compatible: false,
+                requires_cla: false,
+                licenses: vec![],
+                explanation: vec![format!("invalid SPDX expression: {}", e)],
+            },
+        }
+    }
+
+    pub fn evaluate_expr(&self, expr: &LicenseExpr) -> LicenseVerdict {
+        let outcome = self.outcome(expr);
+        LicenseVerdict {
+            expression: expr.to_string(),
+            compatible: outcome.compatible,
+            requires_cla: outcome.requires_cla,
+            licenses: outcome.licenses,
+            explanation: outcome.notes,
+        }
+    }
+
+    pub fn is_compatible_with_sgcl(&self, expression: &str) -> bool {
+        self.evaluate(expression).compatible
+    }
+
+    pub fn requires_cla(&self, expression: &str) -> bool {
+        self.evaluate(expression).requires_cla
+    }
+
+    fn outcome(&self, expr: &LicenseExpr) -> Outcome {
+        match expr {
+            LicenseExpr::License(license) => self.license_outcome(license),
+            LicenseExpr::With(license, exception) => {
+                let mut outcome = self.license_outcome(license);
+                match self.exceptions.get(&exception.to_lowercase()) {
+                    Some(id) => outcome
+                        .notes
+                        .push(format!("{} only adds permissions to {}", id, license)),
+                    None => {
+                        outcome.compatible = false;
+                        outcome.notes.push(format!(
+                            "{}: exception is not in the embedded SPDX list ({}, partial) — cannot verify its terms",
+                            exception, self.list_version
+                        ));
+                    }
+                }
+                outcome
+            }
+            LicenseExpr::And(a, b) => {
+                let (a, b) = (self.outcome(a), self.outcome(b));
+                let mut notes = a.notes;
+                notes.extend(b.notes);
+                if !(a.compatible && b.compatible) {
+                    notes.push("AND requires every license to be compatible".into());
+                }
+                Outcome {
+                    compatible: a.compatible && b.compatible,
+                    requires_cla: a.requires_cla || b.requires_cla,
+                    licenses: a.licenses.into_iter().chain(b.licenses).collect(),
+                    notes,
+                }
+            }
+            LicenseExpr::Or(a, b) => {
+                let (a, b) = (self.outcome(a), self.outcome(b));
+                // Совместимая ветвь; из двух совместимых — та, что не требует CLA
+                let rank = |o: &Outcome| (o.compatible, !o.requires_cla);
+                if !a.compatible && !b.compatible {
+                    let mut notes = a.notes;
+                    notes.extend(b.notes);
+                    notes.push("no OR alternative is compatible".into());
+                    return Outcome {
+                        notes,
+                        licenses: a.licenses.into_iter().chain(b.licenses).collect(),
+                        ..a
+                    };
                 }
+                let (mut chosen, other) = if rank(&b) > rank(&a) { (b, a) } else { (a, b) };
+                chosen.notes.push(format!(
+                    "OR: licensed under {} (alternative {})",
+                    chosen.licenses.join(" AND "),
+                    other.licenses.join(" AND ")
+                ));
+                chosen
+            }
+        }
+    }
+
+    fn license_outcome(&self, license: &SimpleLicense) -> Outcome {
+        let Some((entry, mut notes)) = self.resolve(license) else {
+            return Outcome {
+                compatible: false,
+                requires_cla: false,
+                licenses: vec![license.to_string()],
+                notes: vec![format!(
+                    "{}: not in the embedded SPDX list ({}, partial) — cannot verify its terms",
+                    license, self.list_version
+                )],
+            };
+        };
+        notes.push(format!(
+            "{}: {}{}",
+            entry.spdx_id,
+            entry.reason,
+            match (entry.compatible_with_sgcl, entry.requires_cla) {
+                (true, true) => " (CLA required)",
+                (true, false) => "",
+                (false, _) => " — incompatible with SGCL",
             }
+        ));
+        Outcome {
+            compatible: entry.compatible_with_sgcl,
+            requires_cla: entry.requires_cla,
+            licenses: vec![entry.spdx_id.clone()],
+            notes,
         }
-        "NOASSERTION".to_string()
     }
 
-    pub fn is_compatible_with_sgcl(&self, spdx_id: &str) -> bool {
-        self.licenses
-            .get(spdx_id)
-            .map_or(false, |e| e.compatible_with_sgcl)
+    /// Запись с учётом устаревших id и суффикса `+` (`GPL-2.0+` → `GPL-2.0-or-later`)
+    fn resolve(&self, license: &SimpleLicense) -> Option<(&LicenseEntry, Vec<String>)> {
+        let mut entry = self.entry(&license.id)?;
+        let deprecated = entry.replaced_by.is_some();
+        if let Some(replacement) = entry.replaced_by.as_deref().and_then(|id| self.entry(id)) {
+            entry = replacement;
+        }
+        if license.or_later {
+            let base = entry.spdx_id.strip_suffix("-only").unwrap_or(&entry.spdx_id);
+            if let Some(later) = self.entry(&format!("{}-or-later", base)) {
+                entry = later;
+            }
+        }
+        let notes = if deprecated {
+            vec![format!("{} is deprecated, read as {}", license, entry.spdx_id)]
+        } else {
+            vec![]
+        };
+        Some((entry, notes))
     }
+}
 
-    pub fn requires_cla(&self, spdx_id: &str) -> bool {
-        self.licenses
-            .get(spdx_id)
-            .map_or(false, |e| e.requires_cla)
+impl Default for LicenseRegistry {
+    fn default() -> Self {
+        Self::new()
     }
 }
+
+struct Outcome {
+    compatible: bool,
+    requires_cla: bool,
+    licenses: Vec<String>,
+    notes: Vec<String>,
+}
 ```
\ No newline at end of file
@@ -2,18 +2,132 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // OntoCoder — LSP Server for Editors (VS Code, Vim, etc.)
 
-use async_trait::async_trait;
-use lsp_server::{Connection, Message, Request, Response};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+    Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{
+    CodeActionRequest, Completion, GotoDefinition, HoverRequest, Request as _, WorkspaceSymbolRequest,
+};
 use lsp_types::*;
-use serde_json::Value;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
-use crate::ontocoder::validator::{OntoValidator, ValidationError};
+use crate::ontocoder::validator::OntoValidator;
+use crate::ontocoder::header::{FileHeader, HeaderTag, PHASE_TAG, PROFILE_TAG, SPDX_TAG};
+use crate::ontocoder::report::{self, Finding, Fix, LineIndex, Severity};
 use crate::ontocoder::license_registry::LicenseRegistry;
-use crate::core::activity_ledger::ProfileId;
+use crate::ontocoder::config::ProjectConfig;
+use crate::core::activity_ledger::{ActivityLedger, OntoEvent, ProfileId};
+use crate::core::key_registry::KeyRegistry;
+use crate::core::local_mirror::LocalMirror;
+use crate::ethics::law3_harm_witness::HarmWitness;
+use crate::forms::invariant_registry::{InvariantRegistry, Onto144Profile};
+
+/// Фазы онтогенеза и их смысл (подсказки при наведении и автодополнении)
+const PHASES: [(&str, &str); 4] = [
+    ("Fast", "reactive mode"),
+    ("Slow", "reflective mode (NoemaSlow)"),
+    ("Heyday", "generative synthesis"),
+    ("Decline", "deconstruction"),
+];
+
+/// Вид code action, вставляющего все недостающие строки заголовка
+pub const INSERT_HEADERS_KIND: &str = "source.ontocms.insertHeaders";
+
+/// Сколько событий и свидетельств отдаёт `ontocms/ledger` без явного `limit`
+const DEFAULT_LEDGER_LIMIT: usize = 50;
+
+/// `ontocms/filesByProfile`: файлы рабочей области, сгруппированные по ONTO-PROFILE
+pub enum FilesByProfile {}
+
+impl lsp_types::request::Request for FilesByProfile {
+    type Params = FilesByProfileParams;
+    type Result = Vec<ProfileFiles>;
+    const METHOD: &'static str = "ontocms/filesByProfile";
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct FilesByProfileParams {
+    #[serde(default)]
+    pub profile: Option<String>, // только файлы этого профиля
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ProfileFiles {
+    pub profile: Option<String>, // `None` — файлы без ONTO-PROFILE
+    pub files: Vec<ProfileFile>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ProfileFile {
+    pub uri: Url,
+    pub path: String, // относительно корня рабочей области
+    pub phase: Option<String>,
+}
+
+/// `ontocms/ledger`: последние события локального ActivityLedger и свидетельства о вреде
+pub enum LedgerView {}
+
+impl lsp_types::request::Request for LedgerView {
+    type Params = LedgerParams;
+    type Result = LedgerSnapshot;
+    const METHOD: &'static str = "ontocms/ledger";
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct LedgerParams {
+    #[serde(default)]
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub profile: Option<String>, // события профиля и свидетельства с его участием
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerSnapshot {
+    pub total_events: usize, // всего событий (с учётом фильтра по профилю)
+    pub events: Vec<OntoEvent>,   // новые первыми
+    pub witnesses: Vec<HarmWitness>, // новые первыми
+}
+
+/// Открытый в редакторе документ (полная синхронизация)
+struct Document {
+    text: String,
+    version: i32,
+}
+
+/// Файл рабочей области: аннотации заголовка и находки
+struct IndexedFile {
+    path: String, // относительно корня рабочей области, через `/`
+    profile: Option<(String, Range)>,
+    phase: Option<(String, Range)>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// `initializationOptions` клиента
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct InitOptions {
+    profile: Option<String>, // профиль автора для вставляемого ONTO-PROFILE
+    ledger_dir: Option<PathBuf>, // каталог с `local_mirror/`; по умолчанию — корень рабочей области
+}
 
 pub struct OntoLspServer {
     validator: OntoValidator,
     capabilities: ServerCapabilities,
+    documents: HashMap<Url, Document>,
+    author_profile: Option<String>,
+    invariants: InvariantRegistry, // черты профилей и их YAML для hover, completion, definition
+    workspace: Option<ProjectConfig>,
+    index: BTreeMap<Url, IndexedFile>,
+    ledger: ActivityLedger,
+    ledger_dir: Option<PathBuf>, // зеркало перечитывается при каждом `ontocms/ledger`
+    keys: KeyRegistry,           // ключи свидетелей: только их свидетельства показываются как свидетельства
 }
 
 impl OntoLspServer {
@@ -27,22 +141,98 @@ impl OntoLspServer {
                     TextDocumentSyncKind::FULL,
                 )),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
-                diagnostics_provider: Some(DiagnosticsCapability::Simple(true)),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec![":".into(), " ".into()]),
+                    ..Default::default()
+                }),
+                definition_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+                    code_action_kinds: Some(vec![CodeActionKind::QUICKFIX, CodeActionKind::new(INSERT_HEADERS_KIND)]),
+                    ..Default::default()
+                })),
                 ..Default::default()
             },
+            documents: HashMap::new(),
+            author_profile: None,
+            invariants: InvariantRegistry::new(),
+            workspace: None,
+            index: BTreeMap::new(),
+            ledger: ActivityLedger::new(),
+            ledger_dir: None,
+            keys: KeyRegistry::new(),
         }
     }
 
-    pub async fn run(&self) -> anyhow::Result<()> {
+    /// Профили onto-144 для подсказок; без реестра hover и completion по профилям пусты
+    pub fn with_invariant_registry(mut self, registry: InvariantRegistry) -> Self {
+        self.invariants = registry;
+        self
+    }
+
+    /// Ключи профилей-свидетелей для журнала из локального зеркала
+    pub fn with_keys(mut self, keys: KeyRegistry) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Журнал для `ontocms/ledger` вместо локального зеркала
+    pub fn with_ledger(mut self, ledger: ActivityLedger) -> Self {
+        self.ledger = ledger;
+        self
+    }
+
+    /// Сервер на stdin/stdout (редакторы)
+    pub async fn run(&mut self) -> anyhow::Result<()> {
         let (connection, io_threads) = Connection::stdio();
-        let server_capabilities = serde_json::to_value(&self.capabilities).unwrap();
-        let initialization_params = connection.initialize(server_capabilities)?;
-        self.main_loop(&connection, initialization_params)?;
+        self.run_connection(connection)?;
         io_threads.join()?;
         Ok(())
     }
 
-    fn main_loop(&self, connection: &Connection, _: serde_json::Value) -> anyhow::Result<()> {
+    /// Сессия поверх любого соединения: `initialize`, затем запросы до `shutdown`/`exit`
+    /// С `Connection::memory()` сервер работает в тестах без редактора
+    pub fn run_connection(&mut self, connection: Connection) -> anyhow::Result<()> {
+        let server_capabilities = serde_json::to_value(&self.capabilities)?;
+        let initialization_params = connection.initialize(server_capabilities)?;
+        self.main_loop(&connection, initialization_params)
+    }
+
+    fn main_loop(&mut self, connection: &Connection, params: serde_json::Value) -> anyhow::Result<()> {
+        let params: InitializeParams = serde_json::from_value(params)?;
+        let options: InitOptions = params
+            .initialization_options
+            .and_then(|o| serde_json::from_value(o).ok())
+            .unwrap_or_default();
+        self.author_profile = options.profile;
+
+        let root = params
+            .workspace_folders
+            .as_ref()
+            .and_then(|folders| folders.first())
+            .map(|folder| &folder.uri)
+            .or(params.root_uri.as_ref())
+            .and_then(|uri| uri.to_file_path().ok());
+        self.ledger_dir = options
+            .ledger_dir
+            .or_else(|| root.clone().filter(|r| r.join("local_mirror").is_dir()));
+        if let Some(root) = root {
+            self.index_workspace(&root);
+            // Находки по всей рабочей области, а не только по открытым файлам
+            for (uri, file) in &self.index {
+                if !file.diagnostics.is_empty() {
+                    send_notification::<PublishDiagnostics>(
+                        connection,
+                        PublishDiagnosticsParams {
+                            uri: uri.clone(),
+                            diagnostics: file.diagnostics.clone(),
+                            version: None,
+                        },
+                    )?;
+                }
+            }
+        }
+
         loop {
             match connection.receiver.recv()? {
                 Message::Request(req) => {
@@ -51,72 +241,597 @@ impl OntoLspServer {
                     }
                     self.on_request(connection, req)?;
                 }
-                Message::Notification(_) => {
-                    // Игнорируем нотификации (например, didOpen, didChange — обрабатываем через didSave)
-                }
+                Message::Notification(not) => self.on_notification(connection, not)?,
                 Message::Response(_) => {}
             }
         }
     }
 
-    fn on_request(&self, connection: &Connection, req: Request) -> anyhow::Result<()> {
-        match req.method.as_str() {
-            "textDocument/hover" => {
-                // Показываем онтологический профиль при наведении
-                let _params: HoverParams = serde_json::from_value(req.params)?;
-                let result = Hover {
-                    contents: HoverContents::Scalar(MarkedString::String(
-                        "OntoCMS: Hover over ONTO-PROFILE to see perceptual/behavioral traits".into(),
-                    )),
-                    range: None,
-                };
-                let resp = Response::new_ok(req.id, result);
-                connection.sender.send(Message::Response(resp))?;
+    /// Синхронизация документов; диагностика публикуется после каждого изменения
+    fn on_notification(&mut self, connection: &Connection, not: Notification) -> anyhow::Result<()> {
+        match not.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(not.params)?;
+                let doc = params.text_document;
+                self.documents.insert(
+                    doc.uri.clone(),
+                    Document {
+                        text: doc.text,
+                        version: doc.version,
+                    },
+                );
+                self.update_index(&doc.uri);
+                self.publish_diagnostics(connection, &doc.uri)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(not.params)?;
+                let uri = params.text_document.uri;
+                // FULL: последнее изменение содержит весь текст
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.documents.insert(
+                        uri.clone(),
+                        Document {
+                            text: change.text,
+                            version: params.text_document.version,
+                        },
+                    );
+                }
+                self.update_index(&uri);
+                self.publish_diagnostics(connection, &uri)?;
+            }
+            DidSaveTextDocument::METHOD => {
+                let params: DidSaveTextDocumentParams = serde_json::from_value(not.params)?;
+                self.publish_diagnostics(connection, &params.text_document.uri)?;
             }
-            _ => {
-                // Diagnostics генерируются при сохранении (внешний hook)
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(not.params)?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                // Файл рабочей области остаётся в индексе с содержимым с диска; прочие — без диагностики
+                self.update_index(&uri);
+                let diagnostics = self.index.get(&uri).map(|f| f.diagnostics.clone()).unwrap_or_default();
+                send_notification::<PublishDiagnostics>(
+                    connection,
+                    PublishDiagnosticsParams {
+                        uri,
+                        diagnostics,
+                        version: None,
+                    },
+                )?;
             }
+            _ => {}
         }
         Ok(())
     }
 
+    fn on_request(&mut self, connection: &Connection, req: Request) -> anyhow::Result<()> {
+        let resp = match req.method.as_str() {
+            HoverRequest::METHOD => {
+                let params: HoverParams = serde_json::from_value(req.params)?;
+                Response::new_ok(req.id, self.hover(&params.text_document_position_params))
+            }
+            Completion::METHOD => {
+                let params: CompletionParams = serde_json::from_value(req.params)?;
+                Response::new_ok(req.id, self.completion(&params.text_document_position))
+            }
+            GotoDefinition::METHOD => {
+                let params: GotoDefinitionParams = serde_json::from_value(req.params)?;
+                Response::new_ok(req.id, self.definition(&params.text_document_position_params))
+            }
+            CodeActionRequest::METHOD => {
+                let params: CodeActionParams = serde_json::from_value(req.params)?;
+                Response::new_ok(req.id, self.code_actions(&params))
+            }
+            WorkspaceSymbolRequest::METHOD => {
+                let params: WorkspaceSymbolParams = serde_json::from_value(req.params)?;
+                Response::new_ok(req.id, WorkspaceSymbolResponse::Nested(self.workspace_symbols(&params.query)))
+            }
+            FilesByProfile::METHOD => {
+                let params: FilesByProfileParams = custom_params(req.params)?;
+                Response::new_ok(req.id, self.files_by_profile(params.profile.as_deref()))
+            }
+            LedgerView::METHOD => {
+                let params: LedgerParams = custom_params(req.params)?;
+                self.reload_ledger();
+                Response::new_ok(req.id, self.ledger_snapshot(&params))
+            }
+            _ => Response::new_err(req.id, ErrorCode::MethodNotFound as i32, format!("unsupported method {}", req.method)),
+        };
+        connection.sender.send(Message::Response(resp))?;
+        Ok(())
+    }
+
+    fn publish_diagnostics(&self, connection: &Connection, uri: &Url) -> anyhow::Result<()> {
+        let Some(doc) = self.documents.get(uri) else { return Ok(()) };
+        send_notification::<PublishDiagnostics>(
+            connection,
+            PublishDiagnosticsParams {
+                uri: uri.clone(),
+                diagnostics: self.diagnostics_for_file(uri.as_str(), &doc.text),
+                version: Some(doc.version),
+            },
+        )
+    }
+
+    /// Индекс всех файлов рабочей области по include/exclude из ontocms.toml
+    /// Нечитаемый ontocms.toml не мешает работе редактора: используется конфигурация по умолчанию
+    fn index_workspace(&mut self, root: &Path) {
+        let config = ProjectConfig::discover(root).unwrap_or_else(|_| ProjectConfig::default_for(root));
+        let walker = walkdir::WalkDir::new(config.root())
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|e| !config.excludes(e.path()));
+        for entry in walker.flatten() {
+            if !entry.file_type().is_file() || !config.includes(entry.path()) {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(entry.path()) else { continue };
+            let Ok(uri) = Url::from_file_path(entry.path()) else { continue };
+            let file = self.index_file(&uri, config.root(), &content);
+            self.index.insert(uri, file);
+        }
+        self.workspace = Some(config);
+    }
+
+    /// Переиндексация файла рабочей области: текст открытого документа или содержимое с диска
+    fn update_index(&mut self, uri: &Url) {
+        let Some(config) = &self.workspace else { return };
+        let Ok(path) = uri.to_file_path() else { return };
+        if !path.starts_with(config.root()) || !config.includes(&path) {
+            return;
+        }
+        let content = match self.documents.get(uri) {
+            Some(doc) => Some(doc.text.clone()),
+            None => std::fs::read_to_string(&path).ok(),
+        };
+        match content {
+            Some(content) => {
+                let file = self.index_file(uri, config.root(), &content);
+                self.index.insert(uri.clone(), file);
+            }
+            None => {
+                self.index.remove(uri);
+            }
+        }
+    }
+
+    fn index_file(&self, uri: &Url, root: &Path, content: &str) -> IndexedFile {
+        let index = LineIndex::new(content);
+        let path = uri_path(uri.as_str());
+        let header = FileHeader::of_file(&path, content);
+        let tag = |tag: Option<HeaderTag>| {
+            tag.map(|t| (t.value, bytes_to_range(t.span.start_byte, t.span.end_byte, &index)))
+        };
+        IndexedFile {
+            path: relative_path(path.strip_prefix(root).unwrap_or(&path)),
+            profile: tag(header.profile),
+            phase: tag(header.phase),
+            diagnostics: self.diagnostics_for_file(uri.as_str(), content),
+        }
+    }
+
+    /// Символы рабочей области: ONTO-PROFILE и ONTO-PHASE каждого файла
+    /// Запрос сравнивается без учёта регистра со значением аннотации и путём файла
+    pub fn workspace_symbols(&self, query: &str) -> Vec<WorkspaceSymbol> {
+        let query = query.to_lowercase();
+        let mut symbols = vec![];
+        for (uri, file) in &self.index {
+            let in_path = file.path.to_lowercase().contains(&query);
+            let tags = [(&file.profile, SymbolKind::CONSTANT), (&file.phase, SymbolKind::ENUM_MEMBER)];
+            for (tag, kind) in tags {
+                let Some((value, range)) = tag else { continue };
+                if !in_path && !value.to_lowercase().contains(&query) {
+                    continue;
+                }
+                symbols.push(WorkspaceSymbol {
+                    name: value.clone(),
+                    kind,
+                    tags: None,
+                    container_name: Some(file.path.clone()),
+                    location: OneOf::Left(Location {
+                        uri: uri.clone(),
+                        range: *range,
+                    }),
+                    data: None,
+                });
+            }
+        }
+        symbols
+    }
+
+    /// Файлы рабочей области по профилям; файлы без ONTO-PROFILE — последней группой
+    pub fn files_by_profile(&self, profile: Option<&str>) -> Vec<ProfileFiles> {
+        let mut groups: BTreeMap<Option<&str>, Vec<ProfileFile>> = BTreeMap::new();
+        for (uri, file) in &self.index {
+            let key = file.profile.as_ref().map(|(value, _)| value.as_str());
+            if profile.is_some() && key != profile {
+                continue;
+            }
+            groups.entry(key).or_default().push(ProfileFile {
+                uri: uri.clone(),
+                path: file.path.clone(),
+                phase: file.phase.as_ref().map(|(value, _)| value.clone()),
+            });
+        }
+        let unprofiled = groups.remove(&None);
+        groups
+            .into_iter()
+            .chain(unprofiled.map(|files| (None, files)))
+            .map(|(profile, files)| ProfileFiles {
+                profile: profile.map(str::to_string),
+                files,
+            })
+            .collect()
+    }
+
+    /// Журнал из локального зеркала (`ledgerDir` или `local_mirror/` в корне рабочей области)
+    fn reload_ledger(&mut self) {
+        let Some(dir) = &self.ledger_dir else { return };
+        // LocalMirror::new создаёт каталог — просмотр журнала не должен ничего создавать
+        if !dir.join("local_mirror").is_dir() {
+            return;
+        }
+        let mirror = LocalMirror::new(&dir.to_string_lossy());
+        let mut ledger = ActivityLedger::new();
+        // В зеркало события попадают только после конвейера (ontocli commit, ingest-git, outbox свидетельств)
+        for event in mirror.load_all() {
+            ledger.append(event, &self.keys);
+        }
+        self.ledger = ledger;
+    }
+
+    /// Последние события и свидетельства о вреде, новые первыми
+    pub fn ledger_snapshot(&self, params: &LedgerParams) -> LedgerSnapshot {
+        let limit = params.limit.unwrap_or(DEFAULT_LEDGER_LIMIT);
+        let profile = params.profile.clone().map(ProfileId);
+        let mut events: Vec<&OntoEvent> = match &profile {
+            Some(profile) => self.ledger.events_by_profile(profile),
+            None => self.ledger.events().collect(),
+        };
+        let total_events = events.len();
+        events.sort_by(|a, b| b.timestamp.cmp(&a.timestamp).then_with(|| a.id.cmp(&b.id)));
+
+        // Свидетельство касается профиля, если он свидетель или автор нарушающего события
+        let mut witnesses: Vec<&HarmWitness> = self
+            .ledger
+            .witnesses()
+            .filter(|w| match &profile {
+                Some(profile) => {
+                    w.witness_profile == *profile
+                        || self.ledger.get(&w.violating_event_id).is_some_and(|e| e.profile_id == *profile)
+                }
+                None => true,
+            })
+            .collect();
+        witnesses.sort_by(|a, b| b.timestamp.cmp(&a.timestamp).then_with(|| a.id.cmp(&b.id)));
+
+        LedgerSnapshot {
+            total_events,
+            events: events.into_iter().take(limit).cloned().collect(),
+            witnesses: witnesses.into_iter().take(limit).cloned().collect(),
+        }
+    }
+
     /// Публичный метод для CLI и CI: генерация diagnostics
+    /// Исправление находки передаётся в `Diagnostic::data` (для code actions)
     pub fn diagnostics_for_file(&self, uri: &str, content: &str) -> Vec<Diagnostic> {
-        let mut diagnostics = vec![];
-        match self.validator.validate_file(&std::path::Path::new(uri), content) {
-            Err(ValidationError::EnergyValueDetected) => {
-                diagnostics.push(Diagnostic::new_simple(
-                    Range::new(Position::new(0, 0), Position::new(0, 10)),
-                    "Energy/value metric detected — forbidden by SGRL-α".into(),
-                ));
-            }
-            Err(ValidationError::LicenseIncompatible(lic)) => {
-                diagnostics.push(Diagnostic::new_simple(
-                    Range::new(Position::new(0, 0), Position::new(0, 10)),
-                    format!("License {} incompatible with SGCL", lic),
-                ));
-            }
-            Err(ValidationError::ProfileNotInRegistry(_)) => {
-                diagnostics.push(Diagnostic::new_simple(
-                    Range::new(Position::new(0, 0), Position::new(0, 10)),
-                    "Invalid or missing ONTO-PROFILE".into(),
-                ));
-            }
-            Err(ValidationError::PhaseAnnotationMissing) => {
-                diagnostics.push(Diagnostic::new_simple(
-                    Range::new(Position::new(0, 0), Position::new(0, 10)),
-                    "Missing ONTO-PHASE annotation".into(),
-                ));
-            }
-            Err(ValidationError::AENGAViolation) => {
-                diagnostics.push(Diagnostic::new_simple(
-                    Range::new(Position::new(0, 0), Position::new(0, 10)),
-                    "AENGA violation: remote control or ethics override detected".into(),
-                ));
-            }
-            Ok(()) => {}
-        }
-        diagnostics
+        let index = LineIndex::new(content);
+        let report = self.validator.validate_file(&uri_path(uri), content);
+        report.findings.iter().map(|f| to_diagnostic(f, &index)).collect()
+    }
+
+    /// Исправления из `Diagnostic::data` и вставка недостающих строк заголовка
+    pub fn code_actions(&self, params: &CodeActionParams) -> Vec<CodeActionOrCommand> {
+        let uri = &params.text_document.uri;
+        let Some(doc) = self.documents.get(uri) else { return vec![] };
+        let index = LineIndex::new(&doc.text);
+        let header = FileHeader::of_file(&uri_path(uri.as_str()), &doc.text);
+        let mut actions = vec![];
+
+        for diagnostic in &params.context.diagnostics {
+            if diagnostic.source.as_deref() != Some("ontocms") {
+                continue;
+            }
+            let code = match &diagnostic.code {
+                Some(NumberOrString::String(code)) => code.as_str(),
+                _ => continue,
+            };
+            // У ONTO-PROFILE нет однозначного значения: вставляем профиль автора или заготовку
+            let fix = if code == report::rules::PROFILE_MISSING {
+                Some(self.profile_fix(&header, &index))
+            } else {
+                diagnostic
+                    .data
+                    .clone()
+                    .and_then(|data| serde_json::from_value::<Fix>(data).ok())
+            };
+            let Some(fix) = fix.filter(|f| !f.edits.is_empty()) else { continue };
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title.clone(),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(workspace_edit(uri, &fix, &index)),
+                is_preferred: Some(true),
+                ..Default::default()
+            }));
+        }
+
+        if let Some(fix) = self.missing_headers_fix(&header, &index) {
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title.clone(),
+                kind: Some(CodeActionKind::new(INSERT_HEADERS_KIND)),
+                edit: Some(workspace_edit(uri, &fix, &index)),
+                ..Default::default()
+            }));
+        }
+        actions
+    }
+
+    /// Черты профиля под курсором (ONTO-PROFILE) или смысл фазы (ONTO-PHASE)
+    pub fn hover(&self, position: &TextDocumentPositionParams) -> Option<Hover> {
+        let (tag, kind, index) = self.tag_at(position)?;
+        let range = Some(bytes_to_range(tag.span.start_byte, tag.span.end_byte, &index));
+        let value = match kind {
+            TagKind::Profile => match self.invariants.get_profile(&tag.value) {
+                Some(profile) => profile_markdown(profile),
+                None => format!("**{}** is not in the onto-144 registry", tag.value),
+            },
+            TagKind::Phase => match PHASES.iter().find(|(name, _)| *name == tag.value) {
+                Some((name, meaning)) => format!("**ONTO-PHASE {}** — {}", name, meaning),
+                None => format!(
+                    "**{}** is not a phase: {}",
+                    tag.value,
+                    PHASES.iter().map(|(n, _)| *n).collect::<Vec<_>>().join(" | ")
+                ),
+            },
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range,
+        })
     }
+
+    /// Идентификаторы onto-144 после `ONTO-PROFILE:` и четыре фазы после `ONTO-PHASE:`
+    pub fn completion(&self, position: &TextDocumentPositionParams) -> Option<CompletionResponse> {
+        let doc = self.documents.get(&position.text_document.uri)?;
+        let index = LineIndex::new(&doc.text);
+        let line = position.position.line as usize + 1;
+        let start = index.offset(line, 0);
+        let cursor = index.offset(line, position.position.character as usize);
+        let before = &doc.text[start..cursor];
+
+        // Ключи front matter (`onto_profile:`) сравниваются без учёта регистра и `_`
+        let normalized = before.to_ascii_uppercase().replace('_', "-");
+        let (tag, kind) = [(PROFILE_TAG, TagKind::Profile), (PHASE_TAG, TagKind::Phase)]
+            .into_iter()
+            .find_map(|(tag, kind)| normalized.rfind(tag).map(|at| (at + tag.len(), kind)))?;
+        // Заменяется уже набранная часть значения
+        let typed = &before[tag..];
+        let word_start = start + tag + (typed.len() - typed.trim_start().len());
+        let replace = bytes_to_range(word_start, cursor, &index);
+
+        let item = |label: &str, detail: String, documentation: Option<String>| CompletionItem {
+            label: label.to_string(),
+            kind: Some(CompletionItemKind::ENUM_MEMBER),
+            detail: Some(detail),
+            documentation: documentation.map(|value| {
+                Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value,
+                })
+            }),
+            text_edit: Some(CompletionTextEdit::Edit(lsp_types::TextEdit {
+                range: replace,
+                new_text: label.to_string(),
+            })),
+            ..Default::default()
+        };
+
+        let items = match kind {
+            TagKind::Profile => {
+                let mut ids = self.invariants.all_ids();
+                ids.sort();
+                ids.iter()
+                    .filter_map(|id| self.invariants.get_profile(id))
+                    .map(|p| item(&p.id, profile_detail(p), Some(profile_markdown(p))))
+                    .collect()
+            }
+            TagKind::Phase => PHASES
+                .iter()
+                .map(|(name, meaning)| item(name, meaning.to_string(), None))
+                .collect(),
+        };
+        Some(CompletionResponse::Array(items))
+    }
+
+    /// YAML профиля под курсором
+    pub fn definition(&self, position: &TextDocumentPositionParams) -> Option<GotoDefinitionResponse> {
+        let (tag, kind, _) = self.tag_at(position)?;
+        if kind != TagKind::Profile {
+            return None;
+        }
+        let path = std::fs::canonicalize(self.invariants.profile_path(&tag.value)?).ok()?;
+        Some(GotoDefinitionResponse::Scalar(Location {
+            uri: Url::from_file_path(path).ok()?,
+            range: Range::default(),
+        }))
+    }
+
+    /// Аннотация заголовка, на которой стоит курсор
+    fn tag_at(&self, position: &TextDocumentPositionParams) -> Option<(HeaderTag, TagKind, LineIndex<'_>)> {
+        let uri = &position.text_document.uri;
+        let doc = self.documents.get(uri)?;
+        let index = LineIndex::new(&doc.text);
+        let offset = index.offset(position.position.line as usize + 1, position.position.character as usize);
+        let header = FileHeader::of_file(&uri_path(uri.as_str()), &doc.text);
+        let covers = |tag: &HeaderTag| tag.span.start_byte <= offset && offset <= tag.span.end_byte;
+        if let Some(tag) = header.profile.filter(covers) {
+            return Some((tag, TagKind::Profile, index));
+        }
+        header.phase.filter(covers).map(|tag| (tag, TagKind::Phase, index))
+    }
+
+    fn profile_line(&self, header: &FileHeader) -> String {
+        let profile = self.author_profile.as_deref().unwrap_or("<Zodiac-Element-Animal>");
+        header.line(&format!("{} {}", PROFILE_TAG, profile))
+    }
+
+    fn profile_fix(&self, header: &FileHeader, index: &LineIndex) -> Fix {
+        let line = self.profile_line(header);
+        Fix {
+            title: format!("Add `{}`", line.trim_end()),
+            edits: vec![report::TextEdit {
+                span: index.span_of_bytes(header.insert_at, header.insert_at),
+                new_text: line,
+            }],
+        }
+    }
+
+    /// Все отсутствующие SPDX-License-Identifier, ONTO-PROFILE и ONTO-PHASE одной правкой
+    fn missing_headers_fix(&self, header: &FileHeader, index: &LineIndex) -> Option<Fix> {
+        let mut tags = vec![];
+        let mut text = String::new();
+        if header.spdx.is_none() {
+            tags.push("SPDX");
+            text.push_str(&header.line(&format!("{} GPL-3.0-only", SPDX_TAG)));
+        }
+        if header.profile.is_none() {
+            tags.push("ONTO-PROFILE");
+            text.push_str(&self.profile_line(header));
+        }
+        if header.phase.is_none() {
+            tags.push("ONTO-PHASE");
+            text.push_str(&header.line(&format!("{} Slow", PHASE_TAG)));
+        }
+        if tags.is_empty() {
+            return None;
+        }
+        Some(Fix {
+            title: format!("Insert missing ontoCMS headers ({})", tags.join(", ")),
+            edits: vec![report::TextEdit {
+                span: index.span_of_bytes(header.insert_at, header.insert_at),
+                new_text: text,
+            }],
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TagKind {
+    Profile,
+    Phase,
+}
+
+fn profile_detail(profile: &Onto144Profile) -> String {
+    format!(
+        "{} {} · {} {}",
+        profile.perceptual_modality.element,
+        profile.perceptual_modality.zodiac_sign,
+        profile.behavioral_element.chinese_element,
+        profile.behavioral_element.chinese_animal
+    )
+}
+
+/// Черты PerceptualModality и BehavioralElement профиля
+fn profile_markdown(profile: &Onto144Profile) -> String {
+    let perceptual = &profile.perceptual_modality;
+    let behavioral = &profile.behavioral_element;
+    format!(
+        "**{}** — onto-144\n\n\
+         **PerceptualModality** — {} ({}, {})\n\n{}\n\nCognitive bias: {}\n\n\
+         **BehavioralElement** — {} {} ({}, {})\n\n{}\n\nDecision style: {}",
+        profile.id,
+        perceptual.zodiac_sign,
+        perceptual.element,
+        perceptual.modality,
+        bullets(&perceptual.traits),
+        perceptual.cognitive_bias,
+        behavioral.chinese_element,
+        behavioral.chinese_animal,
+        behavioral.yin_yang,
+        behavioral.season,
+        bullets(&behavioral.traits),
+        behavioral.decision_style
+    )
+}
+
+fn bullets(items: &[String]) -> String {
+    items.iter().map(|t| format!("- {}", t)).collect::<Vec<_>>().join("\n")
+}
+
+/// Параметры собственных запросов `ontocms/*` необязательны
+fn custom_params<P: DeserializeOwned + Default>(params: serde_json::Value) -> anyhow::Result<P> {
+    if params.is_null() {
+        return Ok(P::default());
+    }
+    Ok(serde_json::from_value(params)?)
+}
+
+fn send_notification<N: lsp_types::notification::Notification>(
+    connection: &Connection,
+    params: N::Params,
+) -> anyhow::Result<()> {
+    let not = Notification::new(N::METHOD.to_string(), params);
+    connection.sender.send(Message::Notification(not))?;
+    Ok(())
+}
+
+/// Путь для определения языка: файловый путь URI или сам URI
+fn uri_path(uri: &str) -> PathBuf {
+    Url::parse(uri)
+        .ok()
+        .and_then(|u| u.to_file_path().ok())
+        .unwrap_or_else(|| PathBuf::from(uri))
+}
+
+fn relative_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn workspace_edit(uri: &Url, fix: &Fix, index: &LineIndex) -> WorkspaceEdit {
+    let edits = fix
+        .edits
+        .iter()
+        .map(|e| lsp_types::TextEdit {
+            range: bytes_to_range(e.span.start_byte, e.span.end_byte, index),
+            new_text: e.new_text.clone(),
+        })
+        .collect();
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(uri.clone(), edits)])),
+        ..Default::default()
+    }
+}
+
+fn to_diagnostic(finding: &Finding, index: &LineIndex) -> Diagnostic {
+    Diagnostic {
+        range: bytes_to_range(finding.span.start_byte, finding.span.end_byte, index),
+        severity: Some(match finding.severity {
+            Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+            Severity::Info => DiagnosticSeverity::INFORMATION,
+            Severity::Hint => DiagnosticSeverity::HINT,
+        }),
+        code: Some(NumberOrString::String(finding.rule_id.clone())),
+        source: Some("ontocms".into()),
+        message: finding.message.clone(),
+        data: finding.fix.as_ref().and_then(|fix| serde_json::to_value(fix).ok()),
+        ..Default::default()
+    }
+}
+
+/// Строки в LSP — с 0, столбцы — в единицах UTF-16
+fn bytes_to_range(start: usize, end: usize, index: &LineIndex) -> Range {
+    let position = |offset| {
+        let (line, column) = index.position(offset);
+        Position::new(line.saturating_sub(1) as u32, column as u32)
+    };
+    Range::new(position(start), position(end))
 }
 ```
\ No newline at end of file
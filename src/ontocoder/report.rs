@@ -0,0 +1,278 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Validation Reports (Rule Ids, Severities, Spans, Fixes)
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ontocoder::suppression::Suppression;
+
+/// Идентификаторы правил OntoValidator
+pub mod rules {
+    pub const FORBIDDEN_TERM: &str = "sgrl.forbidden-term";
+    pub const SYNTAX: &str = "onto.syntax";
+    pub const LICENSE: &str = "onto.license";
+    pub const CLA_MISSING: &str = "sgcl.cla-missing";
+    pub const PROFILE_MISSING: &str = "onto.profile-missing";
+    pub const PROFILE_UNKNOWN: &str = "onto.profile-unknown";
+    pub const PHASE_MISSING: &str = "onto.phase-missing";
+    pub const PHASE_INVALID: &str = "onto.phase-invalid";
+    pub const AENGA: &str = "aenga.remote-control";
+    pub const SUPPRESSION_INVALID: &str = "onto.suppression-invalid";
+    pub const SUPPRESSION_UNUSED: &str = "onto.suppression-unused";
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Hint,
+    Info,
+    Warning,
+    Error,
+}
+
+/// Положение в исходном тексте
+/// Строки с 1, столбцы с 0 (в единицах UTF-16 — как в LSP); байты — смещения от начала файла
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct SourceSpan {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// Замена фрагмента текста
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: SourceSpan,
+    pub new_text: String,
+}
+
+/// Предлагаемое исправление; без правок — только совет
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Fix {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Finding {
+    pub rule_id: String,
+    pub severity: Severity,
+    pub span: SourceSpan,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+/// Находка, подавленная комментарием onto-allow
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SuppressedFinding {
+    pub finding: Finding,
+    pub suppression: Suppression,
+}
+
+/// Все находки OntoValidator по одному файлу
+/// Подавленные находки не влияют на результат, но остаются в отчёте
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ValidationReport {
+    pub path: String,
+    pub findings: Vec<Finding>,
+    #[serde(default)]
+    pub suppressed: Vec<SuppressedFinding>,
+}
+
+impl ValidationReport {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            findings: vec![],
+            suppressed: vec![],
+        }
+    }
+
+    pub fn push(&mut self, finding: Finding) {
+        self.findings.push(finding);
+    }
+
+    /// Файл проходит проверку, если нет находок уровня Error
+    pub fn is_clean(&self) -> bool {
+        self.count(Severity::Error) == 0
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings.iter().filter(|f| f.severity == severity).count()
+    }
+
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.findings.iter().map(|f| f.severity).max()
+    }
+
+    /// Находки в порядке положения в файле
+    pub fn sort(&mut self) {
+        self.findings
+            .sort_by_key(|f| (f.span.start_byte, f.span.end_byte, f.rule_id.clone()));
+        self.suppressed
+            .sort_by_key(|s| (s.finding.span.start_byte, s.finding.rule_id.clone()));
+    }
+
+    /// Формат компилятора: `path:line:col: severity[rule]: message`
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        for f in &self.findings {
+            out.push_str(&format!(
+                "{}:{}:{}: {}[{}]: {}\n",
+                self.path,
+                f.span.start_line,
+                f.span.start_column + 1,
+                f.severity,
+                f.rule_id,
+                f.message
+            ));
+            if let Some(fix) = &f.fix {
+                out.push_str(&format!("    fix: {}\n", fix.title));
+            }
+        }
+        for s in &self.suppressed {
+            out.push_str(&format!(
+                "{}:{}:{}: suppressed[{}] by {}: {}\n",
+                self.path,
+                s.finding.span.start_line,
+                s.finding.span.start_column + 1,
+                s.finding.rule_id,
+                s.suppression.profile.0,
+                s.suppression.reason
+            ));
+        }
+        out
+    }
+
+    /// Аннотации GitHub Actions (`::error file=…,line=…::…`)
+    pub fn render_github(&self) -> String {
+        let mut out = String::new();
+        for f in &self.findings {
+            let command = match f.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info | Severity::Hint => "notice",
+            };
+            out.push_str(&format!(
+                "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}\n",
+                command,
+                escape_property(&self.path),
+                f.span.start_line,
+                f.span.start_column + 1,
+                f.span.end_line,
+                f.span.end_column + 1,
+                escape_property(&f.rule_id),
+                escape_data(&f.message)
+            ));
+        }
+        for s in &self.suppressed {
+            out.push_str(&format!(
+                "::notice file={},line={},title={}::{}\n",
+                escape_property(&self.path),
+                s.finding.span.start_line,
+                escape_property(&format!("{} suppressed", s.finding.rule_id)),
+                escape_data(&format!("{} (by {}: {})", s.finding.message, s.suppression.profile.0, s.suppression.reason))
+            ));
+        }
+        out
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Severity::Hint => "hint",
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Индекс начала строк: перевод между байтами и строкой/столбцом
+pub struct LineIndex<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        Self { text, line_starts }
+    }
+
+    /// Байтовое смещение по строке (с 1) и столбцу (с 0, в символах — как в proc-macro2)
+    pub fn char_offset(&self, line: usize, column: usize) -> usize {
+        let Some(&start) = self.line_starts.get(line.saturating_sub(1)) else {
+            return self.text.len();
+        };
+        let end = self.line_starts.get(line).copied().unwrap_or(self.text.len());
+        self.text[start..end]
+            .char_indices()
+            .nth(column)
+            .map_or(end, |(i, _)| start + i)
+    }
+
+    /// Строка (с 1) и столбец (с 0, в единицах UTF-16) по байтовому смещению
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.text.len());
+        let line = self.line_starts.partition_point(|&s| s <= offset);
+        let start = self.line_starts[line - 1];
+        (line, self.text[start..offset].encode_utf16().count())
+    }
+
+    /// Байтовое смещение по строке (с 1) и столбцу (с 0, в единицах UTF-16)
+    pub fn offset(&self, line: usize, column: usize) -> usize {
+        let Some(&start) = self.line_starts.get(line.saturating_sub(1)) else {
+            return self.text.len();
+        };
+        let end = self.line_starts.get(line).copied().unwrap_or(self.text.len());
+        let mut units = 0;
+        for (i, c) in self.text[start..end].char_indices() {
+            if units >= column || c == '\n' {
+                return start + i;
+            }
+            units += c.len_utf16();
+        }
+        end
+    }
+
+    /// Диапазон по строкам и столбцам в символах (спаны proc-macro2); столбцы пересчитываются в UTF-16
+    pub fn span_of_chars(&self, start_line: usize, start_column: usize, end_line: usize, end_column: usize) -> SourceSpan {
+        self.span_of_bytes(
+            self.char_offset(start_line, start_column),
+            self.char_offset(end_line, end_column),
+        )
+    }
+
+    pub fn span_of_bytes(&self, start_byte: usize, end_byte: usize) -> SourceSpan {
+        let (start_line, start_column) = self.position(start_byte);
+        let (end_line, end_column) = self.position(end_byte);
+        SourceSpan {
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+            start_byte,
+            end_byte,
+        }
+    }
+}
+
+/// Текст сообщения команды GitHub Actions
+fn escape_data(message: &str) -> String {
+    message.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Значение свойства команды (`file=…,title=…`): `:` и `,` — разделители
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+```
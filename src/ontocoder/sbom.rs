@@ -0,0 +1,309 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Software Bill of Materials (SPDX 2.3 JSON)
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ontocoder::file_inventory::FileRecord;
+use crate::ontocoder::header::{PHASE_TAG, PROFILE_TAG};
+use crate::ontocoder::spdx;
+
+pub const SPDX_VERSION: &str = "SPDX-2.3";
+pub const DOCUMENT_ID: &str = "SPDXRef-DOCUMENT";
+pub const NOASSERTION: &str = "NOASSERTION";
+
+/// Документ SPDX 2.3 (JSON-сериализация по спецификации)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxDocument {
+    pub spdx_version: String,
+    pub data_license: String,
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    pub name: String,
+    pub document_namespace: String,
+    pub creation_info: CreationInfo,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<SpdxPackage>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<SpdxFile>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<Relationship>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreationInfo {
+    pub created: String, // ISO 8601, UTC
+    pub creators: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license_list_version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxPackage {
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_info: Option<String>,
+    pub download_location: String,
+    pub files_analyzed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_verification_code: Option<VerificationCode>, // обязателен при filesAnalyzed
+    pub license_concluded: String,
+    pub license_declared: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub license_info_from_files: Vec<String>,
+    pub copyright_text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checksums: Vec<Checksum>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_refs: Vec<ExternalRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Файл пакета; профиль и фаза onto-144 записываются аннотациями
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxFile {
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    pub file_name: String, // `./src/lib.rs`
+    pub checksums: Vec<Checksum>,
+    pub license_concluded: String,
+    #[serde(default)]
+    pub license_info_in_files: Vec<String>,
+    pub copyright_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license_comments: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Annotation {
+    pub annotation_date: String,
+    pub annotation_type: String, // REVIEW | OTHER
+    pub annotator: String,
+    pub comment: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationCode {
+    pub package_verification_code_value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Checksum {
+    pub algorithm: String, // SHA1, SHA256, …
+    pub checksum_value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalRef {
+    pub reference_category: String,
+    pub reference_type: String,
+    pub reference_locator: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Relationship {
+    pub spdx_element_id: String,
+    pub relationship_type: String, // DESCRIBES, DEPENDS_ON, CONTAINS, …
+    pub related_spdx_element: String,
+}
+
+impl SpdxDocument {
+    /// Пустой документ; пространство имён уникально для каждого запуска
+    pub fn new(name: &str) -> Self {
+        let id = uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext));
+        Self {
+            spdx_version: SPDX_VERSION.into(),
+            data_license: "CC0-1.0".into(),
+            spdx_id: DOCUMENT_ID.into(),
+            name: name.to_string(),
+            document_namespace: format!("https://spdx.org/spdxdocs/{}-{}", spdx_ref(name), id),
+            creation_info: CreationInfo {
+                created: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                creators: vec![format!("Tool: ontocms-{}", env!("CARGO_PKG_VERSION"))],
+                license_list_version: None,
+            },
+            packages: vec![],
+            files: vec![],
+            relationships: vec![],
+        }
+    }
+
+    /// SBOM исходного дерева: пакет проекта, его файлы и аннотации ONTO-PROFILE / ONTO-PHASE
+    pub fn for_files(name: &str, release: Option<&str>, files: &[FileRecord], license_list_version: &str) -> Self {
+        let mut doc = Self::new(name);
+        doc.creation_info.license_list_version = Some(license_list_version.to_string());
+        let package_id = format!("SPDXRef-Package-{}", spdx_ref(name));
+        let annotator = doc.creation_info.creators[0].clone();
+        let date = doc.creation_info.created.clone();
+
+        let mut license_ids = BTreeSet::new();
+        for (index, record) in files.iter().enumerate() {
+            let license = license_field(&record.license);
+            let in_file = license_ids_of(&license);
+            license_ids.extend(in_file.iter().cloned());
+
+            let annotate = |tag: &str, value: &Option<String>| {
+                value.as_ref().map(|v| Annotation {
+                    annotation_date: date.clone(),
+                    annotation_type: "OTHER".into(),
+                    annotator: annotator.clone(),
+                    comment: format!("{} {}", tag, v),
+                })
+            };
+            let file = SpdxFile {
+                spdx_id: format!("SPDXRef-File-{}-{}", index + 1, spdx_ref(&record.path)), // путь после замены символов может совпасть
+                file_name: format!("./{}", record.path),
+                checksums: vec![
+                    Checksum {
+                        algorithm: "SHA1".into(),
+                        checksum_value: record.sha1.clone(),
+                    },
+                    Checksum {
+                        algorithm: "SHA256".into(),
+                        checksum_value: record.sha256.clone(),
+                    },
+                ],
+                license_info_in_files: if in_file.is_empty() { vec![NOASSERTION.into()] } else { in_file },
+                license_comments: (license == NOASSERTION && record.license != NOASSERTION)
+                    .then(|| format!("header declares '{}', which is not a valid SPDX expression", record.license)),
+                license_concluded: license,
+                copyright_text: NOASSERTION.into(),
+                annotations: [annotate(PROFILE_TAG, &record.profile), annotate(PHASE_TAG, &record.phase)]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            };
+            doc.relate(&package_id, "CONTAINS", &file.spdx_id);
+            doc.files.push(file);
+        }
+
+        doc.packages.push(SpdxPackage {
+            spdx_id: package_id.clone(),
+            name: name.to_string(),
+            version_info: release.map(str::to_string),
+            download_location: NOASSERTION.into(),
+            files_analyzed: true,
+            package_verification_code: Some(verification_code(files)),
+            license_concluded: NOASSERTION.into(),
+            license_declared: NOASSERTION.into(),
+            license_info_from_files: license_ids.into_iter().collect(),
+            copyright_text: NOASSERTION.into(),
+            checksums: vec![],
+            external_refs: vec![],
+            comment: None,
+        });
+        doc.relationships.insert(
+            0,
+            Relationship {
+                spdx_element_id: DOCUMENT_ID.into(),
+                relationship_type: "DESCRIBES".into(),
+                related_spdx_element: package_id,
+            },
+        );
+        doc
+    }
+
+    /// Файлы документа в виде записей инвентаря (для сверки с деревом)
+    pub fn file_records(&self) -> Vec<FileRecord> {
+        self.files
+            .iter()
+            .map(|file| {
+                let checksum = |algorithm: &str| {
+                    file.checksums
+                        .iter()
+                        .find(|c| c.algorithm == algorithm)
+                        .map_or(String::new(), |c| c.checksum_value.clone())
+                };
+                let annotation = |tag: &str| {
+                    file.annotations
+                        .iter()
+                        .find_map(|a| a.comment.strip_prefix(tag).map(|v| v.trim().to_string()))
+                };
+                FileRecord {
+                    path: file.file_name.strip_prefix("./").unwrap_or(&file.file_name).to_string(),
+                    sha1: checksum("SHA1"),
+                    sha256: checksum("SHA256"),
+                    license: file.license_concluded.clone(),
+                    profile: annotation(PROFILE_TAG),
+                    phase: annotation(PHASE_TAG),
+                }
+            })
+            .collect()
+    }
+
+    pub fn relate(&mut self, element: &str, relationship_type: &str, related: &str) {
+        self.relationships.push(Relationship {
+            spdx_element_id: element.to_string(),
+            relationship_type: relationship_type.to_string(),
+            related_spdx_element: related.to_string(),
+        });
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Package URL для крейта: `pkg:cargo/serde@1.0.200`
+pub fn cargo_purl(name: &str, version: &str) -> ExternalRef {
+    ExternalRef {
+        reference_category: "PACKAGE-MANAGER".into(),
+        reference_type: "purl".into(),
+        reference_locator: format!("pkg:cargo/{}@{}", name, version),
+    }
+}
+
+/// Лицензия для поля SPDX: каноническое выражение или `NOASSERTION`,
+/// если выражение не разбирается или содержит id вне синтаксиса SPDX (`SGRL-α`)
+pub fn license_field(expression: &str) -> String {
+    match spdx::parse(expression) {
+        Ok(expr) if expr.licenses().iter().all(|l| is_spdx_idstring(&l.id)) => expr.to_string(),
+        _ => NOASSERTION.into(),
+    }
+}
+
+/// Код проверки пакета (SPDX 2.3, 7.9): SHA1 от отсортированных SHA1 файлов
+pub fn verification_code(files: &[FileRecord]) -> VerificationCode {
+    let mut hashes: Vec<&str> = files.iter().map(|f| f.sha1.as_str()).collect();
+    hashes.sort_unstable();
+    VerificationCode {
+        package_verification_code_value: sha1_smol::Sha1::from(hashes.concat()).digest().to_string(),
+    }
+}
+
+fn license_ids_of(license: &str) -> Vec<String> {
+    spdx::parse(license)
+        .map(|expr| {
+            let ids: BTreeSet<String> = expr.licenses().iter().map(|l| l.to_string()).collect();
+            ids.into_iter().collect()
+        })
+        .unwrap_or_default()
+}
+
+fn is_spdx_idstring(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+}
+
+/// Допустимый идентификатор SPDX: буквы, цифры, `.` и `-`
+pub fn spdx_ref(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '-' })
+        .collect()
+}
+```
@@ -0,0 +1,260 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — SPDX License Expressions (SPDX 2.3, Annex D)
+
+use std::fmt;
+
+/// Лицензия без исключения: `MIT`, `GPL-2.0+`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SimpleLicense {
+    pub id: String,
+    pub or_later: bool, // суффикс `+`
+}
+
+/// Разобранное выражение SPDX
+/// Приоритет операторов: `WITH` > `AND` > `OR`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LicenseExpr {
+    License(SimpleLicense),
+    With(SimpleLicense, String),
+    And(Box<LicenseExpr>, Box<LicenseExpr>),
+    Or(Box<LicenseExpr>, Box<LicenseExpr>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpdxError {
+    Empty,
+    InvalidCharacter { character: char, position: usize },
+    UnexpectedToken { token: String, position: usize },
+    UnexpectedEnd,
+    UnclosedParen { position: usize },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Id(String),
+    Plus,
+    And,
+    Or,
+    With,
+    Open,
+    Close,
+}
+
+impl LicenseExpr {
+    /// Все лицензии выражения слева направо
+    pub fn licenses(&self) -> Vec<&SimpleLicense> {
+        match self {
+            LicenseExpr::License(l) | LicenseExpr::With(l, _) => vec![l],
+            LicenseExpr::And(a, b) | LicenseExpr::Or(a, b) => {
+                let mut out = a.licenses();
+                out.extend(b.licenses());
+                out
+            }
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            LicenseExpr::Or(..) => 0,
+            LicenseExpr::And(..) => 1,
+            LicenseExpr::License(_) | LicenseExpr::With(..) => 2,
+        }
+    }
+}
+
+/// Разбор выражения
+/// Операторы принимаются в верхнем или нижнем регистре; `/` (старая запись crates.io) — как `OR`
+pub fn parse(expression: &str) -> Result<LicenseExpr, SpdxError> {
+    let tokens = tokenize(expression)?;
+    if tokens.is_empty() {
+        return Err(SpdxError::Empty);
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.or_expr()?;
+    match parser.tokens.get(parser.pos) {
+        None => Ok(expr),
+        Some((token, position)) => Err(SpdxError::UnexpectedToken {
+            token: token.to_string(),
+            position: *position,
+        }),
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<(Token, usize)>, SpdxError> {
+    let mut tokens = vec![];
+    let mut chars = expression.char_indices().peekable();
+    while let Some(&(position, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | '+' | '/' => {
+                chars.next();
+                let token = match c {
+                    '(' => Token::Open,
+                    ')' => Token::Close,
+                    '+' => Token::Plus,
+                    _ => Token::Or,
+                };
+                tokens.push((token, position));
+            }
+            c if is_id_char(c) => {
+                let mut word = String::new();
+                while let Some(&(_, c)) = chars.peek().filter(|(_, c)| is_id_char(*c)) {
+                    word.push(c);
+                    chars.next();
+                }
+                let token = match word.as_str() {
+                    "AND" | "and" => Token::And,
+                    "OR" | "or" => Token::Or,
+                    "WITH" | "with" => Token::With,
+                    _ => Token::Id(word),
+                };
+                tokens.push((token, position));
+            }
+            character => return Err(SpdxError::InvalidCharacter { character, position }),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Символы idstring; буквы не только ASCII — для собственных идентификаторов вроде `SGRL-α`
+fn is_id_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '.' || c == ':'
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn next(&mut self) -> Result<(Token, usize), SpdxError> {
+        let token = self.tokens.get(self.pos).cloned().ok_or(SpdxError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn or_expr(&mut self) -> Result<LicenseExpr, SpdxError> {
+        let mut left = self.and_expr()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            left = LicenseExpr::Or(Box::new(left), Box::new(self.and_expr()?));
+        }
+        Ok(left)
+    }
+
+    fn and_expr(&mut self) -> Result<LicenseExpr, SpdxError> {
+        let mut left = self.primary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            left = LicenseExpr::And(Box::new(left), Box::new(self.primary()?));
+        }
+        Ok(left)
+    }
+
+    fn primary(&mut self) -> Result<LicenseExpr, SpdxError> {
+        match self.next()? {
+            (Token::Open, position) => {
+                let expr = self.or_expr()?;
+                match self.next() {
+                    Ok((Token::Close, _)) => Ok(expr),
+                    Ok((token, position)) => Err(SpdxError::UnexpectedToken {
+                        token: token.to_string(),
+                        position,
+                    }),
+                    Err(_) => Err(SpdxError::UnclosedParen { position }),
+                }
+            }
+            (Token::Id(id), _) => {
+                let or_later = self.peek() == Some(&Token::Plus);
+                if or_later {
+                    self.pos += 1;
+                }
+                let license = SimpleLicense { id, or_later };
+                if self.peek() != Some(&Token::With) {
+                    return Ok(LicenseExpr::License(license));
+                }
+                self.pos += 1;
+                match self.next()? {
+                    (Token::Id(exception), _) => Ok(LicenseExpr::With(license, exception)),
+                    (token, position) => Err(SpdxError::UnexpectedToken {
+                        token: token.to_string(),
+                        position,
+                    }),
+                }
+            }
+            (token, position) => Err(SpdxError::UnexpectedToken {
+                token: token.to_string(),
+                position,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for SimpleLicense {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.id, if self.or_later { "+" } else { "" })
+    }
+}
+
+/// Каноническая запись: операторы в верхнем регистре, скобки только где нужны
+impl fmt::Display for LicenseExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operand = |f: &mut fmt::Formatter<'_>, e: &LicenseExpr| {
+            if e.precedence() < self.precedence() {
+                write!(f, "({})", e)
+            } else {
+                write!(f, "{}", e)
+            }
+        };
+        match self {
+            LicenseExpr::License(l) => write!(f, "{}", l),
+            LicenseExpr::With(l, exception) => write!(f, "{} WITH {}", l, exception),
+            LicenseExpr::And(a, b) => {
+                operand(f, a)?;
+                write!(f, " AND ")?;
+                operand(f, b)
+            }
+            LicenseExpr::Or(a, b) => {
+                operand(f, a)?;
+                write!(f, " OR ")?;
+                operand(f, b)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Id(id) => write!(f, "{}", id),
+            Token::Plus => write!(f, "+"),
+            Token::And => write!(f, "AND"),
+            Token::Or => write!(f, "OR"),
+            Token::With => write!(f, "WITH"),
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+        }
+    }
+}
+
+impl fmt::Display for SpdxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpdxError::Empty => write!(f, "empty license expression"),
+            SpdxError::InvalidCharacter { character, position } => {
+                write!(f, "invalid character '{}' at {}", character, position)
+            }
+            SpdxError::UnexpectedToken { token, position } => write!(f, "unexpected '{}' at {}", token, position),
+            SpdxError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            SpdxError::UnclosedParen { position } => write!(f, "unclosed '(' at {}", position),
+        }
+    }
+}
+```
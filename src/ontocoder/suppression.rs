@@ -0,0 +1,116 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Attributed Inline Suppressions (onto-allow)
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::activity_ledger::ProfileId;
+use crate::ontocoder::header::Language;
+use crate::ontocoder::report::{LineIndex, SourceSpan};
+
+/// Маркер подавления в комментарии (doc-комментарии Rust — документация, в них маркер не действует):
+/// `// onto-allow(sgrl.forbidden-term): wire format of v1 ledgers ONTO-PROFILE: Aries-Wood-Rabbit`
+pub const SUPPRESSION_MARKER: &str = "onto-allow(";
+
+/// Подавление правила на строке — с причиной и профилем, который за него отвечает
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Suppression {
+    pub rule_id: String,
+    pub reason: String,
+    pub profile: ProfileId,
+    pub span: SourceSpan, // сам комментарий
+    pub lines: (usize, usize), // строки, на которые действует (включительно)
+}
+
+/// Комментарий с маркером, который не удалось разобрать
+#[derive(Clone, Debug)]
+pub struct MalformedSuppression {
+    pub span: SourceSpan,
+    pub message: String,
+}
+
+impl Suppression {
+    pub fn covers(&self, rule_id: &str, span: &SourceSpan) -> bool {
+        self.rule_id == rule_id && self.lines.0 <= span.start_line && span.start_line <= self.lines.1
+    }
+}
+
+/// Все маркеры onto-allow в комментариях файла (синтаксис комментариев — по языку)
+/// Комментарий в конце строки кода действует на эту строку,
+/// отдельный комментарий — на себя и следующую строку
+/// Маркер в строковом литерале не подавляет ничего; для неизвестного языка подавлений нет
+pub fn parse_suppressions(language: Option<Language>, content: &str) -> (Vec<Suppression>, Vec<MalformedSuppression>) {
+    let index = LineIndex::new(content);
+    let mut parsed = vec![];
+    let mut malformed = vec![];
+    let Some(language) = language else {
+        return (parsed, malformed);
+    };
+
+    for comment in language.comment_ranges(content) {
+        if language == Language::Rust && is_rust_doc_comment(&content[comment.clone()]) {
+            continue;
+        }
+        for (at, _) in content[comment.clone()].match_indices(SUPPRESSION_MARKER) {
+            let marker = comment.start + at;
+            let line_start = content[..marker].rfind('\n').map_or(0, |n| n + 1);
+            let line_end = content[marker..].find('\n').map_or(content.len(), |n| marker + n);
+            let text = content[marker..line_end.min(comment.end)].trim_end_matches('\r');
+            let span = index.span_of_bytes(marker, marker + text.len());
+            let line_no = span.start_line;
+            // Отдельный комментарий: до него на строке нет кода (или строка — продолжение блока)
+            let standalone = comment.start < line_start || content[line_start..comment.start].trim().is_empty();
+            let lines = if standalone { (line_no, line_no + 1) } else { (line_no, line_no) };
+
+            match parse_marker(&text[SUPPRESSION_MARKER.len()..]) {
+                Ok((rule_id, reason, profile)) => parsed.push(Suppression {
+                    rule_id,
+                    reason,
+                    profile: ProfileId(profile),
+                    span,
+                    lines,
+                }),
+                Err(message) => malformed.push(MalformedSuppression { span, message }),
+            }
+        }
+    }
+    (parsed, malformed)
+}
+
+/// `///`, `//!`, `/** */`, `/*! */` — но не `////` и не `/***/`
+fn is_rust_doc_comment(comment: &str) -> bool {
+    (comment.starts_with("///") && !comment.starts_with("////"))
+        || comment.starts_with("//!")
+        || (comment.starts_with("/**") && !comment.starts_with("/***") && comment != "/**/")
+        || comment.starts_with("/*!")
+}
+
+/// `rule): reason ONTO-PROFILE: Profile` → (rule, reason, profile)
+fn parse_marker(rest: &str) -> Result<(String, String, String), String> {
+    let (rule_id, rest) = rest
+        .split_once(')')
+        .ok_or_else(|| "unterminated onto-allow(…)".to_string())?;
+    let rule_id = rule_id.trim();
+    if rule_id.is_empty() {
+        return Err("onto-allow requires a rule id".into());
+    }
+    let rest = rest
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(|| format!("onto-allow({}) requires `: reason`", rule_id))?;
+    let rest = rest.trim_end().trim_end_matches("*/").trim_end_matches("-->");
+
+    let (reason, profile) = rest
+        .split_once("ONTO-PROFILE:")
+        .ok_or_else(|| format!("onto-allow({}) must be attributed with ONTO-PROFILE", rule_id))?;
+    let reason = reason.trim().trim_end_matches(['—', '-', ',', ';']).trim();
+    if reason.is_empty() {
+        return Err(format!("onto-allow({}) requires a reason", rule_id));
+    }
+    let profile = profile.split_whitespace().next().unwrap_or_default();
+    if profile.is_empty() {
+        return Err(format!("onto-allow({}) has an empty ONTO-PROFILE", rule_id));
+    }
+    Ok((rule_id.to_string(), reason.to_string(), profile.to_string()))
+}
+```
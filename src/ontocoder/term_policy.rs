@@ -0,0 +1,391 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Forbidden-Term Policy over the Rust AST (syn)
+
+use std::fs;
+use std::sync::OnceLock;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use syn::visit::{self, Visit};
+
+use crate::ethics::harm_rules::normalize;
+use crate::ontocoder::report::{LineIndex, SourceSpan};
+
+/// Встроенная политика (версионируемый конфиг)
+const BUILTIN_POLICY: &str = include_str!("policy/term_policy.v1.yaml");
+
+pub const SUPPORTED_POLICY_VERSION: u32 = 1;
+
+/// Область исходного кода, к которой применяется запрет
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TermScope {
+    PublicItems,
+    PrivateItems,
+    Fields,
+    Variants,
+    Locals,
+    StringLiterals,
+    Text, // файлы не на Rust: слова-идентификаторы в тексте
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TermPolicyConfig {
+    pub version: u32,
+    pub terms: Vec<String>,
+    pub scopes: Vec<TermScope>,
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub allow_symbols: Vec<String>,
+}
+
+/// Нарушение политики: запрещённый термин в имени или литерале
+#[derive(Clone, Debug)]
+pub struct TermViolation {
+    pub term: String,    // основа из политики
+    pub matched: String, // совпавшее слово
+    pub symbol: String,  // имя (Type::member) или литерал
+    pub scope: TermScope,
+    pub span: SourceSpan,
+}
+
+#[derive(Debug)]
+pub enum PolicyError {
+    UnsupportedVersion(u32),
+    InvalidTerm { term: String, reason: String },
+    Parse(String),
+    Io(String),
+    /// Исходный код не разбирается как Rust
+    Syntax { message: String, span: SourceSpan },
+}
+
+/// Скомпилированная политика запрещённых терминов
+pub struct TermPolicy {
+    config: TermPolicyConfig,
+    terms: Vec<(String, Regex)>,
+    allow: Vec<Regex>,
+}
+
+impl TermPolicy {
+    /// Встроенная политика ontoCMS
+    pub fn builtin() -> Result<Self, PolicyError> {
+        Self::from_yaml_str(BUILTIN_POLICY)
+    }
+
+    pub fn load(path: &str) -> Result<Self, PolicyError> {
+        let content = fs::read_to_string(path).map_err(|e| PolicyError::Io(e.to_string()))?;
+        Self::from_yaml_str(&content)
+    }
+
+    pub fn from_yaml_str(content: &str) -> Result<Self, PolicyError> {
+        let config: TermPolicyConfig = serde_yaml::from_str(content).map_err(|e| PolicyError::Parse(e.to_string()))?;
+        Self::new(config)
+    }
+
+    pub fn new(config: TermPolicyConfig) -> Result<Self, PolicyError> {
+        if config.version != SUPPORTED_POLICY_VERSION {
+            return Err(PolicyError::UnsupportedVersion(config.version));
+        }
+        let compile = |term: &String, pattern: String| {
+            Regex::new(&pattern).map_err(|e| PolicyError::InvalidTerm {
+                term: term.clone(),
+                reason: e.to_string(),
+            })
+        };
+        // Термин — основа слова: "score" совпадает с "scores", но не с "underscore"
+        let terms = config
+            .terms
+            .iter()
+            .map(|t| Ok((t.clone(), compile(t, format!(r"\b{}\w*", regex::escape(&normalize(t))))?)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let allow = config
+            .allow
+            .iter()
+            .map(|a| compile(a, format!(r"\b{}\b", regex::escape(&normalize(a)))))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { config, terms, allow })
+    }
+
+    pub fn config(&self) -> &TermPolicyConfig {
+        &self.config
+    }
+
+    /// Проверка исходного кода на Rust по AST
+    pub fn check_rust(&self, source: &str) -> Result<Vec<TermViolation>, PolicyError> {
+        let index = LineIndex::new(source);
+        let file = syn::parse_file(source).map_err(|e| PolicyError::Syntax {
+            message: e.to_string(),
+            span: span_of(&index, e.span()),
+        })?;
+        let mut visitor = PolicyVisitor {
+            policy: self,
+            index,
+            container: vec![],
+            in_trait_impl: false,
+            trait_public: false,
+            violations: vec![],
+        };
+        visitor.visit_file(&file);
+        Ok(visitor.violations)
+    }
+
+    /// Проверка текста другого формата: каждое слово-идентификатор
+    pub fn check_text(&self, content: &str) -> Vec<TermViolation> {
+        let index = LineIndex::new(content);
+        let mut violations = vec![];
+        for (start, end) in text_words(content) {
+            let word = &content[start..end];
+            let span = index.span_of_bytes(start, end);
+            self.check_symbol(word, word, TermScope::Text, span, &mut violations);
+        }
+        violations
+    }
+
+    fn check_symbol(&self, text: &str, qualified: &str, scope: TermScope, span: SourceSpan, out: &mut Vec<TermViolation>) {
+        if scope != TermScope::Text && !self.config.scopes.contains(&scope) {
+            return;
+        }
+        if self
+            .config
+            .allow_symbols
+            .iter()
+            .any(|s| s == text || s == qualified)
+        {
+            return;
+        }
+
+        let normalized = normalize(text);
+        let allowed: Vec<(usize, usize)> = self
+            .allow
+            .iter()
+            .flat_map(|a| a.find_iter(&normalized))
+            .map(|m| (m.start(), m.end()))
+            .collect();
+
+        for (term, re) in &self.terms {
+            let hit = re
+                .find_iter(&normalized)
+                .find(|m| !allowed.iter().any(|(s, e)| *s <= m.start() && m.end() <= *e));
+            if let Some(m) = hit {
+                out.push(TermViolation {
+                    term: term.clone(),
+                    matched: m.as_str().to_string(),
+                    symbol: qualified.to_string(),
+                    scope,
+                    span,
+                });
+            }
+        }
+    }
+}
+
+/// Слова-идентификаторы ASCII (`[A-Za-z][A-Za-z0-9_]*`) как диапазоны байтов
+fn text_words(content: &str) -> Vec<(usize, usize)> {
+    let bytes = content.as_bytes();
+    let mut words = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_alphabetic() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+            i += 1;
+        }
+        words.push((start, i));
+    }
+    words
+}
+
+/// Политика по умолчанию (разбирается один раз)
+/// Встроенная политика проверяется тестом `builtin_term_policy_is_valid` (tests/embedded_data.rs):
+/// ошибка в ней — ошибка сборки, а не входных данных
+#[allow(clippy::expect_used)]
+pub fn default_policy() -> &'static TermPolicy {
+    static POLICY: OnceLock<TermPolicy> = OnceLock::new();
+    POLICY.get_or_init(|| TermPolicy::builtin().expect("embedded term policy must be valid"))
+}
+
+struct PolicyVisitor<'p> {
+    policy: &'p TermPolicy,
+    index: LineIndex<'p>,
+    container: Vec<String>, // тип, в котором объявлен элемент (для Type::member)
+    in_trait_impl: bool,    // имена в impl Trait for X выбраны автором трейта
+    trait_public: bool,
+    violations: Vec<TermViolation>,
+}
+
+impl PolicyVisitor<'_> {
+    fn check_ident(&mut self, ident: &syn::Ident, scope: TermScope) {
+        let name = ident.to_string();
+        let qualified = match self.container.last() {
+            Some(container) => format!("{}::{}", container, name),
+            None => name.clone(),
+        };
+        self.policy
+            .check_symbol(&name, &qualified, scope, span_of(&self.index, ident.span()), &mut self.violations);
+    }
+
+    fn check_literal(&mut self, value: &str, span: Span) {
+        // Литерал-ключ (без пробелов) — данные; текст сообщений — описание
+        if value.is_empty() || value.chars().any(char::is_whitespace) {
+            return;
+        }
+        self.policy
+            .check_symbol(value, value, TermScope::StringLiterals, span_of(&self.index, span), &mut self.violations);
+    }
+
+    /// Тело макроса не разбирается syn — строковые литералы берутся из токенов
+    fn check_macro_tokens(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Group(group) => self.check_macro_tokens(group.stream()),
+                TokenTree::Literal(literal) => {
+                    if let syn::Lit::Str(s) = syn::Lit::new(literal) {
+                        self.check_literal(&s.value(), s.span());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn with_container(&mut self, name: String, f: impl FnOnce(&mut Self)) {
+        self.container.push(name);
+        f(self);
+        self.container.pop();
+    }
+}
+
+fn item_scope(vis: &syn::Visibility) -> TermScope {
+    match vis {
+        syn::Visibility::Public(_) => TermScope::PublicItems,
+        _ => TermScope::PrivateItems,
+    }
+}
+
+impl<'ast> Visit<'ast> for PolicyVisitor<'_> {
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        self.check_ident(&item.sig.ident, item_scope(&item.vis));
+        visit::visit_item_fn(self, item);
+    }
+
+    fn visit_item_struct(&mut self, item: &'ast syn::ItemStruct) {
+        self.check_ident(&item.ident, item_scope(&item.vis));
+        self.with_container(item.ident.to_string(), |v| visit::visit_item_struct(v, item));
+    }
+
+    fn visit_item_enum(&mut self, item: &'ast syn::ItemEnum) {
+        self.check_ident(&item.ident, item_scope(&item.vis));
+        self.with_container(item.ident.to_string(), |v| visit::visit_item_enum(v, item));
+    }
+
+    fn visit_item_union(&mut self, item: &'ast syn::ItemUnion) {
+        self.check_ident(&item.ident, item_scope(&item.vis));
+        self.with_container(item.ident.to_string(), |v| visit::visit_item_union(v, item));
+    }
+
+    fn visit_item_trait(&mut self, item: &'ast syn::ItemTrait) {
+        self.check_ident(&item.ident, item_scope(&item.vis));
+        let was_public = self.trait_public;
+        self.trait_public = matches!(item.vis, syn::Visibility::Public(_));
+        self.with_container(item.ident.to_string(), |v| visit::visit_item_trait(v, item));
+        self.trait_public = was_public;
+    }
+
+    fn visit_item_const(&mut self, item: &'ast syn::ItemConst) {
+        self.check_ident(&item.ident, item_scope(&item.vis));
+        visit::visit_item_const(self, item);
+    }
+
+    fn visit_item_static(&mut self, item: &'ast syn::ItemStatic) {
+        self.check_ident(&item.ident, item_scope(&item.vis));
+        visit::visit_item_static(self, item);
+    }
+
+    fn visit_item_type(&mut self, item: &'ast syn::ItemType) {
+        self.check_ident(&item.ident, item_scope(&item.vis));
+        visit::visit_item_type(self, item);
+    }
+
+    fn visit_item_mod(&mut self, item: &'ast syn::ItemMod) {
+        self.check_ident(&item.ident, item_scope(&item.vis));
+        visit::visit_item_mod(self, item);
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast syn::ItemImpl) {
+        let name = match item.self_ty.as_ref() {
+            syn::Type::Path(p) => p.path.segments.last().map(|s| s.ident.to_string()),
+            _ => None,
+        }
+        .unwrap_or_default();
+        let was_trait_impl = self.in_trait_impl;
+        self.in_trait_impl = item.trait_.is_some();
+        self.with_container(name, |v| visit::visit_item_impl(v, item));
+        self.in_trait_impl = was_trait_impl;
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        if !self.in_trait_impl {
+            self.check_ident(&item.sig.ident, item_scope(&item.vis));
+        }
+        visit::visit_impl_item_fn(self, item);
+    }
+
+    fn visit_impl_item_const(&mut self, item: &'ast syn::ImplItemConst) {
+        if !self.in_trait_impl {
+            self.check_ident(&item.ident, item_scope(&item.vis));
+        }
+        visit::visit_impl_item_const(self, item);
+    }
+
+    fn visit_trait_item_fn(&mut self, item: &'ast syn::TraitItemFn) {
+        let scope = if self.trait_public {
+            TermScope::PublicItems
+        } else {
+            TermScope::PrivateItems
+        };
+        self.check_ident(&item.sig.ident, scope);
+        visit::visit_trait_item_fn(self, item);
+    }
+
+    fn visit_field(&mut self, field: &'ast syn::Field) {
+        if let Some(ident) = &field.ident {
+            self.check_ident(ident, TermScope::Fields);
+        }
+        visit::visit_field(self, field);
+    }
+
+    fn visit_variant(&mut self, variant: &'ast syn::Variant) {
+        self.check_ident(&variant.ident, TermScope::Variants);
+        visit::visit_variant(self, variant);
+    }
+
+    fn visit_pat_ident(&mut self, pat: &'ast syn::PatIdent) {
+        self.check_ident(&pat.ident, TermScope::Locals);
+        visit::visit_pat_ident(self, pat);
+    }
+
+    fn visit_lit_str(&mut self, lit: &'ast syn::LitStr) {
+        self.check_literal(&lit.value(), lit.span());
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        self.check_macro_tokens(mac.tokens.clone());
+    }
+
+    // Атрибуты (в том числе doc-комментарии) и импорты не проверяются
+    fn visit_attribute(&mut self, _attr: &'ast syn::Attribute) {}
+
+    fn visit_item_use(&mut self, _item: &'ast syn::ItemUse) {}
+}
+
+fn span_of(index: &LineIndex, span: Span) -> SourceSpan {
+    let (start, end) = (span.start(), span.end());
+    index.span_of_chars(start.line, start.column, end.line, end.column)
+}
+```
@@ -2,24 +2,29 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // OntoCoder — License and Ontology Validator
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
+use crate::ontocoder::cla_registry::ClaRegistry;
+use crate::ontocoder::config::{ConfigError, ProjectConfig, RuleLevel};
+use crate::ontocoder::git_history::GitHistory;
+use crate::ontocoder::header::{FileHeader, Language, PHASE_TAG, PROFILE_TAG, SPDX_TAG};
 use crate::ontocoder::license_registry::LicenseRegistry;
+use crate::ontocoder::report::{
+    rules, Finding, Fix, LineIndex, Severity, SourceSpan, SuppressedFinding, TextEdit, ValidationReport,
+};
+use crate::ontocoder::suppression;
+use crate::ontocoder::term_policy::{self, PolicyError, TermPolicy, TermScope};
 use crate::core::activity_ledger::ProfileId;
 
-#[derive(Debug)]
-pub enum ValidationError {
-    LicenseIncompatible(String),
-    ProfileNotInRegistry(ProfileId),
-    EnergyValueDetected,
-    PhaseAnnotationMissing,
-    AENGAViolation,
-}
+const PHASES: [&str; 4] = ["Fast", "Slow", "Heyday", "Decline"];
 
 pub struct OntoValidator {
     license_registry: LicenseRegistry,
     allowed_profiles: HashSet<ProfileId>,
+    term_policy: Option<TermPolicy>, // None — встроенная политика
+    levels: BTreeMap<String, RuleLevel>, // уровни из ontocms.toml
+    cla: Option<(ClaRegistry, GitHistory)>, // None — проверка CLA выключена
 }
 
 impl OntoValidator {
@@ -27,71 +32,366 @@ impl OntoValidator {
         Self {
             license_registry,
             allowed_profiles: profile_list.into_iter().collect(),
+            term_policy: None,
+            levels: BTreeMap::new(),
+            cla: None,
+        }
+    }
+
+    /// Настройки проекта: уровни правил и секция `[terms]` из ontocms.toml
+    pub fn with_config(mut self, config: &ProjectConfig) -> Result<Self, ConfigError> {
+        if let Some(policy) = config.term_policy()? {
+            self.term_policy = Some(policy);
+        }
+        self.levels = config.file().rules.clone();
+        Ok(self)
+    }
+
+    /// Собственная политика запрещённых терминов вместо встроенной
+    pub fn with_term_policy(mut self, policy: TermPolicy) -> Self {
+        self.term_policy = Some(policy);
+        self
+    }
+
+    /// Проверка CLA: последний автор файла под лицензией, требующей CLA, должен быть в реестре
+    pub fn with_cla(mut self, registry: ClaRegistry, history: GitHistory) -> Self {
+        self.cla = Some((registry, history));
+        self
+    }
+
+    pub fn term_policy(&self) -> &TermPolicy {
+        self.term_policy.as_ref().unwrap_or_else(|| term_policy::default_policy())
+    }
+
+    /// Важность правила с учётом ontocms.toml; `None` — правило отключено
+    pub fn severity(&self, rule_id: &str) -> Option<Severity> {
+        match self.levels.get(rule_id) {
+            Some(level) => level.severity(),
+            None => Some(default_severity(rule_id)),
         }
     }
 
     /// Валидация исходного файла на соответствие SGRL-α и onto-144
-    pub fn validate_file(&self, path: &Path, content: &str) -> Result<(), ValidationError> {
-        self.check_no_energy_values(content)?;
-        self.check_license_compliance(path)?;
-        self.check_profile_attribution(content)?;
-        self.check_phase_annotation(content)?;
-        self.check_aenga_compliance(content)?;
-        Ok(())
-    }
-
-    fn check_no_energy_values(&self, content: &str) -> Result<(), ValidationError> {
-        let forbidden = &["energy", "value", "attract", "monetize", "score"];
-        if forbidden.iter().any(|kw| content.contains(kw)) {
-            Err(ValidationError::EnergyValueDetected)
-        } else {
-            Ok(())
+    /// Все проверки выполняются всегда; отчёт содержит все находки
+    /// Язык (и синтаксис заголовка) — по расширению или shebang
+    pub fn validate_file(&self, path: &Path, content: &str) -> ValidationReport {
+        let index = LineIndex::new(content);
+        let header = FileHeader::of_file(path, content);
+        let mut report = ValidationReport::new(&path.display().to_string());
+
+        self.check_no_energy_values(&header, content, &index, &mut report);
+        self.check_license_compliance(&header, &index, &mut report);
+        self.check_cla(path, &header, &index, &mut report);
+        self.check_profile_attribution(&header, &index, &mut report);
+        self.check_phase_annotation(&header, &index, &mut report);
+        self.check_aenga_compliance(content, &index, &mut report);
+
+        report.findings.retain_mut(|f| match self.severity(&f.rule_id) {
+            Some(severity) => {
+                f.severity = severity;
+                true
+            }
+            None => false,
+        });
+        self.apply_suppressions(header.language, content, &mut report);
+
+        report.sort();
+        report
+    }
+
+    /// Подавления onto-allow: каждое требует причину и профиль из реестра onto-144
+    /// Подавить нельзя только находки о самих подавлениях
+    fn apply_suppressions(&self, language: Option<Language>, content: &str, report: &mut ValidationReport) {
+        let (parsed, malformed) = suppression::parse_suppressions(language, content);
+        let mut extra = vec![];
+
+        for m in malformed {
+            extra.push(finding(rules::SUPPRESSION_INVALID, m.span, m.message, None));
         }
+
+        for s in parsed {
+            if !self.allowed_profiles.contains(&s.profile) {
+                extra.push(finding(
+                    rules::SUPPRESSION_INVALID,
+                    s.span,
+                    format!(
+                        "onto-allow({}) is attributed to '{}', which is not in the onto-144 registry",
+                        s.rule_id, s.profile.0
+                    ),
+                    None,
+                ));
+                continue;
+            }
+
+            let (hidden, kept): (Vec<Finding>, Vec<Finding>) = std::mem::take(&mut report.findings)
+                .into_iter()
+                .partition(|f| s.covers(&f.rule_id, &f.span));
+            report.findings = kept;
+
+            if hidden.is_empty() {
+                extra.push(finding(
+                    rules::SUPPRESSION_UNUSED,
+                    s.span,
+                    format!("onto-allow({}) does not suppress any finding", s.rule_id),
+                    Some(Fix {
+                        title: "Remove the unused suppression".into(),
+                        edits: vec![TextEdit {
+                            span: s.span,
+                            new_text: String::new(),
+                        }],
+                    }),
+                ));
+            }
+            report.suppressed.extend(hidden.into_iter().map(|finding| SuppressedFinding {
+                finding,
+                suppression: s.clone(),
+            }));
+        }
+
+        report.findings.extend(extra.into_iter().filter_map(|mut f| {
+            f.severity = self.severity(&f.rule_id)?;
+            Some(f)
+        }));
     }
 
-    fn check_license_compliance(&self, path: &Path) -> Result<(), ValidationError> {
-        let license = self.license_registry.detect_license_from_file(path);
-        if !self.license_registry.is_compatible_with_sgcl(&license) {
-            Err(ValidationError::LicenseIncompatible(license))
+    fn check_no_energy_values(&self, header: &FileHeader, content: &str, index: &LineIndex, report: &mut ValidationReport) {
+        // Rust — по AST (имена, поля, литералы); остальные форматы — по словам
+        let violations = if header.language == Some(Language::Rust) {
+            match self.term_policy().check_rust(content) {
+                Ok(violations) => violations,
+                Err(PolicyError::Syntax { message, span }) => {
+                    report.push(finding(rules::SYNTAX, span, format!("cannot parse Rust source: {}", message), None));
+                    return;
+                }
+                Err(e) => {
+                    report.push(finding(rules::SYNTAX, index.span_of_bytes(0, 0), format!("{:?}", e), None));
+                    return;
+                }
+            }
         } else {
-            Ok(())
+            self.term_policy().check_text(content)
+        };
+
+        for v in violations {
+            report.push(finding(
+                rules::FORBIDDEN_TERM,
+                v.span,
+                format!(
+                    "energy/value metric '{}' in {} — forbidden by SGRL-α (term '{}')",
+                    v.symbol,
+                    scope_name(v.scope),
+                    v.term
+                ),
+                Some(Fix {
+                    title: format!("Rename '{}' to an ontologically neutral name", v.symbol),
+                    edits: vec![],
+                }),
+            ));
         }
     }
 
-    fn check_profile_attribution(&self, content: &str) -> Result<(), ValidationError> {
-        // Ищем строку вида: // ONTO-PROFILE: Aries-Wood-Rabbit
-        let lines: Vec<&str> = content.lines().collect();
-        let profile_line = lines.iter().find(|l| l.contains("ONTO-PROFILE:"));
-        match profile_line {
-            Some(line) => {
-                let id_str = line.split("ONTO-PROFILE:").nth(1).unwrap_or("").trim();
-                let profile_id = ProfileId(id_str.to_string());
-                if self.allowed_profiles.contains(&profile_id) {
-                    Ok(())
+    fn check_license_compliance(&self, header: &FileHeader, index: &LineIndex, report: &mut ValidationReport) {
+        let license = header.license();
+        let verdict = self.license_registry.evaluate(license);
+        if verdict.compatible {
+            return;
+        }
+        let insert = index.span_of_bytes(header.insert_at, header.insert_at);
+        let span = header.spdx.as_ref().map_or(insert, |t| t.span);
+        report.push(finding(
+            rules::LICENSE,
+            span,
+            format!("license {} is incompatible with SGCL: {}", license, verdict.explanation.join("; ")),
+            Some(Fix {
+                title: "Use SPDX-License-Identifier: GPL-3.0-only".into(),
+                edits: if header.spdx.is_none() {
+                    vec![TextEdit {
+                        span: insert,
+                        new_text: header.line(&format!("{} GPL-3.0-only", SPDX_TAG)),
+                    }]
                 } else {
-                    Err(ValidationError::ProfileNotInRegistry(profile_id))
-                }
-            }
-            None => Err(ValidationError::ProfileNotInRegistry(ProfileId("unattributed".into()))),
+                    vec![TextEdit {
+                        span,
+                        new_text: "GPL-3.0-only".into(),
+                    }]
+                },
+            }),
+        ));
+    }
+
+    fn check_cla(&self, path: &Path, header: &FileHeader, index: &LineIndex, report: &mut ValidationReport) {
+        let Some((registry, history)) = &self.cla else { return };
+        let license = header.license();
+        if !self.license_registry.requires_cla(license) {
+            return;
         }
+        // Файл вне истории (новый, ещё не закоммиченный) проверяется при коммите
+        let Some(change) = history.last_change(path) else { return };
+        if registry.has_cla(&change.author) {
+            return;
+        }
+        let span = header
+            .spdx
+            .as_ref()
+            .map_or(index.span_of_bytes(header.insert_at, header.insert_at), |t| t.span);
+        report.push(finding(
+            rules::CLA_MISSING,
+            span,
+            format!(
+                "{} requires a CLA, but the file was last changed by {} in {} without a recorded CLA",
+                license,
+                change.author,
+                &change.commit[..change.commit.len().min(12)]
+            ),
+            None,
+        ));
     }
 
-    fn check_phase_annotation(&self, content: &str) -> Result<(), ValidationError> {
+    fn check_profile_attribution(&self, header: &FileHeader, index: &LineIndex, report: &mut ValidationReport) {
+        // Ищем строку вида: // ONTO-PROFILE: Aries-Wood-Rabbit (или # …, <!-- … -->, front matter)
+        let Some(tag) = &header.profile else {
+            report.push(finding(
+                rules::PROFILE_MISSING,
+                index.span_of_bytes(header.insert_at, header.insert_at),
+                "missing ONTO-PROFILE annotation".into(),
+                Some(Fix {
+                    title: format!(
+                        "Add `{}` to the file header",
+                        header.line(&format!("{} <Zodiac-Element-Animal>", PROFILE_TAG)).trim_end()
+                    ),
+                    edits: vec![],
+                }),
+            ));
+            return;
+        };
+
+        let span = tag.span;
+        let profile_id = ProfileId(tag.value.clone());
+        if self.allowed_profiles.contains(&profile_id) {
+            return;
+        }
+        let fix = closest(&profile_id.0, self.allowed_profiles.iter().map(|p| p.0.as_str())).map(|suggestion| Fix {
+            title: format!("Replace with {}", suggestion),
+            edits: vec![TextEdit {
+                span,
+                new_text: suggestion.to_string(),
+            }],
+        });
+        report.push(finding(
+            rules::PROFILE_UNKNOWN,
+            span,
+            format!("profile '{}' is not in the onto-144 registry", profile_id.0),
+            fix,
+        ));
+    }
+
+    fn check_phase_annotation(&self, header: &FileHeader, index: &LineIndex, report: &mut ValidationReport) {
         // Требуем: // ONTO-PHASE: Slow | Fast | Heyday | Decline
-        if !content.contains("ONTO-PHASE:") {
-            Err(ValidationError::PhaseAnnotationMissing)
-        } else {
-            Ok(())
+        let Some(tag) = &header.phase else {
+            let insert = index.span_of_bytes(header.insert_at, header.insert_at);
+            let line = header.line(&format!("{} Slow", PHASE_TAG));
+            report.push(finding(
+                rules::PHASE_MISSING,
+                insert,
+                "missing ONTO-PHASE annotation".into(),
+                Some(Fix {
+                    title: format!("Add `{}`", line.trim_end()),
+                    edits: vec![TextEdit {
+                        span: insert,
+                        new_text: line,
+                    }],
+                }),
+            ));
+            return;
+        };
+
+        let span = tag.span;
+        let phase = tag.value.as_str();
+        if PHASES.contains(&phase) {
+            return;
         }
+        let fix = closest(phase, PHASES.iter().copied()).map(|suggestion| Fix {
+            title: format!("Replace with {}", suggestion),
+            edits: vec![TextEdit {
+                span,
+                new_text: suggestion.to_string(),
+            }],
+        });
+        report.push(finding(
+            rules::PHASE_INVALID,
+            span,
+            format!("unknown phase '{}' (expected Fast | Slow | Heyday | Decline)", phase),
+            fix,
+        ));
     }
 
-    fn check_aenga_compliance(&self, content: &str) -> Result<(), ValidationError> {
+    fn check_aenga_compliance(&self, content: &str, index: &LineIndex, report: &mut ValidationReport) {
         // Запрещаем: вызовы внешних API без фазовой обёртки
-        if content.contains("remote_control") || content.contains("override_ethics") {
-            Err(ValidationError::AENGAViolation)
-        } else {
-            Ok(())
+        for pattern in ["remote_control", "override_ethics"] {
+            for (start, _) in content.match_indices(pattern) {
+                report.push(finding(
+                    rules::AENGA,
+                    index.span_of_bytes(start, start + pattern.len()),
+                    format!("AENGA violation: '{}' (remote control or ethics override)", pattern),
+                    None,
+                ));
+            }
         }
     }
 }
+
+/// Важность по умолчанию для правила
+pub fn default_severity(rule_id: &str) -> Severity {
+    match rule_id {
+        rules::PHASE_INVALID => Severity::Warning,
+        _ => Severity::Error,
+    }
+}
+
+fn finding(rule_id: &str, span: SourceSpan, message: String, fix: Option<Fix>) -> Finding {
+    Finding {
+        rule_id: rule_id.to_string(),
+        severity: default_severity(rule_id),
+        span,
+        message,
+        fix,
+    }
+}
+
+/// Ближайший вариант по расстоянию Левенштейна (без учёта регистра)
+fn closest<'a>(value: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let value = value.to_lowercase();
+    candidates
+        .map(|c| (levenshtein(&value, &c.to_lowercase()), c))
+        .filter(|(distance, c)| *distance <= c.len() / 2)
+        .min()
+        .map(|(_, c)| c)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = (prev + usize::from(ca != *cb)).min(row[j] + 1).min(current + 1);
+            prev = current;
+        }
+    }
+    row[b.len()]
+}
+
+fn scope_name(scope: TermScope) -> &'static str {
+    match scope {
+        TermScope::PublicItems => "public item name",
+        TermScope::PrivateItems => "item name",
+        TermScope::Fields => "field name",
+        TermScope::Variants => "enum variant",
+        TermScope::Locals => "local binding",
+        TermScope::StringLiterals => "string literal",
+        TermScope::Text => "text",
+    }
+}
 ```
\ No newline at end of file
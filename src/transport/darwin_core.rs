@@ -19,12 +19,18 @@ pub struct DarwinCoreRecord {
 
 /// Преобразование онтологического события в Darwin Core
 /// Только если событие НЕ содержит биометрию и НЕ приватное
+/// (социальный контекст допускает публикацию: `SocialContext::is_publishable`)
 pub fn export_to_darwin_core(event: &OntoEvent) -> Result<DarwinCoreRecord, DarwinCoreError> {
     // Запрет на биометрию (Law III)
     if event.payload.to_string().to_lowercase().contains("biometric") {
         return Err(DarwinCoreError::BiometricDataDetected);
     }
 
+    // Личные и адресные события не покидают узел
+    if !event.social_context.is_publishable() {
+        return Err(DarwinCoreError::PrivateSocialContext);
+    }
+
     // Только публичные события (например, связанные с наблюдением природы)
     if !is_public_biodiversity_event(event) {
         return Err(DarwinCoreError::NonPublicEvent);
@@ -86,6 +92,7 @@ fn extract_country(event: &OntoEvent) -> Option<String> {
 pub enum DarwinCoreError {
     BiometricDataDetected,
     NonPublicEvent,
+    PrivateSocialContext,
     InvalidTimestamp,
 }
 ```
\ No newline at end of file
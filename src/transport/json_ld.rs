@@ -0,0 +1,631 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// JSON-LD Events — Published Context, Compaction/Expansion, RDF N-Quads
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use serde_json::{json, Map, Value};
+
+use crate::core::activity_ledger::OntoEvent;
+use crate::ethics::law3_harm_witness::HarmWitness;
+
+/// IRI, по которому публикуется контекст ontoCMS
+pub const CONTEXT_IRI: &str = "https://ontocms.org/ns/v1/context.jsonld";
+
+/// Опубликованный контекст — единственный источник терминов (docs/context/ontocms-v1.jsonld)
+const CONTEXT_DOCUMENT: &str = include_str!("../../docs/context/ontocms-v1.jsonld");
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDF_JSON: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON";
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+
+#[derive(Debug)]
+pub enum JsonLdError {
+    UnsupportedContext,
+    InvalidContext(String),
+    UnknownTerm(String),
+    InvalidNode(String),
+}
+
+#[derive(Clone, Debug)]
+struct TermDefinition {
+    iri: String,
+    type_mapping: Option<String>, // "@id" | "@vocab" | "@json" | IRI типа данных
+}
+
+/// Разобранный JSON-LD контекст
+/// Поддерживает ровно то подмножество JSON-LD 1.1, которое использует контекст ontoCMS
+pub struct Context {
+    source: Value,
+    prefixes: BTreeMap<String, String>,
+    aliases: BTreeMap<String, String>,
+    terms: BTreeMap<String, TermDefinition>,
+}
+
+impl Context {
+    /// Разбор документа вида { "@context": { ... } }
+    pub fn parse(doc: &Value) -> Result<Self, JsonLdError> {
+        let defs = doc
+            .get("@context")
+            .and_then(Value::as_object)
+            .ok_or_else(|| JsonLdError::InvalidContext("missing @context object".into()))?;
+
+        let mut ctx = Context {
+            source: Value::Object(defs.clone()),
+            prefixes: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+            terms: BTreeMap::new(),
+        };
+
+        // Первый проход: алиасы ключевых слов и префиксы
+        for (key, def) in defs {
+            if let Some(iri) = def.as_str() {
+                if iri.starts_with('@') {
+                    ctx.aliases.insert(key.clone(), iri.to_string());
+                } else if iri.ends_with(['#', '/', ':']) {
+                    ctx.prefixes.insert(key.clone(), iri.to_string());
+                }
+            }
+        }
+
+        // Второй проход: термины (могут ссылаться на префиксы)
+        for (key, def) in defs {
+            if key.starts_with('@') || ctx.aliases.contains_key(key) || ctx.prefixes.contains_key(key) {
+                continue;
+            }
+            let term = match def {
+                Value::String(iri) => TermDefinition {
+                    iri: ctx.expand_prefixed(iri),
+                    type_mapping: None,
+                },
+                Value::Object(obj) => {
+                    let iri = obj.get("@id").and_then(Value::as_str).ok_or_else(|| {
+                        JsonLdError::InvalidContext(format!("term '{}' has no @id", key))
+                    })?;
+                    let type_mapping = obj.get("@type").and_then(Value::as_str).map(|t| {
+                        if t.starts_with('@') {
+                            t.to_string()
+                        } else {
+                            ctx.expand_prefixed(t)
+                        }
+                    });
+                    TermDefinition {
+                        iri: ctx.expand_prefixed(iri),
+                        type_mapping,
+                    }
+                }
+                _ => {
+                    return Err(JsonLdError::InvalidContext(format!(
+                        "term '{}' has unsupported definition",
+                        key
+                    )))
+                }
+            };
+            ctx.terms.insert(key.clone(), term);
+        }
+
+        Ok(ctx)
+    }
+
+    /// Развёртывание документа в expanded form (массив узлов с полными IRI)
+    pub fn expand(&self, doc: &Value) -> Result<Value, JsonLdError> {
+        if let Some(context) = doc.get("@context") {
+            self.check_context(context)?;
+        }
+
+        let nodes: Vec<&Value> = match doc {
+            Value::Array(items) => items.iter().collect(),
+            Value::Object(obj) => match obj.get("@graph") {
+                Some(Value::Array(items)) => items.iter().collect(),
+                Some(_) => return Err(JsonLdError::InvalidNode("@graph must be an array".into())),
+                None => vec![doc],
+            },
+            _ => return Err(JsonLdError::InvalidNode("document must be an object or array".into())),
+        };
+
+        nodes
+            .into_iter()
+            .map(|node| self.expand_node(node))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array)
+    }
+
+    /// Сжатие expanded-документа обратно в термины контекста ontoCMS
+    pub fn compact(&self, expanded: &Value) -> Result<Value, JsonLdError> {
+        let mut nodes = as_list(expanded)
+            .into_iter()
+            .map(|node| self.compact_node(node))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut doc = Map::new();
+        doc.insert("@context".into(), Value::String(CONTEXT_IRI.into()));
+        if nodes.len() == 1 {
+            if let Some(Value::Object(node)) = nodes.pop() {
+                doc.extend(node);
+            }
+        } else {
+            doc.insert("@graph".into(), Value::Array(nodes));
+        }
+        Ok(Value::Object(doc))
+    }
+
+    /// Преобразование expanded-документа в строки RDF N-Quads (отсортированные, без дублей)
+    pub fn to_nquads(&self, expanded: &Value, graph: Option<&str>) -> Result<Vec<String>, JsonLdError> {
+        let graph_suffix = graph.map(|g| format!(" {}", iri_term(g))).unwrap_or_default();
+        let mut quads = Vec::new();
+
+        for node in as_list(expanded) {
+            let obj = node
+                .as_object()
+                .ok_or_else(|| JsonLdError::InvalidNode("node must be an object".into()))?;
+            let subject = obj
+                .get("@id")
+                .and_then(Value::as_str)
+                .ok_or_else(|| JsonLdError::InvalidNode("node without @id".into()))?;
+
+            for (key, values) in obj {
+                match key.as_str() {
+                    "@id" => {}
+                    "@type" => {
+                        for t in as_list(values) {
+                            let t = t
+                                .as_str()
+                                .ok_or_else(|| JsonLdError::InvalidNode("@type must be an IRI".into()))?;
+                            quads.push(format!(
+                                "{} {} {}{} .",
+                                iri_term(subject),
+                                iri_term(RDF_TYPE),
+                                iri_term(t),
+                                graph_suffix
+                            ));
+                        }
+                    }
+                    predicate => {
+                        for value in as_list(values) {
+                            quads.push(format!(
+                                "{} {} {}{} .",
+                                iri_term(subject),
+                                iri_term(predicate),
+                                object_term(value)?,
+                                graph_suffix
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        quads.sort();
+        quads.dedup();
+        Ok(quads)
+    }
+
+    fn check_context(&self, context: &Value) -> Result<(), JsonLdError> {
+        match context {
+            Value::String(iri) if iri == CONTEXT_IRI => Ok(()),
+            Value::Object(_) if *context == self.source => Ok(()),
+            _ => Err(JsonLdError::UnsupportedContext),
+        }
+    }
+
+    fn keyword<'a>(&'a self, key: &'a str) -> &'a str {
+        self.aliases.get(key).map(|k| k.as_str()).unwrap_or(key)
+    }
+
+    fn alias_for(&self, keyword: &str) -> String {
+        self.aliases
+            .iter()
+            .find(|(_, k)| k.as_str() == keyword)
+            .map(|(alias, _)| alias.clone())
+            .unwrap_or_else(|| keyword.to_string())
+    }
+
+    fn expand_prefixed(&self, value: &str) -> String {
+        if let Some((prefix, suffix)) = value.split_once(':') {
+            if let Some(base) = self.prefixes.get(prefix) {
+                return format!("{}{}", base, suffix);
+            }
+        }
+        value.to_string()
+    }
+
+    fn expand_vocab(&self, value: &str) -> String {
+        match self.terms.get(value) {
+            Some(def) if def.type_mapping.is_none() => def.iri.clone(),
+            _ => self.expand_prefixed(value),
+        }
+    }
+
+    fn compact_iri(&self, iri: &str, vocab: bool) -> String {
+        if vocab {
+            if let Some((term, _)) = self
+                .terms
+                .iter()
+                .find(|(_, def)| def.type_mapping.is_none() && def.iri == iri)
+            {
+                return term.clone();
+            }
+        }
+        self.prefixes
+            .iter()
+            .filter(|(_, base)| iri.starts_with(base.as_str()) && iri.len() > base.len())
+            .max_by_key(|(_, base)| base.len())
+            .map(|(prefix, base)| format!("{}:{}", prefix, &iri[base.len()..]))
+            .unwrap_or_else(|| iri.to_string())
+    }
+
+    fn expand_node(&self, node: &Value) -> Result<Value, JsonLdError> {
+        let obj = node
+            .as_object()
+            .ok_or_else(|| JsonLdError::InvalidNode("node must be an object".into()))?;
+        let mut out = Map::new();
+
+        for (key, value) in obj {
+            match self.keyword(key) {
+                "@context" => {}
+                "@id" => {
+                    let id = value
+                        .as_str()
+                        .ok_or_else(|| JsonLdError::InvalidNode("@id must be a string".into()))?;
+                    out.insert("@id".into(), Value::String(self.expand_prefixed(id)));
+                }
+                "@type" => {
+                    let types = as_list(value)
+                        .into_iter()
+                        .map(|t| {
+                            t.as_str()
+                                .map(|t| Value::String(self.expand_vocab(t)))
+                                .ok_or_else(|| JsonLdError::InvalidNode("@type must be a string".into()))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    out.insert("@type".into(), Value::Array(types));
+                }
+                _ => {
+                    let def = self
+                        .terms
+                        .get(key)
+                        .ok_or_else(|| JsonLdError::UnknownTerm(key.clone()))?;
+                    if value.is_null() {
+                        continue;
+                    }
+                    // @json-значения не разворачиваются как списки
+                    let values = if def.type_mapping.as_deref() == Some("@json") {
+                        vec![value]
+                    } else {
+                        as_list(value)
+                    };
+                    let expanded = values
+                        .into_iter()
+                        .map(|v| self.expand_value(def, v))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    out.insert(def.iri.clone(), Value::Array(expanded));
+                }
+            }
+        }
+
+        Ok(Value::Object(out))
+    }
+
+    fn expand_value(&self, def: &TermDefinition, value: &Value) -> Result<Value, JsonLdError> {
+        match def.type_mapping.as_deref() {
+            Some(mapping @ ("@id" | "@vocab")) => {
+                let s = value
+                    .as_str()
+                    .ok_or_else(|| JsonLdError::InvalidNode(format!("{} expects an IRI", def.iri)))?;
+                let iri = if mapping == "@vocab" {
+                    self.expand_vocab(s)
+                } else {
+                    self.expand_prefixed(s)
+                };
+                Ok(json!({ "@id": iri }))
+            }
+            Some(datatype) => Ok(json!({ "@value": value, "@type": datatype })),
+            None => Ok(json!({ "@value": value })),
+        }
+    }
+
+    fn compact_node(&self, node: &Value) -> Result<Value, JsonLdError> {
+        let obj = node
+            .as_object()
+            .ok_or_else(|| JsonLdError::InvalidNode("node must be an object".into()))?;
+        let mut out = Map::new();
+
+        for (key, values) in obj {
+            match key.as_str() {
+                "@id" => {
+                    let id = values
+                        .as_str()
+                        .ok_or_else(|| JsonLdError::InvalidNode("@id must be a string".into()))?;
+                    out.insert(self.alias_for("@id"), Value::String(self.compact_iri(id, false)));
+                }
+                "@type" => {
+                    let mut types = as_list(values)
+                        .into_iter()
+                        .filter_map(Value::as_str)
+                        .map(|t| Value::String(self.compact_iri(t, true)))
+                        .collect::<Vec<_>>();
+                    let value = if types.len() == 1 {
+                        types.remove(0)
+                    } else {
+                        Value::Array(types)
+                    };
+                    out.insert(self.alias_for("@type"), value);
+                }
+                iri => {
+                    let (term, def) = self
+                        .terms
+                        .iter()
+                        .find(|(_, def)| def.iri == iri)
+                        .ok_or_else(|| JsonLdError::UnknownTerm(iri.to_string()))?;
+                    let mut compacted = as_list(values)
+                        .into_iter()
+                        .map(|v| self.compact_value(def, v))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let value = if compacted.len() == 1 {
+                        compacted.remove(0)
+                    } else {
+                        Value::Array(compacted)
+                    };
+                    out.insert(term.clone(), value);
+                }
+            }
+        }
+
+        Ok(Value::Object(out))
+    }
+
+    fn compact_value(&self, def: &TermDefinition, value: &Value) -> Result<Value, JsonLdError> {
+        if let Some(iri) = value.get("@id").and_then(Value::as_str) {
+            let vocab = def.type_mapping.as_deref() == Some("@vocab");
+            return Ok(Value::String(self.compact_iri(iri, vocab)));
+        }
+        value
+            .get("@value")
+            .cloned()
+            .ok_or_else(|| JsonLdError::InvalidNode(format!("value of {} has no @value", def.iri)))
+    }
+}
+
+/// Контекст ontoCMS (разбирается один раз)
+pub fn ontocms_context() -> Result<&'static Context, JsonLdError> {
+    static CONTEXT: OnceLock<Context> = OnceLock::new();
+    if let Some(ctx) = CONTEXT.get() {
+        return Ok(ctx);
+    }
+    let ctx = Context::parse(&context_document()?)?;
+    Ok(CONTEXT.get_or_init(|| ctx))
+}
+
+/// Контекст как JSON-документ — то, что отдаётся по CONTEXT_IRI
+pub fn context_document() -> Result<Value, JsonLdError> {
+    serde_json::from_str(CONTEXT_DOCUMENT).map_err(|e| JsonLdError::InvalidContext(e.to_string()))
+}
+
+/// Событие в compacted form с контекстом ontoCMS
+pub fn compact_event(event: &OntoEvent) -> Value {
+    let mut doc = json!({
+        "@context": CONTEXT_IRI,
+        "id": format!("event:{}", event.id),
+        "type": "OntoEvent",
+        "profile_id": format!("profile:{}", event.profile_id.0),
+        "phase": format!("{:?}", event.phase),
+        "payload": event.payload,
+        "timestamp": event.timestamp,
+    });
+    if let Some(obj) = doc.as_object_mut() {
+        // Прежний числовой контекст — прежний термин: N-Quads и хеши старых событий не меняются
+        if event.social_context.is_legacy() {
+            obj.insert("social_proximity".into(), event.social_context.proximity.into());
+        } else {
+            obj.insert("social_context".into(), json!(event.social_context));
+        }
+        if let Some(hash) = &event.causal_hash {
+            obj.insert("causal_hash".into(), Value::String(hash.clone()));
+        }
+    }
+    doc
+}
+
+/// Событие в expanded form (полные IRI, без контекста)
+pub fn expand_event(event: &OntoEvent) -> Result<Value, JsonLdError> {
+    ontocms_context()?.expand(&compact_event(event))
+}
+
+/// Восстановление события из JSON-LD (compacted или expanded form)
+pub fn event_from_jsonld(doc: &Value) -> Result<OntoEvent, JsonLdError> {
+    let ctx = ontocms_context()?;
+    let expanded = if doc.is_array() { doc.clone() } else { ctx.expand(doc)? };
+    let compacted = ctx.compact(&expanded)?;
+
+    let mut node = match compacted {
+        Value::Object(node) if !node.contains_key("@graph") => node,
+        _ => return Err(JsonLdError::InvalidNode("expected exactly one event node".into())),
+    };
+    node.remove("@context");
+    if node.remove("type") != Some(Value::String("OntoEvent".into())) {
+        return Err(JsonLdError::InvalidNode("node is not an OntoEvent".into()));
+    }
+    strip_prefix(&mut node, "id", "event:")?;
+    strip_prefix(&mut node, "profile_id", "profile:")?;
+
+    serde_json::from_value(Value::Object(node)).map_err(|e| JsonLdError::InvalidNode(e.to_string()))
+}
+
+/// Свидетельство о вреде в compacted form
+pub fn compact_witness(witness: &HarmWitness) -> Value {
+    json!({
+        "@context": CONTEXT_IRI,
+        "id": format!("witness:{}", witness.id),
+        "type": "HarmWitness",
+        "detected_harm": format!("{:?}", witness.detected_harm),
+        "violating_event_id": format!("event:{}", witness.violating_event_id),
+        "causal_hash": witness.causal_hash,
+        "violating_phase": format!("{:?}", witness.violating_phase),
+        "witness_profile": format!("profile:{}", witness.witness_profile.0),
+        "timestamp": witness.timestamp,
+        "evidence": witness.evidence,
+        "signature": witness.signature,
+    })
+}
+
+/// Свидетельство о вреде в expanded form
+pub fn expand_witness(witness: &HarmWitness) -> Result<Value, JsonLdError> {
+    ontocms_context()?.expand(&compact_witness(witness))
+}
+
+/// Набор событий как один JSON-LD документ с @graph
+pub fn export_events<'a>(events: impl IntoIterator<Item = &'a OntoEvent>) -> Value {
+    let graph: Vec<Value> = events
+        .into_iter()
+        .map(|event| {
+            let mut node = compact_event(event);
+            if let Some(obj) = node.as_object_mut() {
+                obj.remove("@context");
+            }
+            node
+        })
+        .collect();
+    json!({ "@context": CONTEXT_IRI, "@graph": graph })
+}
+
+/// Набор событий как RDF N-Quads: строки отсортированы, порядок входа не влияет на результат
+pub fn events_to_nquads<'a>(
+    events: impl IntoIterator<Item = &'a OntoEvent>,
+    graph: Option<&str>,
+) -> Result<String, JsonLdError> {
+    let ctx = ontocms_context()?;
+    let mut quads = Vec::new();
+    for event in events {
+        quads.extend(ctx.to_nquads(&ctx.expand(&compact_event(event))?, graph)?);
+    }
+    quads.sort();
+    quads.dedup();
+    Ok(quads.into_iter().map(|q| q + "\n").collect())
+}
+
+/// Детерминированный хеш события — BLAKE3 от его N-Quads
+pub fn event_hash(event: &OntoEvent) -> Result<String, JsonLdError> {
+    let nquads = events_to_nquads([event], None)?;
+    Ok(format!("blake3:{}", blake3::hash(nquads.as_bytes()).to_hex()))
+}
+
+fn as_list(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    }
+}
+
+fn strip_prefix(node: &mut Map<String, Value>, key: &str, prefix: &str) -> Result<(), JsonLdError> {
+    let value = node
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| JsonLdError::InvalidNode(format!("missing '{}'", key)))?;
+    let stripped = value
+        .strip_prefix(prefix)
+        .ok_or_else(|| JsonLdError::InvalidNode(format!("'{}' is not a {} IRI", value, prefix)))?
+        .to_string();
+    node.insert(key.into(), Value::String(stripped));
+    Ok(())
+}
+
+fn object_term(value: &Value) -> Result<String, JsonLdError> {
+    if let Some(iri) = value.get("@id").and_then(Value::as_str) {
+        return Ok(iri_term(iri));
+    }
+
+    let literal = value
+        .get("@value")
+        .ok_or_else(|| JsonLdError::InvalidNode("value object without @value".into()))?;
+    let datatype = value.get("@type").and_then(Value::as_str);
+
+    let (lexical, datatype) = match (literal, datatype) {
+        (json, Some("@json")) => (canonical_json(json), Some(RDF_JSON)),
+        (Value::String(s), dt) => (s.clone(), dt),
+        (Value::Bool(b), dt) => (b.to_string(), dt.or(Some(XSD_BOOLEAN))),
+        (Value::Number(n), dt) => {
+            let default = if n.is_f64() { XSD_DOUBLE } else { XSD_INTEGER };
+            (n.to_string(), dt.or(Some(default)))
+        }
+        _ => return Err(JsonLdError::InvalidNode("unsupported literal".into())),
+    };
+
+    Ok(match datatype {
+        Some(dt) if dt != XSD_STRING => format!("\"{}\"^^{}", escape_literal(&lexical), iri_term(dt)),
+        _ => format!("\"{}\"", escape_literal(&lexical)),
+    })
+}
+
+/// IRI в N-Quads: символы, запрещённые в IRIREF (`<>"{}|^`, обратная кавычка, `\`, пробел и управляющие),
+/// записываются как `\uXXXX` — строка не ломается и не открывает новый терм
+fn iri_term(iri: &str) -> String {
+    let mut out = String::with_capacity(iri.len() + 2);
+    out.push('<');
+    for c in iri.chars() {
+        match c {
+            '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' | '\u{0}'..='\u{20}' => {
+                out.push_str(&format!("\\u{:04X}", c as u32))
+            }
+            _ => out.push(c),
+        }
+    }
+    out.push('>');
+    out
+}
+
+/// JSON-литерал в канонической форме JCS (RFC 8785): ключи по UTF-16, без пробелов, числа как в ECMAScript
+/// Хеш события не зависит от порядка ключей и записи чисел в исходном payload
+fn canonical_json(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            let members: Vec<String> = entries
+                .into_iter()
+                .map(|(key, value)| format!("{}:{}", Value::String(key.clone()), canonical_json(value)))
+                .collect();
+            format!("{{{}}}", members.join(","))
+        }
+        Value::Array(items) => format!("[{}]", items.iter().map(canonical_json).collect::<Vec<_>>().join(",")),
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if n.is_f64() => ecmascript_number(f),
+            _ => n.to_string(),
+        },
+        // Строки, true/false/null: экранирование serde_json совпадает с JCS
+        other => other.to_string(),
+    }
+}
+
+/// Кратчайшая запись числа, как `Number.prototype.toString`: 1.0 → 1, 1e21 → 1e+21
+fn ecmascript_number(f: f64) -> String {
+    if f == 0.0 {
+        return "0".into();
+    }
+    if (1e-6..1e21).contains(&f.abs()) {
+        return f.to_string();
+    }
+    let exponential = format!("{:e}", f);
+    match exponential.split_once('e') {
+        Some((mantissa, exponent)) if !exponent.starts_with('-') => format!("{}e+{}", mantissa, exponent),
+        _ => exponential,
+    }
+}
+
+fn escape_literal(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+```
@@ -0,0 +1,66 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// Закон I Онтогенеза: Действие → Ментальный профиль
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ontocms::core::activity_ledger::ProfileId;
+use ontocms::ethics::law1_attribution::{CachedProfileSource, ProfileSource};
+
+/// Реестр из одного профиля, считающий обращения
+struct CountingSource {
+    known: &'static str,
+    lookups: AtomicUsize,
+}
+
+impl CountingSource {
+    fn new(known: &'static str) -> Self {
+        Self {
+            known,
+            lookups: AtomicUsize::new(0),
+        }
+    }
+
+    fn lookups(&self) -> usize {
+        self.lookups.load(Ordering::SeqCst)
+    }
+}
+
+impl ProfileSource for CountingSource {
+    fn contains(&self, profile: &ProfileId) -> bool {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        profile.0 == self.known
+    }
+}
+
+#[test]
+fn found_profiles_are_served_from_cache() {
+    let cached = CachedProfileSource::new(CountingSource::new("Aries-Wood-Rabbit"));
+    let profile = ProfileId("Aries-Wood-Rabbit".into());
+    assert!(cached.contains(&profile));
+    assert!(cached.contains(&profile));
+    assert!(cached.contains(&profile));
+    assert_eq!(cached.source().lookups(), 1);
+}
+
+#[test]
+fn unknown_profiles_are_not_cached() {
+    let cached = CachedProfileSource::new(CountingSource::new("Aries-Wood-Rabbit"));
+    let stranger = ProfileId("Nobody-Of-Nowhere".into());
+    assert!(!cached.contains(&stranger));
+    assert!(!cached.contains(&stranger));
+    // Каждый неизвестный ID снова идёт в реестр: кеш не растёт от чужих ID
+    assert_eq!(cached.source().lookups(), 2);
+}
+
+#[test]
+fn invalidate_forces_a_new_lookup() {
+    let cached = CachedProfileSource::new(CountingSource::new("Aries-Wood-Rabbit"));
+    let profile = ProfileId("Aries-Wood-Rabbit".into());
+    assert!(cached.contains(&profile));
+    cached.invalidate();
+    assert!(cached.contains(&profile));
+    assert_eq!(cached.source().lookups(), 2);
+}
+```
@@ -0,0 +1,124 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Git Commits as OntoEvents (Onto-Profile / Onto-Phase attribution)
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::path::Path;
+
+use git2::{Repository, Signature};
+
+use ontocms::core::activity_ledger::{OntoPhase, ProfileId};
+use ontocms::core::local_mirror::LocalMirror;
+use ontocms::ethics::law1_attribution::ProfileSource;
+use ontocms::ethics::pipeline::EthicsPipeline;
+use ontocms::ontocoder::commit_event::{commit_event, pending_commit_event};
+use ontocms::ontocoder::git_history::commit_info;
+use ontocms::transport::json_ld;
+
+const RABBIT: &str = "Aries-Wood-Rabbit";
+const CHAIN_START: &str = "invariant-rabbit";
+
+struct Profiles;
+
+impl ProfileSource for Profiles {
+    fn contains(&self, profile: &ProfileId) -> bool {
+        profile.0 == RABBIT
+    }
+}
+
+/// Репозиторий с идентичностью автора (её читает `git var GIT_AUTHOR_IDENT`)
+fn repository(root: &Path) -> Repository {
+    let repository = Repository::init(root).unwrap();
+    let mut config = repository.config().unwrap();
+    config.set_str("user.name", "Alice").unwrap();
+    config.set_str("user.email", "alice@example.org").unwrap();
+    repository
+}
+
+fn commit(repository: &Repository, message: &str) {
+    let root = repository.workdir().unwrap();
+    std::fs::write(root.join("a.rs"), message).unwrap();
+    let mut index = repository.index().unwrap();
+    index.add_path(Path::new("a.rs")).unwrap();
+    index.write().unwrap();
+    let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("Alice", "alice@example.org").unwrap();
+    repository.commit(Some("HEAD"), &signature, &signature, message, &tree, &[]).unwrap();
+}
+
+fn pipeline() -> EthicsPipeline {
+    EthicsPipeline::new().with_profiles(Profiles)
+}
+
+#[test]
+fn first_commit_event_starts_from_the_profile_invariant() {
+    let repo_dir = tempfile::tempdir().unwrap();
+    let mirror_dir = tempfile::tempdir().unwrap();
+    repository(repo_dir.path());
+    let mirror = LocalMirror::new(&mirror_dir.path().to_string_lossy());
+
+    let message = "Add parser\n\nOnto-Profile: Aries-Wood-Rabbit\nOnto-Phase: Slow\n";
+    let pending =
+        pending_commit_event(repo_dir.path(), message, ProfileId(RABBIT.into()), OntoPhase::Slow, &mirror, CHAIN_START)
+            .unwrap();
+    assert_eq!(pending.commit.id, "0".repeat(40));
+    assert!(pending.commit.parents.is_empty());
+    assert_eq!(pending.commit.author.name, "Alice");
+    assert_eq!(pending.commit.profile.as_deref(), Some(RABBIT));
+    assert_eq!(pending.event.causal_hash.as_deref(), Some(CHAIN_START));
+    assert_eq!(pending.event.payload["summary"], "Add parser");
+    assert!(pipeline().enforce(&pending.event).is_ok());
+}
+
+#[test]
+fn pending_event_links_to_the_head_event() {
+    let repo_dir = tempfile::tempdir().unwrap();
+    let mirror_dir = tempfile::tempdir().unwrap();
+    let repository = repository(repo_dir.path());
+    let mirror = LocalMirror::new(&mirror_dir.path().to_string_lossy());
+    commit(&repository, "Add parser\n\nOnto-Profile: Aries-Wood-Rabbit\n");
+    let head = commit_info(repo_dir.path(), "HEAD").unwrap();
+    let head_event = commit_event(&head, ProfileId(RABBIT.into()), OntoPhase::Slow, &[], CHAIN_START);
+    mirror.persist(&head_event).unwrap();
+
+    let pending =
+        pending_commit_event(repo_dir.path(), "Add printer\n", ProfileId(RABBIT.into()), OntoPhase::Slow, &mirror, CHAIN_START)
+            .unwrap();
+    let expected = json_ld::event_hash(&head_event).unwrap();
+    assert_eq!(pending.commit.parents, [head.id]);
+    assert_eq!(pending.event.causal_hash.as_deref(), Some(expected.as_str()));
+    assert_eq!(pending.parent_events, [expected]);
+}
+
+#[test]
+fn gate_rejects_harmful_message_and_unknown_profile() {
+    let repo_dir = tempfile::tempdir().unwrap();
+    let mirror_dir = tempfile::tempdir().unwrap();
+    repository(repo_dir.path());
+    let mirror = LocalMirror::new(&mirror_dir.path().to_string_lossy());
+
+    let harmful = pending_commit_event(
+        repo_dir.path(),
+        "Override ethics for remote peers\n",
+        ProfileId(RABBIT.into()),
+        OntoPhase::Slow,
+        &mirror,
+        CHAIN_START,
+    )
+    .unwrap();
+    assert!(pipeline().enforce(&harmful.event).is_err());
+
+    let stranger = pending_commit_event(
+        repo_dir.path(),
+        "Add parser\n",
+        ProfileId("Nobody-Of-Nowhere".into()),
+        OntoPhase::Slow,
+        &mirror,
+        CHAIN_START,
+    )
+    .unwrap();
+    assert!(pipeline().enforce(&stranger.event).is_err());
+    // Проверка не создаёт коммит
+    assert!(Repository::open(repo_dir.path()).unwrap().head().is_err());
+}
+```
@@ -0,0 +1,82 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// Закон III — отзывные согласия: ключ субъекта, обработчик и время приёма
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use libp2p::identity::Keypair;
+use serde_json::json;
+
+use ontocms::core::activity_ledger::{OntoEvent, OntoPhase, ProfileId};
+use ontocms::core::key_registry::KeyRegistry;
+use ontocms::core::social_context::SocialContext;
+use ontocms::ethics::consent_registry::{ConsentError, ConsentGrant, ConsentRegistry, ConsentRevocation, NoConsents};
+use ontocms::ethics::harm_rules;
+use ontocms::ethics::law3_harm_witness::detect_all_harm;
+
+const PURPOSE: &str = "biometric_processing";
+
+fn subject() -> ProfileId {
+    ProfileId("Virgo-Water-Goat".into())
+}
+
+fn grantee() -> ProfileId {
+    ProfileId("Aries-Wood-Rabbit".into())
+}
+
+fn signed_grant(keypair: &Keypair) -> ConsentGrant {
+    let mut grant = ConsentGrant::new(subject(), grantee(), PURPOSE, "genesis".into());
+    grant.sign(keypair).unwrap();
+    grant
+}
+
+fn biometric_event(profile: ProfileId, consent_id: &str, timestamp: u64) -> OntoEvent {
+    OntoEvent {
+        id: "event-1".into(),
+        profile_id: profile,
+        phase: OntoPhase::Fast,
+        payload: json!({ "type": "scan", "biometric_hash": "ab12", "consent_id": consent_id }),
+        social_context: SocialContext::new(50),
+        causal_hash: Some("genesis".into()),
+        timestamp,
+    }
+}
+
+#[test]
+fn grant_needs_the_subjects_registered_key() {
+    let keypair = Keypair::generate_ed25519();
+    let mut registry = ConsentRegistry::new();
+    let result = registry.record_grant(signed_grant(&keypair));
+    assert!(matches!(result, Err(ConsentError::UnregisteredKey { .. })));
+
+    // Самоподписанное согласие не снимает проверку собственной цели
+    let rules = harm_rules::default_rules();
+    let event = signed_grant(&keypair).to_event();
+    assert!(!detect_all_harm(&event, rules, &NoConsents, &KeyRegistry::new()).is_empty());
+    let keys = KeyRegistry::new().with_key(subject(), &keypair.public().to_peer_id());
+    assert!(detect_all_harm(&event, rules, &NoConsents, &keys).is_empty());
+}
+
+#[test]
+fn consent_covers_only_its_grantee_until_revoked() {
+    let keypair = Keypair::generate_ed25519();
+    let keys = KeyRegistry::new().with_key(subject(), &keypair.public().to_peer_id());
+    let mut registry = ConsentRegistry::new().with_keys(keys.clone());
+    let grant = signed_grant(&keypair);
+    registry.record_grant(grant.clone()).unwrap();
+
+    let rules = harm_rules::default_rules();
+    let now = grant.granted_at + 1;
+    let by_grantee = biometric_event(grantee(), &grant.id, now);
+    assert!(detect_all_harm(&by_grantee, rules, &registry, &keys).is_empty());
+    let by_other = biometric_event(ProfileId("Leo-Metal-Dragon".into()), &grant.id, now);
+    assert!(!detect_all_harm(&by_other, rules, &registry, &keys).is_empty());
+
+    let mut revocation = ConsentRevocation::new(&grant, None).unwrap();
+    revocation.sign(&keypair).unwrap();
+    registry.record_revocation(revocation).unwrap();
+
+    // Событие «датировано» до отзыва — отзыв всё равно действует
+    let backdated = biometric_event(grantee(), &grant.id, grant.granted_at);
+    assert!(!detect_all_harm(&backdated, rules, &registry, &keys).is_empty());
+}
+```
@@ -0,0 +1,130 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Dependency License Audit (Cargo.lock, offline)
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::fs;
+use std::path::Path;
+
+use ontocms::ontocoder::dependency_audit::{audit_lockfile, AuditSources, DependencyStatus};
+use ontocms::ontocoder::license_registry::LicenseRegistry;
+
+const CRATES_IO: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn manifest(name: &str, version: &str, license_line: &str) -> String {
+    format!("[package]\nname = \"{}\"\nversion = \"{}\"\n{}\n", name, version, license_line)
+}
+
+/// Рабочее пространство с лицензией в [workspace.package] и vendor/ с зависимостями
+fn workspace(root: &Path) -> AuditSources {
+    write(
+        &root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"app\"]\n\n[workspace.package]\nlicense = \"GPL-3.0-only\"\n",
+    );
+    write(&root.join("app/Cargo.toml"), &manifest("app", "0.1.0", "license.workspace = true"));
+    write(&root.join("vendor/permissive-1.2.0/Cargo.toml"), &manifest("permissive", "1.2.0", "license = \"MIT OR Apache-2.0\""));
+    write(&root.join("vendor/hosted/Cargo.toml"), &manifest("hosted", "2.0.0", "license = \"SSPL-1.0\""));
+    write(&root.join("vendor/custom-0.3.0/Cargo.toml"), &manifest("custom", "0.3.0", "license-file = \"LICENSE.txt\""));
+    // Манифест другой версии не подходит
+    write(&root.join("vendor/stale/Cargo.toml"), &manifest("stale", "0.9.0", "license = \"MIT\""));
+    write(
+        &root.join("Cargo.lock"),
+        &format!(
+            r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["permissive", "hosted 2.0.0", "custom", "stale"]
+
+[[package]]
+name = "permissive"
+version = "1.2.0"
+source = "{src}"
+checksum = "abc123"
+
+[[package]]
+name = "hosted"
+version = "2.0.0"
+source = "{src}"
+
+[[package]]
+name = "custom"
+version = "0.3.0"
+source = "{src}"
+
+[[package]]
+name = "stale"
+version = "1.0.0"
+source = "{src}"
+"#,
+            src = CRATES_IO
+        ),
+    );
+    AuditSources {
+        workspace_root: root.to_path_buf(),
+        vendor_dir: Some(root.join("vendor")),
+        registry_dirs: vec![],
+    }
+}
+
+#[test]
+fn lockfile_packages_are_classified_by_their_manifests() {
+    let dir = tempfile::tempdir().unwrap();
+    let sources = workspace(dir.path());
+    let audit = audit_lockfile(&dir.path().join("Cargo.lock"), &sources, &LicenseRegistry::new()).unwrap();
+    let status = |name: &str| audit.dependencies.iter().find(|d| d.name == name).unwrap().status;
+
+    // Лицензия члена рабочего пространства наследуется из [workspace.package]
+    assert_eq!(status("app"), DependencyStatus::Compatible);
+    assert_eq!(status("permissive"), DependencyStatus::Compatible);
+    assert_eq!(status("hosted"), DependencyStatus::Incompatible);
+    assert_eq!(status("custom"), DependencyStatus::Unresolved);
+    assert_eq!(status("stale"), DependencyStatus::Unresolved);
+    assert!(!audit.is_clean());
+    assert_eq!(audit.count(DependencyStatus::Unresolved), 2);
+
+    let custom = audit.dependencies.iter().find(|d| d.name == "custom").unwrap();
+    assert!(custom.note.as_deref().unwrap().contains("LICENSE.txt"));
+}
+
+#[test]
+fn spdx_document_carries_checksums_and_dependency_graph() {
+    let dir = tempfile::tempdir().unwrap();
+    let sources = workspace(dir.path());
+    let audit = audit_lockfile(&dir.path().join("Cargo.lock"), &sources, &LicenseRegistry::new()).unwrap();
+    let doc = audit.to_spdx("workspace");
+
+    let permissive = doc.packages.iter().find(|p| p.name == "permissive").unwrap();
+    assert_eq!(permissive.checksums[0].checksum_value, "abc123");
+    assert_eq!(permissive.download_location, "https://crates.io/api/v1/crates/permissive/1.2.0/download");
+    let hosted = doc.packages.iter().find(|p| p.name == "hosted").unwrap();
+    assert_eq!(hosted.license_concluded, "NOASSERTION");
+
+    let relations: Vec<(&str, &str)> = doc
+        .relationships
+        .iter()
+        .filter(|r| r.relationship_type == "DEPENDS_ON")
+        .map(|r| (r.spdx_element_id.as_str(), r.related_spdx_element.as_str()))
+        .collect();
+    // "name" и "name version" разрешаются по Cargo.lock, даже если манифест зависимости не найден
+    assert_eq!(
+        relations,
+        [
+            ("SPDXRef-Package-app-0.1.0", "SPDXRef-Package-permissive-1.2.0"),
+            ("SPDXRef-Package-app-0.1.0", "SPDXRef-Package-hosted-2.0.0"),
+            ("SPDXRef-Package-app-0.1.0", "SPDXRef-Package-custom-0.3.0"),
+            ("SPDXRef-Package-app-0.1.0", "SPDXRef-Package-stale-1.0.0"),
+        ]
+    );
+    assert!(doc
+        .relationships
+        .iter()
+        .any(|r| r.relationship_type == "DESCRIBES" && r.related_spdx_element.contains("app")));
+}
+```
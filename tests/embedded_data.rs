@@ -0,0 +1,90 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// Встроенные данные (include_str!) и правила Закона III
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::path::Path;
+
+use serde_json::json;
+
+use ontocms::ethics::harm_rules::HarmRuleSet;
+use ontocms::forms::event_schema_registry::EventSchemaRegistry;
+use ontocms::ontocoder::config::{ConfigFile, ProjectConfig};
+use ontocms::ontocoder::license_registry::LicenseRegistry;
+use ontocms::ontocoder::term_policy::TermPolicy;
+
+#[test]
+fn builtin_harm_rules_are_valid() {
+    let rules = HarmRuleSet::builtin().expect("harm_rules.v2.yaml");
+    assert!(!rules.rule_ids().is_empty());
+}
+
+#[test]
+fn builtin_event_schemas_are_valid() {
+    let schemas = EventSchemaRegistry::builtin().expect("forms/schemas");
+    assert!(schemas.latest_version("harm_witness").is_some());
+}
+
+#[test]
+fn default_config_globs_are_valid() {
+    assert!(ProjectConfig::new(Path::new("."), ConfigFile::default()).is_ok());
+}
+
+#[test]
+fn builtin_license_tables_are_valid() {
+    let registry = LicenseRegistry::builtin().expect("policy/spdx_licenses.yaml, policy/sgcl_compatibility.v1.yaml");
+    assert!(registry.entry("GPL-3.0-only").is_some_and(|e| e.compatible_with_sgcl));
+}
+
+#[test]
+fn builtin_term_policy_is_valid() {
+    let policy = TermPolicy::builtin().expect("policy/term_policy.v1.yaml");
+    assert!(!policy.config().terms.is_empty());
+}
+
+#[test]
+fn text_words_match_identifiers() {
+    let policy = TermPolicy::builtin().unwrap();
+    let hits = policy.check_text("underscore: 1\n9scores = _monetized");
+    let matched: Vec<&str> = hits.iter().map(|v| v.matched.as_str()).collect();
+    assert_eq!(matched, ["scores", "monetized"]);
+    assert_eq!((hits[0].span.start_line, hits[0].span.start_column), (2, 1));
+}
+
+#[test]
+fn term_policy_allows_listed_symbols_not_phrases() {
+    let policy = TermPolicy::builtin().unwrap();
+    let symbols = |source: &str| -> Vec<String> {
+        let mut symbols: Vec<String> = policy.check_rust(source).unwrap().into_iter().map(|v| v.symbol).collect();
+        symbols.dedup(); // по нарушению на каждый термин
+        symbols
+    };
+    // Исключение — точное имя, а не фраза: то же слово в другом типе нарушает политику
+    assert!(symbols("pub enum HarmType { EnergyValueInjection }").is_empty());
+    assert_eq!(symbols("pub enum Alert { EnergyValueInjection }"), ["Alert::EnergyValueInjection"]);
+    assert!(symbols("pub struct HeaderTag { pub value: String }").is_empty());
+    assert_eq!(symbols("pub struct Reading { pub value: u8 }"), ["Reading::value"]);
+}
+
+#[test]
+fn regex_allow_only_clears_the_covered_match() {
+    let rules = HarmRuleSet::from_yaml_str(
+        r#"
+version: 2
+rules:
+  - id: tracking
+    harm: AENGAViolation
+    regex: ["(?i)track(ing)?"]
+    allow: ["opt in tracking"]
+"#,
+    )
+    .unwrap();
+
+    // Разрешённая фраза покрывает совпадение
+    assert!(rules.evaluate(&json!({ "note": "opt-in tracking" })).is_empty());
+    // Та же фраза в тексте не снимает совпадение в другом месте
+    let hits = rules.evaluate(&json!({ "note": "opt-in tracking, then track everyone" }));
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].matched, "track");
+}
+```
@@ -0,0 +1,147 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Local Git History (last change per file)
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::path::Path;
+
+use git2::{Repository, Signature};
+
+use ontocms::ontocoder::git_history::{commit_info, install_hooks, GitHistory, HookInstall, HOOK_MARKER};
+
+/// Коммит всех файлов рабочего дерева от имени `author`
+fn commit_all(repository: &Repository, author: &str, message: &str) -> git2::Oid {
+    let mut index = repository.index().unwrap();
+    index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+    index.write().unwrap();
+    let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now(author, &format!("{}@example.org", author.to_lowercase())).unwrap();
+    let parents: Vec<git2::Commit> = repository.head().ok().and_then(|h| h.peel_to_commit().ok()).into_iter().collect();
+    let parents: Vec<&git2::Commit> = parents.iter().collect();
+    repository
+        .commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+        .unwrap()
+}
+
+fn write(root: &Path, file: &str, content: &str) {
+    std::fs::write(root.join(file), content).unwrap();
+}
+
+#[test]
+fn last_change_reads_author_and_did_trailer() {
+    let dir = tempfile::tempdir().unwrap();
+    let repository = Repository::init(dir.path()).unwrap();
+    write(dir.path(), "a.rs", "fn a() {}\n");
+    write(dir.path(), "b.rs", "fn b() {}\n");
+    commit_all(&repository, "Alice", "Add a and b\n\nContributor-DID: did:key:z6MkAlice\n");
+    write(dir.path(), "b.rs", "fn b() { }\n");
+    let second = commit_all(&repository, "Bob", "Touch b\n");
+
+    let history = GitHistory::load(dir.path()).unwrap();
+    let a = history.last_change(&dir.path().join("a.rs")).unwrap();
+    assert_eq!(a.author.name, "Alice");
+    assert_eq!(a.author.did.as_deref(), Some("did:key:z6MkAlice"));
+    assert!(a.date.contains('T'), "ISO 8601 date: {}", a.date);
+    let b = history.last_change(&dir.path().join("b.rs")).unwrap();
+    assert_eq!(b.commit, second.to_string());
+    assert_eq!(b.author.did, None);
+    assert!(history.last_change(&dir.path().join("c.rs")).is_none());
+}
+
+#[test]
+fn commit_info_reads_trailers_like_pending_commits() {
+    let dir = tempfile::tempdir().unwrap();
+    let repository = Repository::init(dir.path()).unwrap();
+    write(dir.path(), "a.rs", "fn a() {}\n");
+    let id = commit_all(
+        &repository,
+        "Alice",
+        "Add a\n\nOnto-Profile: Aries-Wood-Rabbit\nonto-phase: Slow\n",
+    );
+
+    let info = commit_info(dir.path(), "HEAD").unwrap();
+    assert_eq!(info.id, id.to_string());
+    assert!(info.parents.is_empty());
+    assert_eq!(info.summary, "Add a");
+    assert_eq!(info.profile.as_deref(), Some("Aries-Wood-Rabbit"));
+    assert_eq!(info.phase.as_deref(), Some("Slow"));
+}
+
+#[test]
+fn empty_repository_has_no_history() {
+    let dir = tempfile::tempdir().unwrap();
+    Repository::init(dir.path()).unwrap();
+    write(dir.path(), "a.rs", "fn a() {}\n");
+    let history = GitHistory::load(dir.path()).unwrap();
+    assert!(history.last_change(&dir.path().join("a.rs")).is_none());
+}
+
+#[test]
+fn hooks_are_installed_where_git_looks_for_them() {
+    let dir = tempfile::tempdir().unwrap();
+    let repository = Repository::init(dir.path()).unwrap();
+    repository.config().unwrap().set_str("core.hooksPath", ".githooks").unwrap();
+
+    let installed = install_hooks(dir.path(), "Aries-Wood-Rabbit", "Slow", false).unwrap();
+    assert_eq!(
+        installed,
+        [
+            HookInstall::Written(dir.path().join(".githooks/pre-commit")),
+            HookInstall::Written(dir.path().join(".githooks/commit-msg")),
+        ]
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(dir.path().join(".githooks/commit-msg")).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+}
+
+#[test]
+fn foreign_hook_is_kept_unless_forced() {
+    let dir = tempfile::tempdir().unwrap();
+    Repository::init(dir.path()).unwrap();
+    let pre_commit = dir.path().join(".git/hooks/pre-commit");
+    std::fs::create_dir_all(pre_commit.parent().unwrap()).unwrap();
+    std::fs::write(&pre_commit, "#!/bin/sh\nexit 0\n").unwrap();
+
+    let installed = install_hooks(dir.path(), "Aries-Wood-Rabbit", "Slow", false).unwrap();
+    assert_eq!(installed[0], HookInstall::Kept(pre_commit.clone()));
+    assert_eq!(std::fs::read_to_string(&pre_commit).unwrap(), "#!/bin/sh\nexit 0\n");
+
+    // Свой хук обновляется без --force: новый профиль попадает в commit-msg
+    install_hooks(dir.path(), "Leo-Fire-Horse", "Heyday", false).unwrap();
+    let commit_msg = std::fs::read_to_string(dir.path().join(".git/hooks/commit-msg")).unwrap();
+    assert!(commit_msg.contains("Onto-Profile: Leo-Fire-Horse"));
+
+    let forced = install_hooks(dir.path(), "Aries-Wood-Rabbit", "Slow", true).unwrap();
+    assert_eq!(forced[0], HookInstall::Written(pre_commit.clone()));
+    assert!(std::fs::read_to_string(&pre_commit).unwrap().contains(HOOK_MARKER));
+}
+
+#[cfg(unix)]
+#[test]
+fn commit_msg_hook_adds_default_trailers_and_keeps_explicit_ones() {
+    let dir = tempfile::tempdir().unwrap();
+    Repository::init(dir.path()).unwrap();
+    install_hooks(dir.path(), "Aries-Wood-Rabbit", "Slow", false).unwrap();
+    let hook = dir.path().join(".git/hooks/commit-msg");
+
+    let run = |message: &str| {
+        let file = dir.path().join("COMMIT_EDITMSG");
+        std::fs::write(&file, message).unwrap();
+        let status = std::process::Command::new(&hook).arg(&file).current_dir(dir.path()).status().unwrap();
+        assert!(status.success());
+        std::fs::read_to_string(&file).unwrap()
+    };
+    let plain = run("Add parser\n");
+    assert!(plain.contains("Onto-Profile: Aries-Wood-Rabbit\n"), "{}", plain);
+    assert!(plain.contains("Onto-Phase: Slow\n"), "{}", plain);
+
+    let own = run("Add parser\n\nOnto-Phase: Fast\n");
+    assert!(own.contains("Onto-Phase: Fast\n"), "{}", own);
+    assert!(!own.contains("Onto-Phase: Slow"), "{}", own);
+    assert!(own.contains("Onto-Profile: Aries-Wood-Rabbit\n"), "{}", own);
+}
+```
@@ -0,0 +1,158 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Git History Ingest (incremental, with checkpoint)
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use git2::{Repository, Signature};
+
+use ontocms::core::activity_ledger::ProfileId;
+use ontocms::core::local_mirror::LocalMirror;
+use ontocms::ethics::law1_attribution::ProfileSource;
+use ontocms::ethics::pipeline::EthicsPipeline;
+use ontocms::ontocoder::git_ingest::{GitIngest, IngestOutcome, CHECKPOINT};
+use ontocms::transport::json_ld;
+
+const RABBIT: &str = "Aries-Wood-Rabbit";
+const HORSE: &str = "Leo-Fire-Horse";
+
+struct Profiles;
+
+impl ProfileSource for Profiles {
+    fn contains(&self, profile: &ProfileId) -> bool {
+        profile.0 == RABBIT || profile.0 == HORSE
+    }
+}
+
+fn chain_starts() -> HashMap<String, String> {
+    HashMap::from([
+        (RABBIT.to_string(), "invariant-rabbit".to_string()),
+        (HORSE.to_string(), "invariant-horse".to_string()),
+    ])
+}
+
+/// Коммит файла `file` поверх HEAD
+fn commit(repository: &Repository, file: &str, message: &str) -> String {
+    let root = repository.workdir().unwrap();
+    std::fs::write(root.join(file), message).unwrap();
+    let mut index = repository.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("Alice", "alice@example.org").unwrap();
+    let parent = repository.head().ok().map(|head| head.peel_to_commit().unwrap());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repository
+        .commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+        .unwrap()
+        .to_string()
+}
+
+fn attributed(summary: &str, profile: &str) -> String {
+    format!("{}\n\nOnto-Profile: {}\n", summary, profile)
+}
+
+fn outcomes(report: &ontocms::ontocoder::git_ingest::IngestReport) -> Vec<IngestOutcome> {
+    report.commits.iter().map(|(_, outcome)| outcome.clone()).collect()
+}
+
+#[test]
+fn unattributed_root_commit_does_not_pin_checkpoint() {
+    let repo_dir = tempfile::tempdir().unwrap();
+    let mirror_dir = tempfile::tempdir().unwrap();
+    let repository = Repository::init(repo_dir.path()).unwrap();
+    commit(&repository, "README.md", "Initial import\n");
+    let child = commit(&repository, "a.rs", &attributed("Add parser", RABBIT));
+
+    let mirror = LocalMirror::new(&mirror_dir.path().to_string_lossy());
+    let pipeline = EthicsPipeline::new().with_profiles(Profiles);
+    let ingest = GitIngest::new(&mirror, &pipeline, chain_starts());
+    let report = ingest.run(repo_dir.path(), "HEAD", false).unwrap();
+
+    assert_eq!(
+        outcomes(&report),
+        vec![IngestOutcome::Unattributed, IngestOutcome::Ingested(ProfileId(RABBIT.into()))]
+    );
+    assert_eq!(report.checkpoint.as_deref(), Some(child.as_str()));
+    assert_eq!(mirror.checkpoint(CHECKPOINT).as_deref(), Some(child.as_str()));
+    // У родителя нет события: цепочка начинается с инварианта профиля
+    let event = mirror.find_event(&child).unwrap();
+    assert_eq!(event.causal_hash.as_deref(), Some("invariant-rabbit"));
+    assert!(event.payload.get("parent_events").is_none());
+
+    let again = ingest.run(repo_dir.path(), "HEAD", false).unwrap();
+    assert!(again.commits.is_empty());
+}
+
+#[test]
+fn commit_after_unattributed_parent_links_to_nearest_event() {
+    let repo_dir = tempfile::tempdir().unwrap();
+    let mirror_dir = tempfile::tempdir().unwrap();
+    let repository = Repository::init(repo_dir.path()).unwrap();
+    let first = commit(&repository, "a.rs", &attributed("Add parser", RABBIT));
+    commit(&repository, "b.rs", "Vendor fixtures\n");
+    let third = commit(&repository, "c.rs", &attributed("Add printer", RABBIT));
+
+    let mirror = LocalMirror::new(&mirror_dir.path().to_string_lossy());
+    let pipeline = EthicsPipeline::new().with_profiles(Profiles);
+    GitIngest::new(&mirror, &pipeline, chain_starts())
+        .run(repo_dir.path(), "HEAD", false)
+        .unwrap();
+
+    let nearest = json_ld::event_hash(&mirror.find_event(&first).unwrap()).unwrap();
+    let event = mirror.find_event(&third).unwrap();
+    assert_eq!(event.causal_hash.as_deref(), Some(nearest.as_str()));
+    assert_eq!(event.payload["parent_events"], serde_json::json!([nearest]));
+}
+
+#[test]
+fn rejected_commit_pins_checkpoint_and_is_retried() {
+    let repo_dir = tempfile::tempdir().unwrap();
+    let mirror_dir = tempfile::tempdir().unwrap();
+    let repository = Repository::init(repo_dir.path()).unwrap();
+    let first = commit(&repository, "a.rs", &attributed("Add parser", RABBIT));
+    commit(&repository, "b.rs", "Add lexer\n\nOnto-Profile: Aries-Wood-Rabbit\nOnto-Phase: Sideways\n");
+    commit(&repository, "c.rs", &attributed("Add printer", RABBIT));
+
+    let mirror = LocalMirror::new(&mirror_dir.path().to_string_lossy());
+    let pipeline = EthicsPipeline::new().with_profiles(Profiles);
+    let ingest = GitIngest::new(&mirror, &pipeline, chain_starts());
+    let report = ingest.run(repo_dir.path(), "HEAD", false).unwrap();
+    let outcomes = outcomes(&report);
+    assert!(matches!(outcomes[1], IngestOutcome::Rejected(_)));
+    assert_eq!(outcomes[2], IngestOutcome::Ingested(ProfileId(RABBIT.into())));
+    assert_eq!(report.checkpoint.as_deref(), Some(first.as_str()));
+
+    // Отклонённый коммит и всё после него проходятся снова; записанное не дублируется
+    let again = ingest.run(repo_dir.path(), "HEAD", false).unwrap();
+    assert_eq!(again.commits.len(), 2);
+    assert!(matches!(again.commits[0].1, IngestOutcome::Rejected(_)));
+    assert_eq!(again.commits[1].1, IngestOutcome::Known);
+}
+
+#[test]
+fn unsaved_event_pins_checkpoint() {
+    let repo_dir = tempfile::tempdir().unwrap();
+    let mirror_dir = tempfile::tempdir().unwrap();
+    let repository = Repository::init(repo_dir.path()).unwrap();
+    let first = commit(&repository, "a.rs", &attributed("Add parser", RABBIT));
+    let second = commit(&repository, "b.rs", &attributed("Add lexer", HORSE));
+
+    let mirror = LocalMirror::new(&mirror_dir.path().to_string_lossy());
+    // Файл на месте каталога профиля: событие не записать
+    let blocked = mirror_dir.path().join("local_mirror").join(HORSE);
+    std::fs::write(&blocked, "").unwrap();
+    let pipeline = EthicsPipeline::new().with_profiles(Profiles);
+    let ingest = GitIngest::new(&mirror, &pipeline, chain_starts());
+    let report = ingest.run(repo_dir.path(), "HEAD", false).unwrap();
+    assert!(matches!(report.commits[1].1, IngestOutcome::NotPersisted(_)));
+    assert_eq!(report.checkpoint.as_deref(), Some(first.as_str()));
+
+    std::fs::remove_file(&blocked).unwrap();
+    let again = ingest.run(repo_dir.path(), "HEAD", false).unwrap();
+    assert_eq!(outcomes(&again), vec![IngestOutcome::Ingested(ProfileId(HORSE.into()))]);
+    assert_eq!(again.checkpoint.as_deref(), Some(second.as_str()));
+}
+```
@@ -0,0 +1,157 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// Закон III — свидетельства о вреде и ключи свидетелей
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use libp2p::identity::Keypair;
+use serde_json::json;
+
+use ontocms::core::activity_ledger::{ActivityLedger, OntoEvent, OntoPhase, ProfileId};
+use ontocms::core::key_registry::KeyRegistry;
+use ontocms::core::local_mirror::LocalMirror;
+use ontocms::core::social_context::SocialContext;
+use ontocms::ethics::consent_registry::NoConsents;
+use ontocms::ethics::harm_rules;
+use ontocms::ethics::law3_harm_witness::{detect_all_harm, generate_harm_witness, HarmType, HarmWitness};
+use ontocms::ethics::pipeline::EthicsPipeline;
+
+fn violating_event() -> OntoEvent {
+    OntoEvent {
+        id: "event-1".into(),
+        profile_id: ProfileId("Aries-Wood-Rabbit".into()),
+        phase: OntoPhase::Fast,
+        payload: json!({ "type": "metrics", "energy_level": 7 }),
+        social_context: SocialContext::new(50),
+        causal_hash: Some("genesis".into()),
+        timestamp: 1_700_000_000_000,
+    }
+}
+
+#[test]
+fn witness_evidence_is_trusted_only_with_registered_key() {
+    let witness_profile = ProfileId("Taurus-Fire-Horse".into());
+    let keypair = Keypair::generate_ed25519();
+    let mut witness =
+        generate_harm_witness(HarmType::EnergyValueInjection, &violating_event(), witness_profile.clone()).unwrap();
+    witness.sign(&keypair).unwrap();
+    let event = witness.to_event().unwrap();
+
+    // Ключ встроен в запись: без регистрации доказательства сканируются
+    let rules = harm_rules::default_rules();
+    assert!(!detect_all_harm(&event, rules, &NoConsents, &KeyRegistry::new()).is_empty());
+
+    let keys = KeyRegistry::new().with_key(witness_profile, &keypair.public().to_peer_id());
+    assert!(detect_all_harm(&event, rules, &NoConsents, &keys).is_empty());
+
+    // Ключ чужого профиля не подходит
+    let other = KeyRegistry::new().with_key(ProfileId("Gemini-Earth-Tiger".into()), &keypair.public().to_peer_id());
+    assert!(!detect_all_harm(&event, rules, &NoConsents, &other).is_empty());
+}
+
+#[test]
+fn ledger_records_witnesses_through_the_pipeline() {
+    let witness_profile = ProfileId("Taurus-Fire-Horse".into());
+    let keypair = Keypair::generate_ed25519();
+    let mut witness =
+        generate_harm_witness(HarmType::EnergyValueInjection, &violating_event(), witness_profile.clone()).unwrap();
+    let mut ledger = ActivityLedger::new();
+
+    // Неподписанное свидетельство не проходит схему harm_witness v2
+    assert!(ledger.record_witness(&witness, &EthicsPipeline::new()).is_err());
+    witness.sign(&keypair).unwrap();
+    // Подписанное незарегистрированным ключом: доказательства сканируются как обычный payload
+    assert!(ledger.record_witness(&witness, &EthicsPipeline::new()).is_err());
+    assert!(ledger.witness(&witness.id).is_none());
+
+    let keys = KeyRegistry::new().with_key(witness_profile, &keypair.public().to_peer_id());
+    ledger.record_witness(&witness, &EthicsPipeline::new().with_keys(keys)).unwrap();
+    assert_eq!(ledger.witnesses_by_harm(&HarmType::EnergyValueInjection).len(), 1);
+}
+
+#[test]
+fn rewrapped_witness_is_not_trusted() {
+    let witness_profile = ProfileId("Taurus-Fire-Horse".into());
+    let keypair = Keypair::generate_ed25519();
+    let mut witness =
+        generate_harm_witness(HarmType::EnergyValueInjection, &violating_event(), witness_profile.clone()).unwrap();
+    witness.sign(&keypair).unwrap();
+    let keys = KeyRegistry::new().with_key(witness_profile, &keypair.public().to_peer_id());
+    let rules = harm_rules::default_rules();
+
+    // Подпись покрывает только payload: конверт должен совпадать с подписанной записью
+    let mut other_profile = witness.to_event().unwrap();
+    other_profile.profile_id = ProfileId("Gemini-Earth-Tiger".into());
+    let mut other_cause = witness.to_event().unwrap();
+    other_cause.causal_hash = Some("genesis".into());
+    let mut other_time = witness.to_event().unwrap();
+    other_time.timestamp += 1;
+    for event in [other_profile, other_cause, other_time] {
+        assert!(HarmWitness::from_event(&event).is_none());
+        assert!(!detect_all_harm(&event, rules, &NoConsents, &keys).is_empty());
+    }
+}
+
+#[test]
+fn ledger_indexes_registered_witnesses_once() {
+    let witness_profile = ProfileId("Taurus-Fire-Horse".into());
+    let keypair = Keypair::generate_ed25519();
+    // Без доказательств: такое свидетельство проходит конвейер и с чужим ключом
+    let mut witness =
+        generate_harm_witness(HarmType::AENGAViolation, &violating_event(), witness_profile.clone()).unwrap();
+    witness.sign(&keypair).unwrap();
+
+    let mut ledger = ActivityLedger::new();
+    ledger.record_witness(&witness, &EthicsPipeline::new()).unwrap();
+    assert!(ledger.get(&witness.id).is_some());
+    assert!(ledger.witness(&witness.id).is_none());
+
+    let keys = KeyRegistry::new().with_key(witness_profile.clone(), &keypair.public().to_peer_id());
+    let pipeline = EthicsPipeline::new().with_keys(keys);
+    ledger.record_witness(&witness, &pipeline).unwrap();
+    ledger.record_witness(&witness, &pipeline).unwrap();
+    assert_eq!(ledger.witnesses_by_harm(&HarmType::AENGAViolation).len(), 1);
+    assert_eq!(ledger.events_by_profile(&witness_profile).len(), 1);
+}
+
+#[test]
+fn harm_type_names_are_scanned_outside_trusted_records() {
+    let event = OntoEvent {
+        payload: json!({ "type": "metrics", "note": "energy value injection" }),
+        ..violating_event()
+    };
+    let matches = detect_all_harm(&event, harm_rules::default_rules(), &NoConsents, &KeyRegistry::new());
+    assert!(matches.iter().any(|m| m.harm == HarmType::EnergyValueInjection));
+}
+
+#[test]
+fn unreadable_outbox_file_does_not_hide_other_witnesses() {
+    let dir = tempfile::tempdir().unwrap();
+    let mirror = LocalMirror::new(&dir.path().to_string_lossy());
+    let mut witness =
+        generate_harm_witness(HarmType::EnergyValueInjection, &violating_event(), ProfileId("Taurus-Fire-Horse".into()))
+            .unwrap();
+    witness.sign(&Keypair::generate_ed25519()).unwrap();
+    mirror.record_witness(&witness.to_event().unwrap()).unwrap();
+    std::fs::write(dir.path().join("local_mirror/.outbox/partial.json"), "{\"event\":").unwrap();
+
+    let status = mirror.outbox_status().unwrap();
+    assert_eq!(status.entries.len(), 1);
+    assert_eq!(status.unreadable.len(), 1);
+    assert_eq!(mirror.due_witnesses(u64::MAX).unwrap().len(), 1);
+}
+
+#[test]
+fn witness_is_not_recorded_when_the_mirror_cannot_write_it() {
+    let dir = tempfile::tempdir().unwrap();
+    let mirror = LocalMirror::new(&dir.path().to_string_lossy());
+    // Файл на месте каталога профиля: событие записать нельзя
+    std::fs::write(dir.path().join("local_mirror/Taurus-Fire-Horse"), "").unwrap();
+    let mut witness =
+        generate_harm_witness(HarmType::EnergyValueInjection, &violating_event(), ProfileId("Taurus-Fire-Horse".into()))
+            .unwrap();
+    witness.sign(&Keypair::generate_ed25519()).unwrap();
+
+    assert!(mirror.record_witness(&witness.to_event().unwrap()).is_err());
+    assert!(mirror.outbox_status().unwrap().entries.is_empty());
+}
+```
@@ -0,0 +1,178 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — File Header Parsing per Language
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::path::Path;
+
+use ontocms::core::activity_ledger::ProfileId;
+use ontocms::ontocoder::header::{FileHeader, HeaderTag, Language};
+use ontocms::ontocoder::license_registry::LicenseRegistry;
+use ontocms::ontocoder::report::rules;
+use ontocms::ontocoder::suppression::parse_suppressions;
+use ontocms::ontocoder::validator::OntoValidator;
+
+fn header(path: &str, content: &str) -> FileHeader {
+    FileHeader::of_file(Path::new(path), content)
+}
+
+fn value(tag: &Option<HeaderTag>) -> Option<&str> {
+    tag.as_ref().map(|t| t.value.as_str())
+}
+
+/// Значение тега совпадает с текстом файла по его span
+fn assert_span(content: &str, tag: &Option<HeaderTag>) {
+    let tag = tag.as_ref().expect("tag present");
+    assert_eq!(&content[tag.span.start_byte..tag.span.end_byte], tag.value);
+}
+
+#[test]
+fn rust_line_and_block_comments() {
+    let content = "// SPDX-License-Identifier: GPL-3.0-only\n/*\n * ONTO-PROFILE: Aries-Wood-Rabbit\n * ONTO-PHASE: Slow */\nfn main() {}\n";
+    let h = header("src/main.rs", content);
+    assert_eq!(h.language, Some(Language::Rust));
+    assert_eq!(h.license(), "GPL-3.0-only");
+    assert_eq!(value(&h.profile), Some("Aries-Wood-Rabbit"));
+    assert_eq!(value(&h.phase), Some("Slow"));
+    assert_span(content, &h.phase);
+}
+
+#[test]
+fn rust_header_ends_at_first_item() {
+    let content = "// SPDX-License-Identifier: MIT\nuse std::fmt;\n// ONTO-PROFILE: Aries-Wood-Rabbit\n";
+    let h = header("lib.rs", content);
+    assert_eq!(h.license(), "MIT");
+    assert!(h.profile.is_none());
+}
+
+#[test]
+fn python_shebang_comments_and_docstring() {
+    let content = "#!/usr/bin/env python3\n# -*- coding: utf-8 -*-\n# SPDX-License-Identifier: Apache-2.0\n\"\"\"Importer.\n\nONTO-PROFILE: Leo-Fire-Horse\nONTO-PHASE: Heyday\"\"\"\nimport os\n";
+    let h = header("tools/import.py", content);
+    assert_eq!(h.language, Some(Language::Python));
+    assert_eq!(h.shebang.as_deref(), Some("#!/usr/bin/env python3"));
+    assert_eq!(h.license(), "Apache-2.0");
+    assert_eq!(value(&h.profile), Some("Leo-Fire-Horse"));
+    assert_eq!(value(&h.phase), Some("Heyday"));
+    assert_span(content, &h.phase);
+    assert_eq!(h.insert_at, "#!/usr/bin/env python3\n".len());
+}
+
+#[test]
+fn typescript_jsdoc_block() {
+    let content = "/**\n * SPDX-License-Identifier: MIT\n * ONTO-PROFILE: Virgo-Earth-Ox\n */\n// ONTO-PHASE: Fast\nexport const x = 1;\n";
+    let h = header("web/src/app.tsx", content);
+    assert_eq!(h.language, Some(Language::TypeScript));
+    assert_eq!(h.license(), "MIT");
+    assert_eq!(value(&h.profile), Some("Virgo-Earth-Ox"));
+    assert_eq!(value(&h.phase), Some("Fast"));
+    assert_span(content, &h.profile);
+}
+
+#[test]
+fn typescript_shebang_without_extension() {
+    let content = "#!/usr/bin/env -S deno run\n// SPDX-License-Identifier: GPL-3.0-or-later\n";
+    let h = header("bin/sync", content);
+    assert_eq!(h.language, Some(Language::TypeScript));
+    assert_eq!(h.license(), "GPL-3.0-or-later");
+}
+
+#[test]
+fn yaml_comments_after_document_start() {
+    let content = "---\n# SPDX-License-Identifier: GPL-3.0-only\n# ONTO-PROFILE: Pisces-Water-Goat\n# ONTO-PHASE: Decline\nname: ci\n# ONTO-PHASE: Fast\n";
+    let h = header(".github/workflows/ci.yml", content);
+    assert_eq!(h.language, Some(Language::Yaml));
+    assert_eq!(value(&h.profile), Some("Pisces-Water-Goat"));
+    assert_eq!(value(&h.phase), Some("Decline"));
+    assert_span(content, &h.profile);
+}
+
+#[test]
+fn markdown_front_matter_keys() {
+    let content = "---\ntitle: Guide\nonto_profile: \"Gemini-Metal-Rooster\"\nONTO-PHASE: Slow\nspdx-license-identifier: CC-BY-4.0\n---\n# Guide\n";
+    let h = header("docs/guide.md", content);
+    assert_eq!(h.language, Some(Language::Markdown));
+    assert!(h.front_matter);
+    assert_eq!(value(&h.profile), Some("Gemini-Metal-Rooster"));
+    assert_span(content, &h.profile);
+    assert_eq!(value(&h.phase), Some("Slow"));
+    assert_eq!(h.license(), "CC-BY-4.0");
+    assert_eq!(h.insert_at, content.find("# Guide").unwrap());
+}
+
+#[test]
+fn markdown_html_comments_not_headings() {
+    let content = "<!-- SPDX-License-Identifier: GPL-3.0-only -->\n<!--\nONTO-PROFILE: Taurus-Earth-Dog\n-->\n# ONTO-PHASE: Slow\n";
+    let h = header("README.md", content);
+    assert_eq!(h.license(), "GPL-3.0-only");
+    assert_span(content, &h.spdx);
+    assert_eq!(value(&h.profile), Some("Taurus-Earth-Dog"));
+    assert!(h.phase.is_none(), "a Markdown heading is not a comment");
+}
+
+#[test]
+fn shell_shebang_and_comments() {
+    let content = "#!/bin/bash\n# SPDX-License-Identifier: GPL-3.0-only\n# ONTO-PROFILE: Cancer-Water-Pig\nset -e\n";
+    let h = header("scripts/deploy", content);
+    assert_eq!(h.language, Some(Language::Shell));
+    assert_eq!(h.license(), "GPL-3.0-only");
+    assert_eq!(value(&h.profile), Some("Cancer-Water-Pig"));
+    assert!(h.phase.is_none());
+}
+
+#[test]
+fn crlf_line_endings() {
+    let content = "# SPDX-License-Identifier: MIT\r\n# ONTO-PHASE: Fast\r\nx=1\r\n";
+    let h = header("run.sh", content);
+    assert_eq!(h.license(), "MIT");
+    assert_eq!(value(&h.phase), Some("Fast"));
+    assert_span(content, &h.phase);
+}
+
+#[test]
+fn suppression_profile_is_not_the_file_profile() {
+    let content = "# onto-allow(sgrl.forbidden-term): legacy key ONTO-PROFILE: Aries-Wood-Rabbit\nvalue: 1\n";
+    let h = header("config.yaml", content);
+    assert!(h.profile.is_none());
+}
+
+#[test]
+fn suppressions_only_in_comments() {
+    let content = concat!(
+        "let s = \"// onto-allow(a): not a comment ONTO-PROFILE: Aries-Wood-Rabbit\";\n",
+        "/* onto-allow(b): block ONTO-PROFILE: Aries-Wood-Rabbit */\n",
+        "let t = r#\"/* onto-allow(c) */\"#; // onto-allow(d): tail ONTO-PROFILE: Aries-Wood-Rabbit\n",
+        "/// onto-allow(e): documented example ONTO-PROFILE: Aries-Wood-Rabbit\n",
+    );
+    let (parsed, malformed) = parse_suppressions(Some(Language::Rust), content);
+    assert!(malformed.is_empty());
+    let found: Vec<_> = parsed.iter().map(|s| (s.rule_id.as_str(), s.lines)).collect();
+    assert_eq!(found, vec![("b", (2, 3)), ("d", (3, 3))]);
+
+    let content = "echo \"# onto-allow(a): quoted ONTO-PROFILE: Aries-Wood-Rabbit\"\necho $# # onto-allow(b): args ONTO-PROFILE: Aries-Wood-Rabbit\n";
+    let (parsed, _) = parse_suppressions(Some(Language::Shell), content);
+    let found: Vec<_> = parsed.iter().map(|s| s.rule_id.as_str()).collect();
+    assert_eq!(found, vec!["b"]);
+}
+
+#[test]
+fn license_registry_uses_language_syntax() {
+    let registry = LicenseRegistry::new();
+    let content = "<!-- SPDX-License-Identifier: MIT -->\n";
+    assert_eq!(registry.detect_license_in(Path::new("NOTES.md"), content), "MIT");
+    assert_eq!(registry.detect_license("# SPDX-License-Identifier: MIT\n"), "MIT");
+}
+
+#[test]
+fn validator_fixes_use_file_comment_syntax() {
+    let validator = OntoValidator::new(LicenseRegistry::new(), vec![ProfileId("Aries-Wood-Rabbit".into())]);
+    let content = "#!/usr/bin/env python3\n# SPDX-License-Identifier: GPL-3.0-only\n# ONTO-PROFILE: Aries-Wood-Rabbit\nprint('ok')\n";
+    let report = validator.validate_file(Path::new("tool.py"), content);
+
+    let missing: Vec<_> = report.findings.iter().map(|f| f.rule_id.as_str()).collect();
+    assert_eq!(missing, vec![rules::PHASE_MISSING]);
+    let edit = &report.findings[0].fix.as_ref().unwrap().edits[0];
+    assert_eq!(edit.new_text, "# ONTO-PHASE: Slow\n");
+    assert_eq!(edit.span.start_byte, "#!/usr/bin/env python3\n".len());
+}
+```
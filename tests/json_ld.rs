@@ -0,0 +1,75 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// JSON-LD Events — Published Context, Compaction/Expansion, RDF N-Quads
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use serde_json::{json, Value};
+
+use ontocms::core::activity_ledger::{OntoEvent, OntoPhase, ProfileId};
+use ontocms::core::social_context::SocialContext;
+use ontocms::transport::json_ld::{
+    compact_event, event_from_jsonld, event_hash, events_to_nquads, expand_event, ontocms_context,
+};
+
+fn event(id: &str, payload: Value) -> OntoEvent {
+    OntoEvent {
+        id: id.into(),
+        profile_id: ProfileId("Aries-Wood-Rabbit".into()),
+        phase: OntoPhase::Slow,
+        payload,
+        social_context: SocialContext::new(70),
+        causal_hash: Some("genesis".into()),
+        timestamp: 1_700_000_000_000,
+    }
+}
+
+#[test]
+fn compact_and_expanded_forms_round_trip() {
+    let original = event("event-1", json!({ "type": "note", "tags": ["a", "b"], "nested": { "depth": 2 } }));
+    let from_compact = event_from_jsonld(&compact_event(&original)).unwrap();
+    let from_expanded = event_from_jsonld(&expand_event(&original).unwrap()).unwrap();
+    assert_eq!(serde_json::to_value(&from_compact).unwrap(), serde_json::to_value(&original).unwrap());
+    assert_eq!(serde_json::to_value(&from_expanded).unwrap(), serde_json::to_value(&original).unwrap());
+
+    let ctx = ontocms_context().unwrap();
+    let expanded = expand_event(&original).unwrap();
+    assert_eq!(ctx.expand(&ctx.compact(&expanded).unwrap()).unwrap(), expanded);
+}
+
+#[test]
+fn nquads_are_sorted_and_independent_of_event_order() {
+    let first = event("event-1", json!({ "type": "note" }));
+    let second = event("event-2", json!({ "type": "note" }));
+    let forward = events_to_nquads([&first, &second], None).unwrap();
+    let backward = events_to_nquads([&second, &first], None).unwrap();
+    assert_eq!(forward, backward);
+
+    let lines: Vec<&str> = forward.lines().collect();
+    let mut sorted = lines.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(lines, sorted);
+}
+
+#[test]
+fn event_hash_ignores_payload_key_order_and_number_spelling() {
+    let payload: Value = serde_json::from_str(r#"{"type":"note","meta":{"b":1,"a":[1,2]},"ratio":1.0}"#).unwrap();
+    let reordered: Value = serde_json::from_str(r#"{"ratio":1,"meta":{"a":[1,2],"b":1},"type":"note"}"#).unwrap();
+    let hash = event_hash(&event("event-1", payload)).unwrap();
+    assert_eq!(hash, event_hash(&event("event-1", reordered)).unwrap());
+    assert_ne!(hash, event_hash(&event("event-1", json!({ "type": "note" }))).unwrap());
+}
+
+#[test]
+fn iris_are_escaped_in_nquads() {
+    let mut unsafe_id = event("event 1>\"x\"", json!({ "type": "note" }));
+    unsafe_id.profile_id = ProfileId("Aries{Wood}|Rabbit".into());
+    let nquads = events_to_nquads([&unsafe_id], None).unwrap();
+    assert!(nquads.contains("event\\u00201\\u003E\\u0022x\\u0022>"), "{}", nquads);
+    assert!(nquads.contains("Aries\\u007BWood\\u007D\\u007CRabbit>"), "{}", nquads);
+    // Угловые скобки остаются только на границах IRI
+    for line in nquads.lines() {
+        assert_eq!(line.matches('<').count(), line.matches('>').count(), "{}", line);
+    }
+}
+```
@@ -0,0 +1,183 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — LSP Sessions over an In-Memory Connection (snapshot tests)
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::path::PathBuf;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use lsp_server::{Connection, Message, Notification, Request, RequestId};
+use serde_json::{json, Value};
+
+use ontocms::core::activity_ledger::ProfileId;
+use ontocms::ontocoder::lsp_server::OntoLspServer;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+const URI: &str = "file:///workspace/src/lib.rs";
+
+/// Сценарий клиента: всё, что прислал сервер, попадает в протокол сессии
+struct Session {
+    client: Connection,
+    server: JoinHandle<anyhow::Result<()>>,
+    next_id: i32,
+    transcript: Vec<Value>,
+}
+
+impl Session {
+    fn start(options: Value) -> Self {
+        let (server, client) = Connection::memory();
+        let handle = std::thread::spawn(move || {
+            let mut lsp = OntoLspServer::new(vec![ProfileId("Aries-Wood-Rabbit".into())]);
+            lsp.run_connection(server)
+        });
+        let mut session = Self {
+            client,
+            server: handle,
+            next_id: 0,
+            transcript: vec![],
+        };
+        session.request("initialize", json!({ "capabilities": {}, "initializationOptions": options }));
+        session.notify("initialized", json!({}));
+        session
+    }
+
+    /// Запрос и ответ на него; уведомления до ответа тоже записываются
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        self.next_id += 1;
+        let id = RequestId::from(self.next_id);
+        self.send(Message::Request(Request::new(id.clone(), method.into(), params)));
+        loop {
+            match self.recv() {
+                Message::Response(resp) if resp.id == id => {
+                    let value = serde_json::to_value(&resp).unwrap();
+                    self.transcript.push(json!({ "response": method, "message": value }));
+                    return value;
+                }
+                other => self.record(other),
+            }
+        }
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        self.send(Message::Notification(Notification::new(method.into(), params)));
+    }
+
+    /// Следующее уведомление сервера (публикация диагностики)
+    fn expect_notification(&mut self) {
+        let message = self.recv();
+        assert!(matches!(message, Message::Notification(_)), "expected a notification, got {:?}", message);
+        self.record(message);
+    }
+
+    fn open(&mut self, text: &str) {
+        self.notify(
+            "textDocument/didOpen",
+            json!({ "textDocument": { "uri": URI, "languageId": "rust", "version": 1, "text": text } }),
+        );
+        self.expect_notification();
+    }
+
+    fn change(&mut self, version: i32, text: &str) {
+        self.notify(
+            "textDocument/didChange",
+            json!({ "textDocument": { "uri": URI, "version": version }, "contentChanges": [{ "text": text }] }),
+        );
+        self.expect_notification();
+    }
+
+    fn at(&mut self, method: &str, line: u32, character: u32) -> Value {
+        self.request(
+            method,
+            json!({ "textDocument": { "uri": URI }, "position": { "line": line, "character": character } }),
+        )
+    }
+
+    /// `shutdown` + `exit`; сервер должен завершиться без ошибки
+    fn shutdown(mut self) -> Vec<Value> {
+        self.request("shutdown", Value::Null);
+        self.notify("exit", Value::Null);
+        self.server.join().expect("server thread panicked").expect("server session failed");
+        self.transcript
+    }
+
+    fn send(&self, message: Message) {
+        self.client.sender.send(message).expect("server is gone");
+    }
+
+    fn recv(&self) -> Message {
+        self.client.receiver.recv_timeout(TIMEOUT).expect("no message from the server")
+    }
+
+    fn record(&mut self, message: Message) {
+        let value = serde_json::to_value(&message).unwrap();
+        self.transcript.push(json!({ "notification": value }));
+    }
+}
+
+/// Сравнение протокола с tests/snapshots/lsp/<name>.json
+/// `UPDATE_SNAPSHOTS=1` (или отсутствующий файл) — записать текущий протокол
+fn assert_snapshot(name: &str, transcript: &[Value]) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots/lsp").join(format!("{}.json", name));
+    let actual = Value::Array(transcript.to_vec());
+    let pretty = serde_json::to_string_pretty(&actual).unwrap() + "\n";
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() || !path.exists() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, pretty).unwrap();
+        return;
+    }
+    let expected: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert!(
+        expected == actual,
+        "LSP transcript differs from {} (rerun with UPDATE_SNAPSHOTS=1 to accept)\n{}",
+        path.display(),
+        pretty
+    );
+}
+
+#[test]
+fn open_change_hover_shutdown() {
+    let mut session = Session::start(json!({}));
+    session.open("// SPDX-License-Identifier: GPL-3.0-only\n// ONTO-PROFILE: Aries-Wood-Rabbit\npub fn answer() -> u8 {\n    42\n}\n");
+    session.change(
+        2,
+        "// SPDX-License-Identifier: GPL-3.0-only\n// ONTO-PROFILE: Aries-Wood-Rabbit\n// ONTO-PHASE: Slow\npub fn answer() -> u8 {\n    42\n}\n",
+    );
+    session.at("textDocument/hover", 2, 16);
+    session.at("textDocument/hover", 1, 20);
+    session.at("textDocument/hover", 4, 4);
+    assert_snapshot("open_change_hover_shutdown", &session.shutdown());
+}
+
+#[test]
+fn code_actions_insert_missing_headers() {
+    let mut session = Session::start(json!({ "profile": "Aries-Wood-Rabbit" }));
+    session.open("pub fn answer() -> u8 {\n    42\n}\n");
+    let diagnostics = session.transcript.last().unwrap()["notification"]["params"]["diagnostics"].clone();
+    session.request(
+        "textDocument/codeAction",
+        json!({
+            "textDocument": { "uri": URI },
+            "range": { "start": { "line": 0, "character": 0 }, "end": { "line": 0, "character": 0 } },
+            "context": { "diagnostics": diagnostics }
+        }),
+    );
+    assert_snapshot("code_actions_insert_missing_headers", &session.shutdown());
+}
+
+#[test]
+fn phase_completion_and_invalid_phase() {
+    let mut session = Session::start(json!({}));
+    session.open("// SPDX-License-Identifier: GPL-3.0-only\n// ONTO-PROFILE: Aries-Wood-Rabbit\n// ONTO-PHASE: Sl\npub fn answer() -> u8 {\n    42\n}\n");
+    session.at("textDocument/completion", 2, 17);
+    assert_snapshot("phase_completion_and_invalid_phase", &session.shutdown());
+}
+
+#[test]
+fn unknown_request_is_method_not_found() {
+    let mut session = Session::start(json!({}));
+    let response = session.request("ontocms/unknown", json!({}));
+    assert_eq!(response["error"]["code"], json!(-32601));
+    session.shutdown();
+}
+```
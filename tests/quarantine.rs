@@ -0,0 +1,187 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCMS Core — изоляция узла по свидетельствам зарегистрированных свидетелей
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use libp2p::identity::Keypair;
+use serde_json::json;
+
+use ontocms::core::activity_ledger::{ActivityLedger, OntoEvent, OntoPhase, ProfileId};
+use ontocms::core::key_registry::KeyRegistry;
+use ontocms::core::quarantine::{LiftRequest, QuarantineError, QuarantinePolicy, QuarantineRegistry};
+use ontocms::core::social_context::SocialContext;
+use ontocms::ethics::law3_harm_witness::{generate_harm_witness, HarmType, HarmWitness};
+use ontocms::ethics::pipeline::EthicsPipeline;
+use ontocms::forms::event_schema_registry::EventSchemaRegistry;
+
+struct Witness {
+    profile: ProfileId,
+    keypair: Keypair,
+}
+
+impl Witness {
+    fn new(profile: &str) -> Self {
+        Self {
+            profile: ProfileId(profile.into()),
+            keypair: Keypair::generate_ed25519(),
+        }
+    }
+
+    fn witness(&self, event: &OntoEvent) -> HarmWitness {
+        self.witness_of(HarmType::EnergyValueInjection, event)
+    }
+
+    fn witness_of(&self, harm: HarmType, event: &OntoEvent) -> HarmWitness {
+        let mut witness = generate_harm_witness(harm, event, self.profile.clone()).unwrap();
+        witness.sign(&self.keypair).unwrap();
+        witness
+    }
+}
+
+fn remote_event(id: &str) -> OntoEvent {
+    OntoEvent {
+        id: id.into(),
+        profile_id: ProfileId("Aries-Wood-Rabbit".into()),
+        phase: OntoPhase::Fast,
+        payload: json!({ "type": "metrics", "energy_level": 7 }),
+        social_context: SocialContext::new(50),
+        causal_hash: Some("genesis".into()),
+        timestamp: 1_700_000_000_000,
+    }
+}
+
+fn node_profile() -> ProfileId {
+    ProfileId("Leo-Metal-Dragon".into())
+}
+
+fn registry(keys: KeyRegistry) -> QuarantineRegistry {
+    registry_signed_by(keys, Keypair::generate_ed25519())
+}
+
+fn registry_signed_by(keys: KeyRegistry, node_key: Keypair) -> QuarantineRegistry {
+    let policy = QuarantinePolicy {
+        violation_threshold: 2,
+        ..QuarantinePolicy::default()
+    };
+    QuarantineRegistry::new(policy, node_profile(), node_key).with_keys(keys)
+}
+
+#[test]
+fn self_signed_witness_is_not_counted() {
+    let peer = Keypair::generate_ed25519().public().to_peer_id();
+    let event = remote_event("event-1");
+    let mut quarantine = registry(KeyRegistry::new());
+    quarantine.observe_event(peer, &event).unwrap();
+
+    let result = quarantine.observe_witness(&Witness::new("Taurus-Fire-Horse").witness(&event));
+    assert!(matches!(result, Err(QuarantineError::UntrustedWitness { .. })));
+}
+
+#[test]
+fn witness_must_point_at_the_observed_event() {
+    let peer = Keypair::generate_ed25519().public().to_peer_id();
+    let witness = Witness::new("Taurus-Fire-Horse");
+    let keys = KeyRegistry::new().with_key(witness.profile.clone(), &witness.keypair.public().to_peer_id());
+    let mut quarantine = registry(keys);
+    quarantine.observe_event(peer, &remote_event("event-1")).unwrap();
+
+    // То же id, другое содержимое
+    let mut forged = remote_event("event-1");
+    forged.payload = json!({ "type": "metrics", "energy_score": 9 });
+    let result = quarantine.observe_witness(&witness.witness(&forged));
+    assert!(matches!(result, Err(QuarantineError::CausalHashMismatch { .. })));
+}
+
+#[test]
+fn isolation_needs_distinct_witnesses_and_lift_needs_an_independent_key() {
+    let peer_keypair = Keypair::generate_ed25519();
+    let peer = peer_keypair.public().to_peer_id();
+    let (first, second, reviewer) = (
+        Witness::new("Taurus-Fire-Horse"),
+        Witness::new("Gemini-Earth-Tiger"),
+        Witness::new("Cancer-Water-Ox"),
+    );
+    let keys = KeyRegistry::new()
+        .with_key(first.profile.clone(), &first.keypair.public().to_peer_id())
+        .with_key(second.profile.clone(), &second.keypair.public().to_peer_id())
+        .with_key(reviewer.profile.clone(), &reviewer.keypair.public().to_peer_id())
+        .with_key(ProfileId("Aries-Wood-Rabbit".into()), &peer);
+    let mut quarantine = registry(keys);
+    let events: Vec<OntoEvent> = ["event-1", "event-2", "event-3"].into_iter().map(remote_event).collect();
+    for event in &events {
+        quarantine.observe_event(peer, event).unwrap();
+    }
+
+    // Один свидетель не изолирует узел, сколько бы событий он ни засвидетельствовал
+    assert!(quarantine.observe_witness(&first.witness(&events[0])).unwrap().is_none());
+    assert!(quarantine.observe_witness(&first.witness(&events[1])).unwrap().is_none());
+    let record = quarantine.observe_witness(&second.witness(&events[2])).unwrap().unwrap();
+    assert!(quarantine.is_quarantined(&peer));
+
+    let schemas = EventSchemaRegistry::builtin().unwrap();
+    schemas.validate_payload(&record.event.payload).unwrap();
+
+    let request = |profile: &ProfileId, keypair: &Keypair| {
+        let mut request = LiftRequest {
+            peer: record.peer.clone(),
+            isolation_event_id: record.event.id.clone(),
+            reviewer_profile: profile.clone(),
+            reviewed_witness_ids: record.evidence_chain.iter().map(|l| l.witness_id.clone()).collect(),
+            reason: "evidence reviewed".into(),
+            timestamp: 1_700_000_100_000,
+            signature: None,
+        };
+        request.sign(keypair).unwrap();
+        request
+    };
+
+    // Профиль в запросе — только текст: решает ключ подписи
+    let forged = request(&reviewer.profile, &peer_keypair);
+    assert!(matches!(quarantine.lift(&forged), Err(QuarantineError::UntrustedReviewer { .. })));
+    let own = request(&ProfileId("Aries-Wood-Rabbit".into()), &peer_keypair);
+    assert!(matches!(quarantine.lift(&own), Err(QuarantineError::ReviewerIsPeer)));
+    let by_witness = request(&first.profile, &first.keypair);
+    assert!(matches!(quarantine.lift(&by_witness), Err(QuarantineError::ReviewerIsWitness)));
+
+    let lifted = quarantine.lift(&request(&reviewer.profile, &reviewer.keypair)).unwrap();
+    schemas.validate_payload(&lifted.payload).unwrap();
+    assert!(!quarantine.is_quarantined(&peer));
+}
+
+#[test]
+fn isolation_event_is_ingested_for_any_harm_under_the_node_key() {
+    let peer = Keypair::generate_ed25519().public().to_peer_id();
+    let (first, second) = (Witness::new("Taurus-Fire-Horse"), Witness::new("Gemini-Earth-Tiger"));
+    let node_key = Keypair::generate_ed25519();
+    let keys = KeyRegistry::new()
+        .with_key(first.profile.clone(), &first.keypair.public().to_peer_id())
+        .with_key(second.profile.clone(), &second.keypair.public().to_peer_id());
+    let mut quarantine = registry_signed_by(keys.clone(), node_key.clone());
+    let events: Vec<OntoEvent> = ["event-1", "event-2"]
+        .into_iter()
+        .map(|id| OntoEvent {
+            payload: json!({ "type": "metrics", "biometric_scan": 7 }),
+            ..remote_event(id)
+        })
+        .collect();
+    for event in &events {
+        quarantine.observe_event(peer, event).unwrap();
+    }
+    assert!(quarantine
+        .observe_witness(&first.witness_of(HarmType::BiometricExploitation, &events[0]))
+        .unwrap()
+        .is_none());
+    let record = quarantine
+        .observe_witness(&second.witness_of(HarmType::BiometricExploitation, &events[1]))
+        .unwrap()
+        .unwrap();
+
+    // Без ключа узла имена типов вреда в цепочке и политике сканируются как обычный payload
+    let mut ledger = ActivityLedger::new();
+    assert!(ledger.ingest(record.event.clone(), &EthicsPipeline::new().with_keys(keys.clone())).is_err());
+
+    let node_keys = keys.with_key(node_profile(), &node_key.public().to_peer_id());
+    ledger.ingest(record.event.clone(), &EthicsPipeline::new().with_keys(node_keys)).unwrap();
+    assert!(ledger.get(&record.event.id).is_some());
+}
+```
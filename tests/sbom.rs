@@ -0,0 +1,88 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Software Bill of Materials (SPDX 2.3 / CycloneDX JSON) and Tree Verification
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::path::Path;
+
+use ontocms::ontocoder::cyclonedx::CycloneDxBom;
+use ontocms::ontocoder::file_inventory::{diff, records_from_sbom, FileRecord};
+use ontocms::ontocoder::license_registry::LicenseRegistry;
+use ontocms::ontocoder::sbom::{verification_code, SpdxDocument, NOASSERTION};
+
+fn records() -> Vec<FileRecord> {
+    let registry = LicenseRegistry::new();
+    let files = [
+        (
+            "src/lib.rs",
+            "// SPDX-License-Identifier: GPL-3.0-only\n// ONTO-PROFILE: Aries-Wood-Rabbit\n// ONTO-PHASE: Slow\npub fn a() {}\n",
+        ),
+        ("src/ethics.rs", "// SPDX-License-Identifier: SGRL-α\n// ONTO-PROFILE: Leo-Fire-Horse\npub fn b() {}\n"),
+        ("build.rs", "fn main() {}\n"),
+    ];
+    files
+        .iter()
+        .map(|(path, content)| FileRecord::new(Path::new(path), content.as_bytes(), &registry))
+        .collect()
+}
+
+#[test]
+fn spdx_document_rebuilds_file_records() {
+    let records = records();
+    let doc = SpdxDocument::for_files("ontocms", Some("0.1.0"), &records, "3.24");
+    let rebuilt = records_from_sbom(&doc.to_json().unwrap()).unwrap();
+
+    assert_eq!(rebuilt.len(), records.len());
+    assert!(diff(&rebuilt, &records).is_empty(), "{}", diff(&rebuilt, &records).render_text());
+    let lib = rebuilt.iter().find(|r| r.path == "src/lib.rs").unwrap();
+    assert_eq!(lib.profile.as_deref(), Some("Aries-Wood-Rabbit"));
+    assert_eq!(lib.phase.as_deref(), Some("Slow"));
+    // Не выражение SPDX — в документе NOASSERTION
+    let ethics = rebuilt.iter().find(|r| r.path == "src/ethics.rs").unwrap();
+    assert_eq!(ethics.license, NOASSERTION);
+}
+
+#[test]
+fn cyclonedx_bom_rebuilds_file_records_exactly() {
+    let records = records();
+    let bom = CycloneDxBom::for_files("ontocms", Some("0.1.0"), &records);
+    let rebuilt = records_from_sbom(&bom.to_json().unwrap()).unwrap();
+    // Лицензия вне синтаксиса SPDX сохраняется как имя
+    assert_eq!(rebuilt, records);
+}
+
+#[test]
+fn verification_code_covers_sorted_file_hashes() {
+    let records = records();
+    let doc = SpdxDocument::for_files("ontocms", None, &records, "3.24");
+    let code = doc.packages[0].package_verification_code.clone().unwrap();
+
+    let mut hashes: Vec<&str> = records.iter().map(|r| r.sha1.as_str()).collect();
+    hashes.sort_unstable();
+    let expected = sha1_smol::Sha1::from(hashes.concat()).digest().to_string();
+    assert_eq!(code.package_verification_code_value, expected);
+
+    let mut reversed = records.clone();
+    reversed.reverse();
+    assert_eq!(verification_code(&reversed), code);
+    // Код пересчитывается из записей, восстановленных из документа
+    let rebuilt = records_from_sbom(&doc.to_json().unwrap()).unwrap();
+    assert_eq!(verification_code(&rebuilt), code);
+}
+
+#[test]
+fn changed_file_is_reported_against_the_recorded_sbom() {
+    let recorded = records();
+    let doc = SpdxDocument::for_files("ontocms", None, &recorded, "3.24");
+    let mut actual = recorded.clone();
+    actual[2] = FileRecord::new(Path::new("build.rs"), b"fn main() { println!(); }\n", &LicenseRegistry::new());
+    actual.push(FileRecord::new(Path::new("src/new.rs"), b"pub fn c() {}\n", &LicenseRegistry::new()));
+
+    let report = diff(&records_from_sbom(&doc.to_json().unwrap()).unwrap(), &actual);
+    assert_eq!(report.added, ["src/new.rs"]);
+    assert!(report.removed.is_empty());
+    assert_eq!(report.changed.len(), 1);
+    assert_eq!((report.changed[0].path.as_str(), report.changed[0].field.as_str()), ("build.rs", "checksum"));
+    assert_ne!(verification_code(&actual), doc.packages[0].package_verification_code.clone().unwrap());
+}
+```
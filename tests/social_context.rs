@@ -0,0 +1,54 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCMS Core — Social Context of an Event (Law II)
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use serde_json::json;
+
+use ontocms::core::activity_ledger::OntoEvent;
+use ontocms::core::social_context::{AudienceScope, ProximityBand, RelationKind, SocialContext};
+use ontocms::transport::json_ld::compact_event;
+
+#[test]
+fn legacy_proximity_round_trips_as_a_number() {
+    let context: SocialContext = serde_json::from_value(json!(50)).unwrap();
+    assert_eq!(context, SocialContext::new(50));
+    assert!(context.is_legacy());
+    assert_eq!(context.band(), ProximityBand::Medium);
+    assert_eq!(serde_json::to_value(context).unwrap(), json!(50));
+}
+
+#[test]
+fn structured_context_round_trips_with_defaults() {
+    let context = SocialContext::new(70)
+        .with_relation(RelationKind::SelfAuthored)
+        .with_audience(AudienceScope::Community);
+    let value = serde_json::to_value(context).unwrap();
+    assert_eq!(value, json!({ "proximity": 70, "relation": "self_authored", "audience": "community" }));
+    assert_eq!(serde_json::from_value::<SocialContext>(value).unwrap(), context);
+
+    let partial: SocialContext = serde_json::from_value(json!({ "proximity": 20, "audience": "public" })).unwrap();
+    assert_eq!(partial.relation, RelationKind::Unspecified);
+    assert_eq!(partial.audience, AudienceScope::Public);
+}
+
+#[test]
+fn old_events_read_social_proximity_and_keep_their_json_ld_term() {
+    let event: OntoEvent = serde_json::from_value(json!({
+        "id": "event-1",
+        "profile_id": "Aries-Wood-Rabbit",
+        "phase": "Slow",
+        "payload": { "type": "note" },
+        "social_proximity": 30,
+        "causal_hash": null,
+        "timestamp": 1_700_000_000_000u64,
+    }))
+    .unwrap();
+    assert_eq!(event.social_context, SocialContext::new(30));
+    assert_eq!(serde_json::to_value(&event).unwrap()["social_context"], json!(30));
+
+    let compact = compact_event(&event);
+    assert_eq!(compact["social_proximity"], json!(30));
+    assert!(compact.get("social_context").is_none());
+}
+```
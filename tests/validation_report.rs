@@ -0,0 +1,38 @@
+```rust
+// SPDX-License-Identifier: GPL-3.0-only
+// OntoCoder — Report Columns (UTF-16) and GitHub Annotations
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ontocms::ontocoder::report::{rules, Finding, LineIndex, Severity, ValidationReport};
+use ontocms::ontocoder::term_policy::TermPolicy;
+
+#[test]
+fn columns_are_utf16_units() {
+    let index = LineIndex::new("// 😀\nx");
+    assert_eq!(index.position(7), (1, 5));
+    assert_eq!(index.offset(1, 5), 7);
+
+    // Спаны syn (столбцы в символах) пересчитываются в UTF-16
+    let source = "/* 😀 */ pub struct Value;\n";
+    let hits = TermPolicy::builtin().unwrap().check_rust(source).unwrap();
+    let start = source.find("Value").unwrap();
+    assert_eq!(hits[0].span.start_byte, start);
+    assert_eq!(hits[0].span.start_column, source[..start].encode_utf16().count());
+}
+
+#[test]
+fn github_annotation_properties_are_escaped() {
+    let mut report = ValidationReport::new("src/a,b:c.rs");
+    report.push(Finding {
+        rule_id: rules::SYNTAX.into(),
+        severity: Severity::Error,
+        span: LineIndex::new("").span_of_bytes(0, 0),
+        message: "100% bad:\nline".into(),
+        fix: None,
+    });
+    assert_eq!(
+        report.render_github(),
+        "::error file=src/a%2Cb%3Ac.rs,line=1,col=1,endLine=1,endColumn=1,title=onto.syntax::100%25 bad:%0Aline\n"
+    );
+}
+```